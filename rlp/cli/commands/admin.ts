@@ -85,6 +85,8 @@ export function registerAdminCommands(program: Command) {
     .requiredOption("--mint <address>", "Asset token mint")
     .requiredOption("--oracle <address>", "Pyth oracle address")
     .requiredOption("--access-level <Public|Private>", "Access level")
+    .requiredOption("--max-confidence-bps <bps>", "Max oracle confidence interval, in bps")
+    .option("--oracle-max-age <seconds>", "Max oracle price age, in seconds")
     .action(async (opts, cmd) => {
       try {
         const globals = cmd.optsWithGlobals();
@@ -97,6 +99,8 @@ export function registerAdminCommands(program: Command) {
           address(opts.mint),
           address(opts.oracle),
           parseAccessLevel(opts.accessLevel),
+          Number(opts.maxConfidenceBps),
+          opts.oracleMaxAge !== undefined ? BigInt(opts.oracleMaxAge) : null,
         );
         const sig = await sendAndConfirm(resolveRpcUrl(globals), kp, ix);
         printSuccess(`Asset added. Signature: ${sig}`);
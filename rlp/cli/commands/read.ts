@@ -58,7 +58,7 @@ export function registerReadCommands(program: Command) {
             address: a.address,
             index: a.data.index,
             mint: a.data.mint,
-            oracle: (a.data.oracle as any).fields[0],
+            oracle: Insurance.oracleKey(a.data.oracle),
             accessLevel: a.data.accessLevel,
           })),
         );
@@ -22,13 +22,17 @@ pub index: u8,
 pub mint: Pubkey,
 pub oracle: Oracle,
 pub access_level: AccessLevel,
+pub max_confidence_bps: u16,
+pub oracle_max_age: u64,
+pub additional_oracles: [Option<Oracle>; 2],
+pub max_oracle_deviation_bps: u16,
 }
 
 
 pub const ASSET_DISCRIMINATOR: [u8; 8] = [234, 180, 241, 252, 139, 224, 160, 8];
 
 impl Asset {
-      pub const LEN: usize = 76;
+      pub const LEN: usize = 351;
   
   
   
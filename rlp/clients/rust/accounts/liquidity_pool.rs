@@ -6,6 +6,8 @@
 //!
 
 use solana_pubkey::Pubkey;
+use crate::generated::types::AssetLimits;
+use crate::generated::types::PairFee;
 use borsh::BorshSerialize;
 use borsh::BorshDeserialize;
 
@@ -22,7 +24,15 @@ pub cooldowns: u64,
 pub cooldown_duration: u64,
 pub deposit_cap: Option<u64>,
 pub asset_count: u8,
-pub assets: [u8; 4],
+pub assets: Vec<u8>,
+pub protocol_fees: Vec<u64>,
+pub swap_fee_bps: Option<u16>,
+pub pair_fees: [Option<PairFee>; 6],
+pub target_weights_bps: Vec<u16>,
+pub imbalance_fee_bps: u16,
+pub max_weight_deviation_bps: u16,
+pub asset_limits: Vec<AssetLimits>,
+pub deposit_value_cap: Option<u128>,
 }
 
 
@@ -8,12 +8,14 @@
   pub(crate) mod r#asset;
   pub(crate) mod r#cooldown;
   pub(crate) mod r#liquidity_pool;
+  pub(crate) mod r#reward_pool;
   pub(crate) mod r#settings;
   pub(crate) mod r#user_permissions;
 
   pub use self::r#asset::*;
   pub use self::r#cooldown::*;
   pub use self::r#liquidity_pool::*;
+  pub use self::r#reward_pool::*;
   pub use self::r#settings::*;
   pub use self::r#user_permissions::*;
 
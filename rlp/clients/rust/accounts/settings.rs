@@ -5,6 +5,7 @@
 //! <https://github.com/codama-idl/codama>
 //!
 
+use solana_pubkey::Pubkey;
 use crate::generated::types::AccessControl;
 use borsh::BorshSerialize;
 use borsh::BorshDeserialize;
@@ -19,13 +20,16 @@ pub liquidity_pools: u8,
 pub assets: u8,
 pub access_control: AccessControl,
 pub swap_fee_bps: u16,
+pub protocol_fee_bps: u16,
+#[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::DisplayFromStr>"))]
+pub treasury: Pubkey,
 }
 
 
 pub const SETTINGS_DISCRIMINATOR: [u8; 8] = [223, 179, 163, 190, 177, 224, 67, 173];
 
 impl Settings {
-      pub const LEN: usize = 376;
+      pub const LEN: usize = 410;
   
   
   
@@ -157,6 +157,36 @@ pub enum RlpError {
     /// 6048 - OracleDataTooStale
     #[error("OracleDataTooStale")]
     OracleDataTooStale = 0x17A0,
+    /// 6049 - OracleConfidenceTooWide
+    #[error("OracleConfidenceTooWide")]
+    OracleConfidenceTooWide = 0x17A1,
+    /// 6050 - OracleDepegged
+    #[error("OracleDepegged")]
+    OracleDepegged = 0x17A2,
+    /// 6051 - OracleDeviationTooLarge
+    #[error("OracleDeviationTooLarge")]
+    OracleDeviationTooLarge = 0x17A3,
+    /// 6052 - TooManyOracles
+    #[error("TooManyOracles")]
+    TooManyOracles = 0x17A4,
+    /// 6053 - RewardScheduleFull
+    #[error("RewardScheduleFull")]
+    RewardScheduleFull = 0x17A5,
+    /// 6054 - PairFeesFull
+    #[error("PairFeesFull")]
+    PairFeesFull = 0x17A6,
+    /// 6055 - PoolAssetsFull
+    #[error("PoolAssetsFull")]
+    PoolAssetsFull = 0x17A7,
+    /// 6056 - ReserveNotEmpty
+    #[error("ReserveNotEmpty")]
+    ReserveNotEmpty = 0x17A8,
+    /// 6057 - AssetPaused
+    #[error("AssetPaused")]
+    AssetPaused = 0x17A9,
+    /// 6058 - AssetCapExceeded
+    #[error("AssetCapExceeded")]
+    AssetCapExceeded = 0x17AA,
 }
 
 impl From<RlpError> for solana_program_error::ProgramError {
@@ -6,6 +6,7 @@
 //!
 
 use crate::generated::types::AccessLevel;
+use crate::generated::types::FixedPrice;
 use borsh::BorshSerialize;
 use borsh::BorshDeserialize;
 
@@ -31,7 +32,7 @@ pub struct AddAsset {
           pub asset_mint: solana_pubkey::Pubkey,
           
               
-          pub oracle: solana_pubkey::Pubkey,
+          pub oracle: Option<solana_pubkey::Pubkey>,
           
               
           pub system_program: solana_pubkey::Pubkey,
@@ -65,10 +66,17 @@ impl AddAsset {
             self.asset_mint,
             false
           ));
-                                          accounts.push(solana_instruction::AccountMeta::new(
-            self.oracle,
-            false
-          ));
+                                          if let Some(oracle) = self.oracle {
+              accounts.push(solana_instruction::AccountMeta::new(
+                oracle,
+                false,
+              ));
+            } else {
+              accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::RLP_ID,
+                false,
+              ));
+            }
                                           accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.system_program,
             false
@@ -114,6 +122,9 @@ impl Default for AddAssetInstructionData {
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
  pub struct AddAssetInstructionArgs {
                   pub access_level: AccessLevel,
+                  pub max_confidence_bps: u16,
+                  pub oracle_max_age: Option<u64>,
+                  pub fixed_price: Option<FixedPrice>,
       }
 
 impl AddAssetInstructionArgs {
@@ -132,7 +143,7 @@ impl AddAssetInstructionArgs {
                 ///   2. `[writable]` settings
                 ///   3. `[writable]` asset
                 ///   4. `[writable]` asset_mint
-                ///   5. `[writable]` oracle
+                ///   5. `[writable, optional]` oracle
                 ///   6. `[optional]` system_program (default to `11111111111111111111111111111111`)
 #[derive(Clone, Debug, Default)]
 pub struct AddAssetBuilder {
@@ -144,6 +155,9 @@ pub struct AddAssetBuilder {
                 oracle: Option<solana_pubkey::Pubkey>,
                 system_program: Option<solana_pubkey::Pubkey>,
                         access_level: Option<AccessLevel>,
+                        max_confidence_bps: Option<u16>,
+                        oracle_max_age: Option<u64>,
+                        fixed_price: Option<FixedPrice>,
         __remaining_accounts: Vec<solana_instruction::AccountMeta>,
 }
 
@@ -176,9 +190,10 @@ impl AddAssetBuilder {
                         self.asset_mint = Some(asset_mint);
                     self
     }
-            #[inline(always)]
-    pub fn oracle(&mut self, oracle: solana_pubkey::Pubkey) -> &mut Self {
-                        self.oracle = Some(oracle);
+            /// `[optional account]`
+#[inline(always)]
+    pub fn oracle(&mut self, oracle: Option<solana_pubkey::Pubkey>) -> &mut Self {
+                        self.oracle = oracle;
                     self
     }
             /// `[optional account, default to '11111111111111111111111111111111']`
@@ -191,6 +206,23 @@ impl AddAssetBuilder {
       pub fn access_level(&mut self, access_level: AccessLevel) -> &mut Self {
         self.access_level = Some(access_level);
         self
+      }
+      #[inline(always)]
+      pub fn max_confidence_bps(&mut self, max_confidence_bps: u16) -> &mut Self {
+        self.max_confidence_bps = Some(max_confidence_bps);
+        self
+      }
+                /// `[optional argument]`
+#[inline(always)]
+      pub fn oracle_max_age(&mut self, oracle_max_age: u64) -> &mut Self {
+        self.oracle_max_age = Some(oracle_max_age);
+        self
+      }
+      /// `[optional argument]`
+      #[inline(always)]
+      pub fn fixed_price(&mut self, fixed_price: FixedPrice) -> &mut Self {
+        self.fixed_price = Some(fixed_price);
+        self
       }
         /// Add an additional account to the instruction.
   #[inline(always)]
@@ -212,11 +244,14 @@ impl AddAssetBuilder {
                                         settings: self.settings.expect("settings is not set"),
                                         asset: self.asset.expect("asset is not set"),
                                         asset_mint: self.asset_mint.expect("asset_mint is not set"),
-                                        oracle: self.oracle.expect("oracle is not set"),
+                                        oracle: self.oracle,
                                         system_program: self.system_program.unwrap_or(solana_pubkey::pubkey!("11111111111111111111111111111111")),
                       };
           let args = AddAssetInstructionArgs {
                                                               access_level: self.access_level.clone().expect("access_level is not set"),
+                                                              max_confidence_bps: self.max_confidence_bps.clone().expect("max_confidence_bps is not set"),
+                                                              oracle_max_age: self.oracle_max_age.clone(),
+                                                              fixed_price: self.fixed_price.clone(),
                                     };
     
     accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
@@ -242,7 +277,7 @@ impl AddAssetBuilder {
               pub asset_mint: &'b solana_account_info::AccountInfo<'a>,
                 
                     
-              pub oracle: &'b solana_account_info::AccountInfo<'a>,
+              pub oracle: Option<&'b solana_account_info::AccountInfo<'a>>,
                 
                     
               pub system_program: &'b solana_account_info::AccountInfo<'a>,
@@ -269,7 +304,7 @@ pub struct AddAssetCpi<'a, 'b> {
           pub asset_mint: &'b solana_account_info::AccountInfo<'a>,
           
               
-          pub oracle: &'b solana_account_info::AccountInfo<'a>,
+          pub oracle: Option<&'b solana_account_info::AccountInfo<'a>>,
           
               
           pub system_program: &'b solana_account_info::AccountInfo<'a>,
@@ -336,10 +371,17 @@ impl<'a, 'b> AddAssetCpi<'a, 'b> {
             *self.asset_mint.key,
             false
           ));
-                                          accounts.push(solana_instruction::AccountMeta::new(
-            *self.oracle.key,
-            false
-          ));
+                                          if let Some(oracle) = self.oracle {
+              accounts.push(solana_instruction::AccountMeta::new(
+                *oracle.key,
+                false,
+              ));
+            } else {
+              accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::RLP_ID,
+                false,
+              ));
+            }
                                           accounts.push(solana_instruction::AccountMeta::new_readonly(
             *self.system_program.key,
             false
@@ -367,7 +409,9 @@ impl<'a, 'b> AddAssetCpi<'a, 'b> {
                         account_infos.push(self.settings.clone());
                         account_infos.push(self.asset.clone());
                         account_infos.push(self.asset_mint.clone());
-                        account_infos.push(self.oracle.clone());
+                        if let Some(oracle) = self.oracle {
+          account_infos.push(oracle.clone());
+        }
                         account_infos.push(self.system_program.clone());
               remaining_accounts.iter().for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
 
@@ -388,7 +432,7 @@ impl<'a, 'b> AddAssetCpi<'a, 'b> {
                 ///   2. `[writable]` settings
                 ///   3. `[writable]` asset
                 ///   4. `[writable]` asset_mint
-                ///   5. `[writable]` oracle
+                ///   5. `[writable, optional]` oracle
           ///   6. `[]` system_program
 #[derive(Clone, Debug)]
 pub struct AddAssetCpiBuilder<'a, 'b> {
@@ -407,6 +451,9 @@ impl<'a, 'b> AddAssetCpiBuilder<'a, 'b> {
               oracle: None,
               system_program: None,
                                             access_level: None,
+                                            max_confidence_bps: None,
+                                            oracle_max_age: None,
+                                            fixed_price: None,
                     __remaining_accounts: Vec::new(),
     });
     Self { instruction }
@@ -436,9 +483,10 @@ impl<'a, 'b> AddAssetCpiBuilder<'a, 'b> {
                         self.instruction.asset_mint = Some(asset_mint);
                     self
     }
-      #[inline(always)]
-    pub fn oracle(&mut self, oracle: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
-                        self.instruction.oracle = Some(oracle);
+      /// `[optional account]`
+#[inline(always)]
+    pub fn oracle(&mut self, oracle: Option<&'b solana_account_info::AccountInfo<'a>>) -> &mut Self {
+                        self.instruction.oracle = oracle;
                     self
     }
       #[inline(always)]
@@ -450,6 +498,23 @@ impl<'a, 'b> AddAssetCpiBuilder<'a, 'b> {
       pub fn access_level(&mut self, access_level: AccessLevel) -> &mut Self {
         self.instruction.access_level = Some(access_level);
         self
+      }
+      #[inline(always)]
+      pub fn max_confidence_bps(&mut self, max_confidence_bps: u16) -> &mut Self {
+        self.instruction.max_confidence_bps = Some(max_confidence_bps);
+        self
+      }
+                /// `[optional argument]`
+#[inline(always)]
+      pub fn oracle_max_age(&mut self, oracle_max_age: u64) -> &mut Self {
+        self.instruction.oracle_max_age = Some(oracle_max_age);
+        self
+      }
+      /// `[optional argument]`
+      #[inline(always)]
+      pub fn fixed_price(&mut self, fixed_price: FixedPrice) -> &mut Self {
+        self.instruction.fixed_price = Some(fixed_price);
+        self
       }
         /// Add an additional account to the instruction.
   #[inline(always)]
@@ -475,6 +540,9 @@ impl<'a, 'b> AddAssetCpiBuilder<'a, 'b> {
   pub fn invoke_signed(&self, signers_seeds: &[&[&[u8]]]) -> solana_program_error::ProgramResult {
           let args = AddAssetInstructionArgs {
                                                               access_level: self.instruction.access_level.clone().expect("access_level is not set"),
+                                                              max_confidence_bps: self.instruction.max_confidence_bps.clone().expect("max_confidence_bps is not set"),
+                                                              oracle_max_age: self.instruction.oracle_max_age.clone(),
+                                                              fixed_price: self.instruction.fixed_price.clone(),
                                     };
         let instruction = AddAssetCpi {
         __program: self.instruction.__program,
@@ -489,7 +557,7 @@ impl<'a, 'b> AddAssetCpiBuilder<'a, 'b> {
                   
           asset_mint: self.instruction.asset_mint.expect("asset_mint is not set"),
                   
-          oracle: self.instruction.oracle.expect("oracle is not set"),
+          oracle: self.instruction.oracle,
                   
           system_program: self.instruction.system_program.expect("system_program is not set"),
                           __args: args,
@@ -509,6 +577,9 @@ struct AddAssetCpiBuilderInstruction<'a, 'b> {
                 oracle: Option<&'b solana_account_info::AccountInfo<'a>>,
                 system_program: Option<&'b solana_account_info::AccountInfo<'a>>,
                         access_level: Option<AccessLevel>,
+                        max_confidence_bps: Option<u16>,
+                        oracle_max_age: Option<u64>,
+                        fixed_price: Option<FixedPrice>,
         /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
   __remaining_accounts: Vec<(&'b solana_account_info::AccountInfo<'a>, bool, bool)>,
 }
@@ -27,6 +27,9 @@ pub struct Deposit {
           pub liquidity_pool: solana_pubkey::Pubkey,
           
               
+          pub reward_pool: solana_pubkey::Pubkey,
+          
+              
           pub lp_token: solana_pubkey::Pubkey,
           
               
@@ -45,7 +48,7 @@ pub struct Deposit {
           pub pool_asset_account: solana_pubkey::Pubkey,
           
               
-          pub oracle: solana_pubkey::Pubkey,
+          pub oracle: Option<solana_pubkey::Pubkey>,
           
               
           pub token_program: solana_pubkey::Pubkey,
@@ -87,6 +90,10 @@ impl Deposit {
                                                     accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.liquidity_pool,
             false
+          ));
+                                                    accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.reward_pool,
+            false
           ));
                                           accounts.push(solana_instruction::AccountMeta::new(
             self.lp_token,
@@ -112,10 +119,17 @@ impl Deposit {
             self.pool_asset_account,
             false
           ));
-                                          accounts.push(solana_instruction::AccountMeta::new_readonly(
-            self.oracle,
-            false
-          ));
+                                          if let Some(oracle) = self.oracle {
+              accounts.push(solana_instruction::AccountMeta::new_readonly(
+                oracle,
+                false,
+              ));
+            } else {
+              accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::RLP_ID,
+                false,
+              ));
+            }
                                           accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.token_program,
             false
@@ -188,22 +202,24 @@ impl DepositInstructionArgs {
           ///   1. `[]` settings
                 ///   2. `[optional]` permissions
           ///   3. `[]` liquidity_pool
-                ///   4. `[writable]` lp_token
-                ///   5. `[writable]` user_lp_account
-          ///   6. `[]` asset
-                ///   7. `[writable]` asset_mint
-                ///   8. `[writable]` user_asset_account
-                ///   9. `[writable]` pool_asset_account
-          ///   10. `[]` oracle
-                ///   11. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
-                ///   12. `[optional]` associated_token_program (default to `ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL`)
-                ///   13. `[optional]` system_program (default to `11111111111111111111111111111111`)
+          ///   4. `[]` reward_pool
+                ///   5. `[writable]` lp_token
+                ///   6. `[writable]` user_lp_account
+          ///   7. `[]` asset
+                ///   8. `[writable]` asset_mint
+                ///   9. `[writable]` user_asset_account
+                ///   10. `[writable]` pool_asset_account
+          ///   11. `[optional]` oracle
+                ///   12. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
+                ///   13. `[optional]` associated_token_program (default to `ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL`)
+                ///   14. `[optional]` system_program (default to `11111111111111111111111111111111`)
 #[derive(Clone, Debug, Default)]
 pub struct DepositBuilder {
             signer: Option<solana_pubkey::Pubkey>,
                 settings: Option<solana_pubkey::Pubkey>,
                 permissions: Option<solana_pubkey::Pubkey>,
                 liquidity_pool: Option<solana_pubkey::Pubkey>,
+                reward_pool: Option<solana_pubkey::Pubkey>,
                 lp_token: Option<solana_pubkey::Pubkey>,
                 user_lp_account: Option<solana_pubkey::Pubkey>,
                 asset: Option<solana_pubkey::Pubkey>,
@@ -246,6 +262,11 @@ impl DepositBuilder {
                     self
     }
             #[inline(always)]
+    pub fn reward_pool(&mut self, reward_pool: solana_pubkey::Pubkey) -> &mut Self {
+                        self.reward_pool = Some(reward_pool);
+                    self
+    }
+            #[inline(always)]
     pub fn lp_token(&mut self, lp_token: solana_pubkey::Pubkey) -> &mut Self {
                         self.lp_token = Some(lp_token);
                     self
@@ -275,9 +296,10 @@ impl DepositBuilder {
                         self.pool_asset_account = Some(pool_asset_account);
                     self
     }
-            #[inline(always)]
-    pub fn oracle(&mut self, oracle: solana_pubkey::Pubkey) -> &mut Self {
-                        self.oracle = Some(oracle);
+            /// `[optional account]`
+#[inline(always)]
+    pub fn oracle(&mut self, oracle: Option<solana_pubkey::Pubkey>) -> &mut Self {
+                        self.oracle = oracle;
                     self
     }
             /// `[optional account, default to 'TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA']`
@@ -332,13 +354,14 @@ impl DepositBuilder {
                                         settings: self.settings.expect("settings is not set"),
                                         permissions: self.permissions,
                                         liquidity_pool: self.liquidity_pool.expect("liquidity_pool is not set"),
+                                        reward_pool: self.reward_pool.expect("reward_pool is not set"),
                                         lp_token: self.lp_token.expect("lp_token is not set"),
                                         user_lp_account: self.user_lp_account.expect("user_lp_account is not set"),
                                         asset: self.asset.expect("asset is not set"),
                                         asset_mint: self.asset_mint.expect("asset_mint is not set"),
                                         user_asset_account: self.user_asset_account.expect("user_asset_account is not set"),
                                         pool_asset_account: self.pool_asset_account.expect("pool_asset_account is not set"),
-                                        oracle: self.oracle.expect("oracle is not set"),
+                                        oracle: self.oracle,
                                         token_program: self.token_program.unwrap_or(solana_pubkey::pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA")),
                                         associated_token_program: self.associated_token_program.unwrap_or(solana_pubkey::pubkey!("ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL")),
                                         system_program: self.system_program.unwrap_or(solana_pubkey::pubkey!("11111111111111111111111111111111")),
@@ -369,6 +392,9 @@ impl DepositBuilder {
               pub liquidity_pool: &'b solana_account_info::AccountInfo<'a>,
                 
                     
+              pub reward_pool: &'b solana_account_info::AccountInfo<'a>,
+                
+                    
               pub lp_token: &'b solana_account_info::AccountInfo<'a>,
                 
                     
@@ -387,7 +413,7 @@ impl DepositBuilder {
               pub pool_asset_account: &'b solana_account_info::AccountInfo<'a>,
                 
                     
-              pub oracle: &'b solana_account_info::AccountInfo<'a>,
+              pub oracle: Option<&'b solana_account_info::AccountInfo<'a>>,
                 
                     
               pub token_program: &'b solana_account_info::AccountInfo<'a>,
@@ -417,6 +443,9 @@ pub struct DepositCpi<'a, 'b> {
           pub liquidity_pool: &'b solana_account_info::AccountInfo<'a>,
           
               
+          pub reward_pool: &'b solana_account_info::AccountInfo<'a>,
+          
+              
           pub lp_token: &'b solana_account_info::AccountInfo<'a>,
           
               
@@ -435,7 +464,7 @@ pub struct DepositCpi<'a, 'b> {
           pub pool_asset_account: &'b solana_account_info::AccountInfo<'a>,
           
               
-          pub oracle: &'b solana_account_info::AccountInfo<'a>,
+          pub oracle: Option<&'b solana_account_info::AccountInfo<'a>>,
           
               
           pub token_program: &'b solana_account_info::AccountInfo<'a>,
@@ -461,6 +490,7 @@ impl<'a, 'b> DepositCpi<'a, 'b> {
               settings: accounts.settings,
               permissions: accounts.permissions,
               liquidity_pool: accounts.liquidity_pool,
+              reward_pool: accounts.reward_pool,
               lp_token: accounts.lp_token,
               user_lp_account: accounts.user_lp_account,
               asset: accounts.asset,
@@ -517,6 +547,10 @@ impl<'a, 'b> DepositCpi<'a, 'b> {
                                           accounts.push(solana_instruction::AccountMeta::new_readonly(
             *self.liquidity_pool.key,
             false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.reward_pool.key,
+            false
           ));
                                           accounts.push(solana_instruction::AccountMeta::new(
             *self.lp_token.key,
@@ -542,10 +576,17 @@ impl<'a, 'b> DepositCpi<'a, 'b> {
             *self.pool_asset_account.key,
             false
           ));
-                                          accounts.push(solana_instruction::AccountMeta::new_readonly(
-            *self.oracle.key,
-            false
-          ));
+                                          if let Some(oracle) = self.oracle {
+              accounts.push(solana_instruction::AccountMeta::new_readonly(
+                *oracle.key,
+                false,
+              ));
+            } else {
+              accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::RLP_ID,
+                false,
+              ));
+            }
                                           accounts.push(solana_instruction::AccountMeta::new_readonly(
             *self.token_program.key,
             false
@@ -582,13 +623,16 @@ impl<'a, 'b> DepositCpi<'a, 'b> {
           account_infos.push(permissions.clone());
         }
                         account_infos.push(self.liquidity_pool.clone());
+                        account_infos.push(self.reward_pool.clone());
                         account_infos.push(self.lp_token.clone());
                         account_infos.push(self.user_lp_account.clone());
                         account_infos.push(self.asset.clone());
                         account_infos.push(self.asset_mint.clone());
                         account_infos.push(self.user_asset_account.clone());
                         account_infos.push(self.pool_asset_account.clone());
-                        account_infos.push(self.oracle.clone());
+                        if let Some(oracle) = self.oracle {
+          account_infos.push(oracle.clone());
+        }
                         account_infos.push(self.token_program.clone());
                         account_infos.push(self.associated_token_program.clone());
                         account_infos.push(self.system_program.clone());
@@ -610,16 +654,17 @@ impl<'a, 'b> DepositCpi<'a, 'b> {
           ///   1. `[]` settings
                 ///   2. `[optional]` permissions
           ///   3. `[]` liquidity_pool
-                ///   4. `[writable]` lp_token
-                ///   5. `[writable]` user_lp_account
-          ///   6. `[]` asset
-                ///   7. `[writable]` asset_mint
-                ///   8. `[writable]` user_asset_account
-                ///   9. `[writable]` pool_asset_account
-          ///   10. `[]` oracle
-          ///   11. `[]` token_program
-          ///   12. `[]` associated_token_program
-          ///   13. `[]` system_program
+          ///   4. `[]` reward_pool
+                ///   5. `[writable]` lp_token
+                ///   6. `[writable]` user_lp_account
+          ///   7. `[]` asset
+                ///   8. `[writable]` asset_mint
+                ///   9. `[writable]` user_asset_account
+                ///   10. `[writable]` pool_asset_account
+          ///   11. `[optional]` oracle
+          ///   12. `[]` token_program
+          ///   13. `[]` associated_token_program
+          ///   14. `[]` system_program
 #[derive(Clone, Debug)]
 pub struct DepositCpiBuilder<'a, 'b> {
   instruction: Box<DepositCpiBuilderInstruction<'a, 'b>>,
@@ -633,6 +678,7 @@ impl<'a, 'b> DepositCpiBuilder<'a, 'b> {
               settings: None,
               permissions: None,
               liquidity_pool: None,
+              reward_pool: None,
               lp_token: None,
               user_lp_account: None,
               asset: None,
@@ -672,6 +718,11 @@ impl<'a, 'b> DepositCpiBuilder<'a, 'b> {
                     self
     }
       #[inline(always)]
+    pub fn reward_pool(&mut self, reward_pool: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+                        self.instruction.reward_pool = Some(reward_pool);
+                    self
+    }
+      #[inline(always)]
     pub fn lp_token(&mut self, lp_token: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
                         self.instruction.lp_token = Some(lp_token);
                     self
@@ -701,9 +752,10 @@ impl<'a, 'b> DepositCpiBuilder<'a, 'b> {
                         self.instruction.pool_asset_account = Some(pool_asset_account);
                     self
     }
-      #[inline(always)]
-    pub fn oracle(&mut self, oracle: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
-                        self.instruction.oracle = Some(oracle);
+      /// `[optional account]`
+#[inline(always)]
+    pub fn oracle(&mut self, oracle: Option<&'b solana_account_info::AccountInfo<'a>>) -> &mut Self {
+                        self.instruction.oracle = oracle;
                     self
     }
       #[inline(always)]
@@ -774,6 +826,8 @@ impl<'a, 'b> DepositCpiBuilder<'a, 'b> {
                   
           liquidity_pool: self.instruction.liquidity_pool.expect("liquidity_pool is not set"),
                   
+          reward_pool: self.instruction.reward_pool.expect("reward_pool is not set"),
+                  
           lp_token: self.instruction.lp_token.expect("lp_token is not set"),
                   
           user_lp_account: self.instruction.user_lp_account.expect("user_lp_account is not set"),
@@ -786,7 +840,7 @@ impl<'a, 'b> DepositCpiBuilder<'a, 'b> {
                   
           pool_asset_account: self.instruction.pool_asset_account.expect("pool_asset_account is not set"),
                   
-          oracle: self.instruction.oracle.expect("oracle is not set"),
+          oracle: self.instruction.oracle,
                   
           token_program: self.instruction.token_program.expect("token_program is not set"),
                   
@@ -806,6 +860,7 @@ struct DepositCpiBuilderInstruction<'a, 'b> {
                 settings: Option<&'b solana_account_info::AccountInfo<'a>>,
                 permissions: Option<&'b solana_account_info::AccountInfo<'a>>,
                 liquidity_pool: Option<&'b solana_account_info::AccountInfo<'a>>,
+                reward_pool: Option<&'b solana_account_info::AccountInfo<'a>>,
                 lp_token: Option<&'b solana_account_info::AccountInfo<'a>>,
                 user_lp_account: Option<&'b solana_account_info::AccountInfo<'a>>,
                 asset: Option<&'b solana_account_info::AccountInfo<'a>>,
@@ -6,30 +6,90 @@
 //!
 
   pub(crate) mod r#add_asset;
+  pub(crate) mod r#add_asset_oracle;
+  pub(crate) mod r#add_pool_asset;
+  pub(crate) mod r#cancel_withdrawal_request;
+  pub(crate) mod r#claim_protocol_fees;
   pub(crate) mod r#create_permission_account;
   pub(crate) mod r#deposit;
+  pub(crate) mod r#deposit_proportional;
+  pub(crate) mod r#deposit_rewards;
   pub(crate) mod r#freeze_functionality;
   pub(crate) mod r#initialize_lp;
+  pub(crate) mod r#initialize_reward_pool;
   pub(crate) mod r#initialize_rlp;
+  pub(crate) mod r#merge_cooldowns;
+  pub(crate) mod r#migrate_asset;
+  pub(crate) mod r#migrate_liquidity_pool;
+  pub(crate) mod r#migrate_settings;
+  pub(crate) mod r#quote_deposit;
+  pub(crate) mod r#quote_swap;
+  pub(crate) mod r#quote_withdraw;
+  pub(crate) mod r#remove_asset_oracle;
+  pub(crate) mod r#remove_pool_asset;
   pub(crate) mod r#request_withdrawal;
   pub(crate) mod r#slash;
   pub(crate) mod r#swap;
+  pub(crate) mod r#swap_exact_out;
+  pub(crate) mod r#swap_route;
   pub(crate) mod r#update_action_role;
+  pub(crate) mod r#update_asset;
+  pub(crate) mod r#update_asset_limits;
+  pub(crate) mod r#update_cooldown_duration;
   pub(crate) mod r#update_deposit_cap;
+  pub(crate) mod r#update_deposit_value_cap;
+  pub(crate) mod r#update_oracle_max_age;
+  pub(crate) mod r#update_pool_swap_fee;
+  pub(crate) mod r#update_protocol_fee;
   pub(crate) mod r#update_role_holder;
+  pub(crate) mod r#update_swap_fee;
+  pub(crate) mod r#update_target_weights;
+  pub(crate) mod r#update_vesting_duration;
   pub(crate) mod r#withdraw;
+  pub(crate) mod r#withdraw_partial;
+  pub(crate) mod r#withdraw_single_asset;
 
   pub use self::r#add_asset::*;
+  pub use self::r#add_asset_oracle::*;
+  pub use self::r#add_pool_asset::*;
+  pub use self::r#cancel_withdrawal_request::*;
+  pub use self::r#claim_protocol_fees::*;
   pub use self::r#create_permission_account::*;
   pub use self::r#deposit::*;
+  pub use self::r#deposit_proportional::*;
+  pub use self::r#deposit_rewards::*;
   pub use self::r#freeze_functionality::*;
   pub use self::r#initialize_lp::*;
+  pub use self::r#initialize_reward_pool::*;
   pub use self::r#initialize_rlp::*;
+  pub use self::r#merge_cooldowns::*;
+  pub use self::r#migrate_asset::*;
+  pub use self::r#migrate_liquidity_pool::*;
+  pub use self::r#migrate_settings::*;
+  pub use self::r#quote_deposit::*;
+  pub use self::r#quote_swap::*;
+  pub use self::r#quote_withdraw::*;
+  pub use self::r#remove_asset_oracle::*;
+  pub use self::r#remove_pool_asset::*;
   pub use self::r#request_withdrawal::*;
   pub use self::r#slash::*;
   pub use self::r#swap::*;
+  pub use self::r#swap_exact_out::*;
+  pub use self::r#swap_route::*;
   pub use self::r#update_action_role::*;
+  pub use self::r#update_asset::*;
+  pub use self::r#update_asset_limits::*;
+  pub use self::r#update_cooldown_duration::*;
   pub use self::r#update_deposit_cap::*;
+  pub use self::r#update_deposit_value_cap::*;
+  pub use self::r#update_oracle_max_age::*;
+  pub use self::r#update_pool_swap_fee::*;
+  pub use self::r#update_protocol_fee::*;
   pub use self::r#update_role_holder::*;
+  pub use self::r#update_swap_fee::*;
+  pub use self::r#update_target_weights::*;
+  pub use self::r#update_vesting_duration::*;
   pub use self::r#withdraw::*;
+  pub use self::r#withdraw_partial::*;
+  pub use self::r#withdraw_single_asset::*;
 
@@ -27,13 +27,16 @@ pub struct Swap {
           pub liquidity_pool: solana_pubkey::Pubkey,
           
               
+          pub reward_pool: solana_pubkey::Pubkey,
+          
+              
           pub token_from: solana_pubkey::Pubkey,
           
               
           pub token_from_asset: solana_pubkey::Pubkey,
           
               
-          pub token_from_oracle: solana_pubkey::Pubkey,
+          pub token_from_oracle: Option<solana_pubkey::Pubkey>,
           
               
           pub token_to: solana_pubkey::Pubkey,
@@ -42,7 +45,7 @@ pub struct Swap {
           pub token_to_asset: solana_pubkey::Pubkey,
           
               
-          pub token_to_oracle: solana_pubkey::Pubkey,
+          pub token_to_oracle: Option<solana_pubkey::Pubkey>,
           
               
           pub token_from_pool: solana_pubkey::Pubkey,
@@ -90,22 +93,33 @@ impl Swap {
             self.settings,
             false
           ));
-                                          accounts.push(solana_instruction::AccountMeta::new_readonly(
+                                          accounts.push(solana_instruction::AccountMeta::new(
             self.liquidity_pool,
             false
           ));
                                           accounts.push(solana_instruction::AccountMeta::new_readonly(
-            self.token_from,
+            self.reward_pool,
             false
           ));
                                           accounts.push(solana_instruction::AccountMeta::new_readonly(
-            self.token_from_asset,
+            self.token_from,
             false
           ));
                                           accounts.push(solana_instruction::AccountMeta::new_readonly(
-            self.token_from_oracle,
+            self.token_from_asset,
             false
           ));
+                                          if let Some(token_from_oracle) = self.token_from_oracle {
+              accounts.push(solana_instruction::AccountMeta::new_readonly(
+                token_from_oracle,
+                false,
+              ));
+            } else {
+              accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::RLP_ID,
+                false,
+              ));
+            }
                                           accounts.push(solana_instruction::AccountMeta::new_readonly(
             self.token_to,
             false
@@ -114,10 +128,17 @@ impl Swap {
             self.token_to_asset,
             false
           ));
-                                          accounts.push(solana_instruction::AccountMeta::new_readonly(
-            self.token_to_oracle,
-            false
-          ));
+                                          if let Some(token_to_oracle) = self.token_to_oracle {
+              accounts.push(solana_instruction::AccountMeta::new_readonly(
+                token_to_oracle,
+                false,
+              ));
+            } else {
+              accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::RLP_ID,
+                false,
+              ));
+            }
                                           accounts.push(solana_instruction::AccountMeta::new(
             self.token_from_pool,
             false
@@ -200,25 +221,27 @@ impl SwapInstructionArgs {
                       ///   0. `[writable, signer]` signer
                 ///   1. `[optional]` admin
           ///   2. `[]` settings
-          ///   3. `[]` liquidity_pool
-          ///   4. `[]` token_from
-          ///   5. `[]` token_from_asset
-          ///   6. `[]` token_from_oracle
-          ///   7. `[]` token_to
-          ///   8. `[]` token_to_asset
-          ///   9. `[]` token_to_oracle
-                ///   10. `[writable]` token_from_pool
-                ///   11. `[writable]` token_to_pool
-                ///   12. `[writable]` token_from_signer_account
-                ///   13. `[writable]` token_to_signer_account
-                ///   14. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
-                ///   15. `[optional]` associated_token_program (default to `ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL`)
+                ///   3. `[writable]` liquidity_pool
+          ///   4. `[]` reward_pool
+          ///   5. `[]` token_from
+          ///   6. `[]` token_from_asset
+          ///   7. `[optional]` token_from_oracle
+          ///   8. `[]` token_to
+          ///   9. `[]` token_to_asset
+          ///   10. `[optional]` token_to_oracle
+                ///   11. `[writable]` token_from_pool
+                ///   12. `[writable]` token_to_pool
+                ///   13. `[writable]` token_from_signer_account
+                ///   14. `[writable]` token_to_signer_account
+                ///   15. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
+                ///   16. `[optional]` associated_token_program (default to `ATokenGPvbdGVxr1b2hvZbsiqW5xWH25efTNsLJA8knL`)
 #[derive(Clone, Debug, Default)]
 pub struct SwapBuilder {
             signer: Option<solana_pubkey::Pubkey>,
                 admin: Option<solana_pubkey::Pubkey>,
                 settings: Option<solana_pubkey::Pubkey>,
                 liquidity_pool: Option<solana_pubkey::Pubkey>,
+                reward_pool: Option<solana_pubkey::Pubkey>,
                 token_from: Option<solana_pubkey::Pubkey>,
                 token_from_asset: Option<solana_pubkey::Pubkey>,
                 token_from_oracle: Option<solana_pubkey::Pubkey>,
@@ -262,6 +285,11 @@ impl SwapBuilder {
                     self
     }
             #[inline(always)]
+    pub fn reward_pool(&mut self, reward_pool: solana_pubkey::Pubkey) -> &mut Self {
+                        self.reward_pool = Some(reward_pool);
+                    self
+    }
+            #[inline(always)]
     pub fn token_from(&mut self, token_from: solana_pubkey::Pubkey) -> &mut Self {
                         self.token_from = Some(token_from);
                     self
@@ -271,9 +299,10 @@ impl SwapBuilder {
                         self.token_from_asset = Some(token_from_asset);
                     self
     }
-            #[inline(always)]
-    pub fn token_from_oracle(&mut self, token_from_oracle: solana_pubkey::Pubkey) -> &mut Self {
-                        self.token_from_oracle = Some(token_from_oracle);
+            /// `[optional account]`
+#[inline(always)]
+    pub fn token_from_oracle(&mut self, token_from_oracle: Option<solana_pubkey::Pubkey>) -> &mut Self {
+                        self.token_from_oracle = token_from_oracle;
                     self
     }
             #[inline(always)]
@@ -286,9 +315,10 @@ impl SwapBuilder {
                         self.token_to_asset = Some(token_to_asset);
                     self
     }
-            #[inline(always)]
-    pub fn token_to_oracle(&mut self, token_to_oracle: solana_pubkey::Pubkey) -> &mut Self {
-                        self.token_to_oracle = Some(token_to_oracle);
+            /// `[optional account]`
+#[inline(always)]
+    pub fn token_to_oracle(&mut self, token_to_oracle: Option<solana_pubkey::Pubkey>) -> &mut Self {
+                        self.token_to_oracle = token_to_oracle;
                     self
     }
             #[inline(always)]
@@ -353,12 +383,13 @@ impl SwapBuilder {
                                         admin: self.admin,
                                         settings: self.settings.expect("settings is not set"),
                                         liquidity_pool: self.liquidity_pool.expect("liquidity_pool is not set"),
+                                        reward_pool: self.reward_pool.expect("reward_pool is not set"),
                                         token_from: self.token_from.expect("token_from is not set"),
                                         token_from_asset: self.token_from_asset.expect("token_from_asset is not set"),
-                                        token_from_oracle: self.token_from_oracle.expect("token_from_oracle is not set"),
+                                        token_from_oracle: self.token_from_oracle,
                                         token_to: self.token_to.expect("token_to is not set"),
                                         token_to_asset: self.token_to_asset.expect("token_to_asset is not set"),
-                                        token_to_oracle: self.token_to_oracle.expect("token_to_oracle is not set"),
+                                        token_to_oracle: self.token_to_oracle,
                                         token_from_pool: self.token_from_pool.expect("token_from_pool is not set"),
                                         token_to_pool: self.token_to_pool.expect("token_to_pool is not set"),
                                         token_from_signer_account: self.token_from_signer_account.expect("token_from_signer_account is not set"),
@@ -391,13 +422,16 @@ impl SwapBuilder {
               pub liquidity_pool: &'b solana_account_info::AccountInfo<'a>,
                 
                     
+              pub reward_pool: &'b solana_account_info::AccountInfo<'a>,
+                
+                    
               pub token_from: &'b solana_account_info::AccountInfo<'a>,
                 
                     
               pub token_from_asset: &'b solana_account_info::AccountInfo<'a>,
                 
                     
-              pub token_from_oracle: &'b solana_account_info::AccountInfo<'a>,
+              pub token_from_oracle: Option<&'b solana_account_info::AccountInfo<'a>>,
                 
                     
               pub token_to: &'b solana_account_info::AccountInfo<'a>,
@@ -406,7 +440,7 @@ impl SwapBuilder {
               pub token_to_asset: &'b solana_account_info::AccountInfo<'a>,
                 
                     
-              pub token_to_oracle: &'b solana_account_info::AccountInfo<'a>,
+              pub token_to_oracle: Option<&'b solana_account_info::AccountInfo<'a>>,
                 
                     
               pub token_from_pool: &'b solana_account_info::AccountInfo<'a>,
@@ -445,13 +479,16 @@ pub struct SwapCpi<'a, 'b> {
           pub liquidity_pool: &'b solana_account_info::AccountInfo<'a>,
           
               
+          pub reward_pool: &'b solana_account_info::AccountInfo<'a>,
+          
+              
           pub token_from: &'b solana_account_info::AccountInfo<'a>,
           
               
           pub token_from_asset: &'b solana_account_info::AccountInfo<'a>,
           
               
-          pub token_from_oracle: &'b solana_account_info::AccountInfo<'a>,
+          pub token_from_oracle: Option<&'b solana_account_info::AccountInfo<'a>>,
           
               
           pub token_to: &'b solana_account_info::AccountInfo<'a>,
@@ -460,7 +497,7 @@ pub struct SwapCpi<'a, 'b> {
           pub token_to_asset: &'b solana_account_info::AccountInfo<'a>,
           
               
-          pub token_to_oracle: &'b solana_account_info::AccountInfo<'a>,
+          pub token_to_oracle: Option<&'b solana_account_info::AccountInfo<'a>>,
           
               
           pub token_from_pool: &'b solana_account_info::AccountInfo<'a>,
@@ -495,6 +532,7 @@ impl<'a, 'b> SwapCpi<'a, 'b> {
               admin: accounts.admin,
               settings: accounts.settings,
               liquidity_pool: accounts.liquidity_pool,
+              reward_pool: accounts.reward_pool,
               token_from: accounts.token_from,
               token_from_asset: accounts.token_from_asset,
               token_from_oracle: accounts.token_from_oracle,
@@ -550,22 +588,33 @@ impl<'a, 'b> SwapCpi<'a, 'b> {
             *self.settings.key,
             false
           ));
-                                          accounts.push(solana_instruction::AccountMeta::new_readonly(
+                                          accounts.push(solana_instruction::AccountMeta::new(
             *self.liquidity_pool.key,
             false
           ));
                                           accounts.push(solana_instruction::AccountMeta::new_readonly(
-            *self.token_from.key,
+            *self.reward_pool.key,
             false
           ));
                                           accounts.push(solana_instruction::AccountMeta::new_readonly(
-            *self.token_from_asset.key,
+            *self.token_from.key,
             false
           ));
                                           accounts.push(solana_instruction::AccountMeta::new_readonly(
-            *self.token_from_oracle.key,
+            *self.token_from_asset.key,
             false
           ));
+                                          if let Some(token_from_oracle) = self.token_from_oracle {
+              accounts.push(solana_instruction::AccountMeta::new_readonly(
+                *token_from_oracle.key,
+                false,
+              ));
+            } else {
+              accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::RLP_ID,
+                false,
+              ));
+            }
                                           accounts.push(solana_instruction::AccountMeta::new_readonly(
             *self.token_to.key,
             false
@@ -574,10 +623,17 @@ impl<'a, 'b> SwapCpi<'a, 'b> {
             *self.token_to_asset.key,
             false
           ));
-                                          accounts.push(solana_instruction::AccountMeta::new_readonly(
-            *self.token_to_oracle.key,
-            false
-          ));
+                                          if let Some(token_to_oracle) = self.token_to_oracle {
+              accounts.push(solana_instruction::AccountMeta::new_readonly(
+                *token_to_oracle.key,
+                false,
+              ));
+            } else {
+              accounts.push(solana_instruction::AccountMeta::new_readonly(
+                crate::RLP_ID,
+                false,
+              ));
+            }
                                           accounts.push(solana_instruction::AccountMeta::new(
             *self.token_from_pool.key,
             false
@@ -626,12 +682,17 @@ impl<'a, 'b> SwapCpi<'a, 'b> {
         }
                         account_infos.push(self.settings.clone());
                         account_infos.push(self.liquidity_pool.clone());
+                        account_infos.push(self.reward_pool.clone());
                         account_infos.push(self.token_from.clone());
                         account_infos.push(self.token_from_asset.clone());
-                        account_infos.push(self.token_from_oracle.clone());
+                        if let Some(token_from_oracle) = self.token_from_oracle {
+          account_infos.push(token_from_oracle.clone());
+        }
                         account_infos.push(self.token_to.clone());
                         account_infos.push(self.token_to_asset.clone());
-                        account_infos.push(self.token_to_oracle.clone());
+                        if let Some(token_to_oracle) = self.token_to_oracle {
+          account_infos.push(token_to_oracle.clone());
+        }
                         account_infos.push(self.token_from_pool.clone());
                         account_infos.push(self.token_to_pool.clone());
                         account_infos.push(self.token_from_signer_account.clone());
@@ -655,19 +716,20 @@ impl<'a, 'b> SwapCpi<'a, 'b> {
                       ///   0. `[writable, signer]` signer
                 ///   1. `[optional]` admin
           ///   2. `[]` settings
-          ///   3. `[]` liquidity_pool
-          ///   4. `[]` token_from
-          ///   5. `[]` token_from_asset
-          ///   6. `[]` token_from_oracle
-          ///   7. `[]` token_to
-          ///   8. `[]` token_to_asset
-          ///   9. `[]` token_to_oracle
-                ///   10. `[writable]` token_from_pool
-                ///   11. `[writable]` token_to_pool
-                ///   12. `[writable]` token_from_signer_account
-                ///   13. `[writable]` token_to_signer_account
-          ///   14. `[]` token_program
-          ///   15. `[]` associated_token_program
+                ///   3. `[writable]` liquidity_pool
+          ///   4. `[]` reward_pool
+          ///   5. `[]` token_from
+          ///   6. `[]` token_from_asset
+          ///   7. `[optional]` token_from_oracle
+          ///   8. `[]` token_to
+          ///   9. `[]` token_to_asset
+          ///   10. `[optional]` token_to_oracle
+                ///   11. `[writable]` token_from_pool
+                ///   12. `[writable]` token_to_pool
+                ///   13. `[writable]` token_from_signer_account
+                ///   14. `[writable]` token_to_signer_account
+          ///   15. `[]` token_program
+          ///   16. `[]` associated_token_program
 #[derive(Clone, Debug)]
 pub struct SwapCpiBuilder<'a, 'b> {
   instruction: Box<SwapCpiBuilderInstruction<'a, 'b>>,
@@ -681,6 +743,7 @@ impl<'a, 'b> SwapCpiBuilder<'a, 'b> {
               admin: None,
               settings: None,
               liquidity_pool: None,
+              reward_pool: None,
               token_from: None,
               token_from_asset: None,
               token_from_oracle: None,
@@ -721,6 +784,11 @@ impl<'a, 'b> SwapCpiBuilder<'a, 'b> {
                     self
     }
       #[inline(always)]
+    pub fn reward_pool(&mut self, reward_pool: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+                        self.instruction.reward_pool = Some(reward_pool);
+                    self
+    }
+      #[inline(always)]
     pub fn token_from(&mut self, token_from: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
                         self.instruction.token_from = Some(token_from);
                     self
@@ -730,9 +798,10 @@ impl<'a, 'b> SwapCpiBuilder<'a, 'b> {
                         self.instruction.token_from_asset = Some(token_from_asset);
                     self
     }
-      #[inline(always)]
-    pub fn token_from_oracle(&mut self, token_from_oracle: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
-                        self.instruction.token_from_oracle = Some(token_from_oracle);
+      /// `[optional account]`
+#[inline(always)]
+    pub fn token_from_oracle(&mut self, token_from_oracle: Option<&'b solana_account_info::AccountInfo<'a>>) -> &mut Self {
+                        self.instruction.token_from_oracle = token_from_oracle;
                     self
     }
       #[inline(always)]
@@ -745,9 +814,10 @@ impl<'a, 'b> SwapCpiBuilder<'a, 'b> {
                         self.instruction.token_to_asset = Some(token_to_asset);
                     self
     }
-      #[inline(always)]
-    pub fn token_to_oracle(&mut self, token_to_oracle: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
-                        self.instruction.token_to_oracle = Some(token_to_oracle);
+      /// `[optional account]`
+#[inline(always)]
+    pub fn token_to_oracle(&mut self, token_to_oracle: Option<&'b solana_account_info::AccountInfo<'a>>) -> &mut Self {
+                        self.instruction.token_to_oracle = token_to_oracle;
                     self
     }
       #[inline(always)]
@@ -828,17 +898,19 @@ impl<'a, 'b> SwapCpiBuilder<'a, 'b> {
                   
           liquidity_pool: self.instruction.liquidity_pool.expect("liquidity_pool is not set"),
                   
+          reward_pool: self.instruction.reward_pool.expect("reward_pool is not set"),
+                  
           token_from: self.instruction.token_from.expect("token_from is not set"),
                   
           token_from_asset: self.instruction.token_from_asset.expect("token_from_asset is not set"),
                   
-          token_from_oracle: self.instruction.token_from_oracle.expect("token_from_oracle is not set"),
+          token_from_oracle: self.instruction.token_from_oracle,
                   
           token_to: self.instruction.token_to.expect("token_to is not set"),
                   
           token_to_asset: self.instruction.token_to_asset.expect("token_to_asset is not set"),
                   
-          token_to_oracle: self.instruction.token_to_oracle.expect("token_to_oracle is not set"),
+          token_to_oracle: self.instruction.token_to_oracle,
                   
           token_from_pool: self.instruction.token_from_pool.expect("token_from_pool is not set"),
                   
@@ -864,6 +936,7 @@ struct SwapCpiBuilderInstruction<'a, 'b> {
                 admin: Option<&'b solana_account_info::AccountInfo<'a>>,
                 settings: Option<&'b solana_account_info::AccountInfo<'a>>,
                 liquidity_pool: Option<&'b solana_account_info::AccountInfo<'a>>,
+                reward_pool: Option<&'b solana_account_info::AccountInfo<'a>>,
                 token_from: Option<&'b solana_account_info::AccountInfo<'a>>,
                 token_from_asset: Option<&'b solana_account_info::AccountInfo<'a>>,
                 token_from_oracle: Option<&'b solana_account_info::AccountInfo<'a>>,
@@ -27,6 +27,9 @@ pub struct Withdraw {
           pub liquidity_pool: solana_pubkey::Pubkey,
           
               
+          pub reward_pool: solana_pubkey::Pubkey,
+          
+              
           pub lp_token_mint: solana_pubkey::Pubkey,
           
               
@@ -72,6 +75,10 @@ impl Withdraw {
                                                     accounts.push(solana_instruction::AccountMeta::new(
             self.liquidity_pool,
             false
+          ));
+                                                    accounts.push(solana_instruction::AccountMeta::new_readonly(
+            self.reward_pool,
+            false
           ));
                                           accounts.push(solana_instruction::AccountMeta::new(
             self.lp_token_mint,
@@ -159,17 +166,19 @@ impl WithdrawInstructionArgs {
                 ///   1. `[writable]` settings
                 ///   2. `[optional]` permissions
                 ///   3. `[writable]` liquidity_pool
-                ///   4. `[writable]` lp_token_mint
-                ///   5. `[writable]` cooldown_lp_token_account
-                ///   6. `[writable]` cooldown
-                ///   7. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
-                ///   8. `[optional]` system_program (default to `11111111111111111111111111111111`)
+                ///   4. `[]` reward_pool
+                ///   5. `[writable]` lp_token_mint
+                ///   6. `[writable]` cooldown_lp_token_account
+                ///   7. `[writable]` cooldown
+                ///   8. `[optional]` token_program (default to `TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA`)
+                ///   9. `[optional]` system_program (default to `11111111111111111111111111111111`)
 #[derive(Clone, Debug, Default)]
 pub struct WithdrawBuilder {
             signer: Option<solana_pubkey::Pubkey>,
                 settings: Option<solana_pubkey::Pubkey>,
                 permissions: Option<solana_pubkey::Pubkey>,
                 liquidity_pool: Option<solana_pubkey::Pubkey>,
+                reward_pool: Option<solana_pubkey::Pubkey>,
                 lp_token_mint: Option<solana_pubkey::Pubkey>,
                 cooldown_lp_token_account: Option<solana_pubkey::Pubkey>,
                 cooldown: Option<solana_pubkey::Pubkey>,
@@ -206,6 +215,11 @@ impl WithdrawBuilder {
                     self
     }
             #[inline(always)]
+    pub fn reward_pool(&mut self, reward_pool: solana_pubkey::Pubkey) -> &mut Self {
+                        self.reward_pool = Some(reward_pool);
+                    self
+    }
+            #[inline(always)]
     pub fn lp_token_mint(&mut self, lp_token_mint: solana_pubkey::Pubkey) -> &mut Self {
                         self.lp_token_mint = Some(lp_token_mint);
                     self
@@ -261,6 +275,7 @@ impl WithdrawBuilder {
                                         settings: self.settings.expect("settings is not set"),
                                         permissions: self.permissions,
                                         liquidity_pool: self.liquidity_pool.expect("liquidity_pool is not set"),
+                                        reward_pool: self.reward_pool.expect("reward_pool is not set"),
                                         lp_token_mint: self.lp_token_mint.expect("lp_token_mint is not set"),
                                         cooldown_lp_token_account: self.cooldown_lp_token_account.expect("cooldown_lp_token_account is not set"),
                                         cooldown: self.cooldown.expect("cooldown is not set"),
@@ -292,6 +307,9 @@ impl WithdrawBuilder {
               pub liquidity_pool: &'b solana_account_info::AccountInfo<'a>,
                 
                     
+              pub reward_pool: &'b solana_account_info::AccountInfo<'a>,
+                
+                    
               pub lp_token_mint: &'b solana_account_info::AccountInfo<'a>,
                 
                     
@@ -325,6 +343,9 @@ pub struct WithdrawCpi<'a, 'b> {
           pub liquidity_pool: &'b solana_account_info::AccountInfo<'a>,
           
               
+          pub reward_pool: &'b solana_account_info::AccountInfo<'a>,
+          
+              
           pub lp_token_mint: &'b solana_account_info::AccountInfo<'a>,
           
               
@@ -354,6 +375,7 @@ impl<'a, 'b> WithdrawCpi<'a, 'b> {
               settings: accounts.settings,
               permissions: accounts.permissions,
               liquidity_pool: accounts.liquidity_pool,
+              reward_pool: accounts.reward_pool,
               lp_token_mint: accounts.lp_token_mint,
               cooldown_lp_token_account: accounts.cooldown_lp_token_account,
               cooldown: accounts.cooldown,
@@ -405,6 +427,10 @@ impl<'a, 'b> WithdrawCpi<'a, 'b> {
                                           accounts.push(solana_instruction::AccountMeta::new(
             *self.liquidity_pool.key,
             false
+          ));
+                                          accounts.push(solana_instruction::AccountMeta::new_readonly(
+            *self.reward_pool.key,
+            false
           ));
                                           accounts.push(solana_instruction::AccountMeta::new(
             *self.lp_token_mint.key,
@@ -457,6 +483,7 @@ impl<'a, 'b> WithdrawCpi<'a, 'b> {
           account_infos.push(permissions.clone());
         }
                         account_infos.push(self.liquidity_pool.clone());
+                        account_infos.push(self.reward_pool.clone());
                         account_infos.push(self.lp_token_mint.clone());
                         account_infos.push(self.cooldown_lp_token_account.clone());
                         account_infos.push(self.cooldown.clone());
@@ -482,11 +509,12 @@ impl<'a, 'b> WithdrawCpi<'a, 'b> {
                 ///   1. `[writable]` settings
                 ///   2. `[optional]` permissions
                 ///   3. `[writable]` liquidity_pool
-                ///   4. `[writable]` lp_token_mint
-                ///   5. `[writable]` cooldown_lp_token_account
-                ///   6. `[writable]` cooldown
-          ///   7. `[]` token_program
-                ///   8. `[optional]` system_program
+                ///   4. `[]` reward_pool
+                ///   5. `[writable]` lp_token_mint
+                ///   6. `[writable]` cooldown_lp_token_account
+                ///   7. `[writable]` cooldown
+          ///   8. `[]` token_program
+                ///   9. `[optional]` system_program
 #[derive(Clone, Debug)]
 pub struct WithdrawCpiBuilder<'a, 'b> {
   instruction: Box<WithdrawCpiBuilderInstruction<'a, 'b>>,
@@ -500,6 +528,7 @@ impl<'a, 'b> WithdrawCpiBuilder<'a, 'b> {
               settings: None,
               permissions: None,
               liquidity_pool: None,
+              reward_pool: None,
               lp_token_mint: None,
               cooldown_lp_token_account: None,
               cooldown: None,
@@ -533,6 +562,11 @@ impl<'a, 'b> WithdrawCpiBuilder<'a, 'b> {
                     self
     }
       #[inline(always)]
+    pub fn reward_pool(&mut self, reward_pool: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
+                        self.instruction.reward_pool = Some(reward_pool);
+                    self
+    }
+      #[inline(always)]
     pub fn lp_token_mint(&mut self, lp_token_mint: &'b solana_account_info::AccountInfo<'a>) -> &mut Self {
                         self.instruction.lp_token_mint = Some(lp_token_mint);
                     self
@@ -605,6 +639,8 @@ impl<'a, 'b> WithdrawCpiBuilder<'a, 'b> {
                   
           liquidity_pool: self.instruction.liquidity_pool.expect("liquidity_pool is not set"),
                   
+          reward_pool: self.instruction.reward_pool.expect("reward_pool is not set"),
+                  
           lp_token_mint: self.instruction.lp_token_mint.expect("lp_token_mint is not set"),
                   
           cooldown_lp_token_account: self.instruction.cooldown_lp_token_account.expect("cooldown_lp_token_account is not set"),
@@ -627,6 +663,7 @@ struct WithdrawCpiBuilderInstruction<'a, 'b> {
                 settings: Option<&'b solana_account_info::AccountInfo<'a>>,
                 permissions: Option<&'b solana_account_info::AccountInfo<'a>>,
                 liquidity_pool: Option<&'b solana_account_info::AccountInfo<'a>>,
+                reward_pool: Option<&'b solana_account_info::AccountInfo<'a>>,
                 lp_token_mint: Option<&'b solana_account_info::AccountInfo<'a>>,
                 cooldown_lp_token_account: Option<&'b solana_account_info::AccountInfo<'a>>,
                 cooldown: Option<&'b solana_account_info::AccountInfo<'a>>,
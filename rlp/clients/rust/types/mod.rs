@@ -11,24 +11,58 @@
   pub(crate) mod r#action;
   pub(crate) mod r#action_mapping;
   pub(crate) mod r#add_asset_event;
+  pub(crate) mod r#add_asset_oracle_event;
+  pub(crate) mod r#add_pool_asset_event;
+  pub(crate) mod r#asset_limits;
+  pub(crate) mod r#cancel_withdrawal_request_event;
+  pub(crate) mod r#claim_protocol_fees_event;
   pub(crate) mod r#create_permission_account_event;
+  pub(crate) mod r#depeg_guard;
   pub(crate) mod r#deposit_event;
+  pub(crate) mod r#deposit_proportional_event;
   pub(crate) mod r#deposit_reward_event;
+  pub(crate) mod r#fixed_price;
   pub(crate) mod r#freeze_protocol_action_event;
   pub(crate) mod r#initialize_liquidity_pool_event;
+  pub(crate) mod r#initialize_reward_pool_event;
   pub(crate) mod r#initialize_rlp_event;
   pub(crate) mod r#kill_switch;
   pub(crate) mod r#level_roles;
+  pub(crate) mod r#merge_cooldowns_event;
+  pub(crate) mod r#migrate_asset_event;
+  pub(crate) mod r#migrate_liquidity_pool_event;
+  pub(crate) mod r#migrate_settings_event;
   pub(crate) mod r#oracle;
+  pub(crate) mod r#pair_fee;
+  pub(crate) mod r#quote_deposit_result;
+  pub(crate) mod r#quote_swap_result;
+  pub(crate) mod r#quote_withdraw_result;
+  pub(crate) mod r#rate_source;
+  pub(crate) mod r#remove_asset_oracle_event;
+  pub(crate) mod r#remove_pool_asset_event;
   pub(crate) mod r#request_withdraw_event;
+  pub(crate) mod r#reward_schedule;
   pub(crate) mod r#role;
   pub(crate) mod r#slash_event;
   pub(crate) mod r#swap_event;
+  pub(crate) mod r#swap_route_event;
   pub(crate) mod r#update;
   pub(crate) mod r#update_action_role_event;
+  pub(crate) mod r#update_asset_event;
+  pub(crate) mod r#update_asset_limits_event;
+  pub(crate) mod r#update_cooldown_duration_event;
   pub(crate) mod r#update_deposit_cap_event;
+  pub(crate) mod r#update_deposit_value_cap_event;
+  pub(crate) mod r#update_oracle_max_age_event;
+  pub(crate) mod r#update_pool_swap_fee_event;
+  pub(crate) mod r#update_protocol_fee_event;
   pub(crate) mod r#update_role_holder_event;
+  pub(crate) mod r#update_swap_fee_event;
+  pub(crate) mod r#update_target_weights_event;
+  pub(crate) mod r#update_vesting_duration_event;
+  pub(crate) mod r#withdraw_args;
   pub(crate) mod r#withdraw_event;
+  pub(crate) mod r#withdraw_single_asset_event;
 
   pub use self::r#access_control::*;
   pub use self::r#access_level::*;
@@ -36,22 +70,56 @@
   pub use self::r#action::*;
   pub use self::r#action_mapping::*;
   pub use self::r#add_asset_event::*;
+  pub use self::r#add_asset_oracle_event::*;
+  pub use self::r#add_pool_asset_event::*;
+  pub use self::r#asset_limits::*;
+  pub use self::r#cancel_withdrawal_request_event::*;
+  pub use self::r#claim_protocol_fees_event::*;
   pub use self::r#create_permission_account_event::*;
+  pub use self::r#depeg_guard::*;
   pub use self::r#deposit_event::*;
+  pub use self::r#deposit_proportional_event::*;
   pub use self::r#deposit_reward_event::*;
+  pub use self::r#fixed_price::*;
   pub use self::r#freeze_protocol_action_event::*;
   pub use self::r#initialize_liquidity_pool_event::*;
+  pub use self::r#initialize_reward_pool_event::*;
   pub use self::r#initialize_rlp_event::*;
   pub use self::r#kill_switch::*;
   pub use self::r#level_roles::*;
+  pub use self::r#merge_cooldowns_event::*;
+  pub use self::r#migrate_asset_event::*;
+  pub use self::r#migrate_liquidity_pool_event::*;
+  pub use self::r#migrate_settings_event::*;
   pub use self::r#oracle::*;
+  pub use self::r#pair_fee::*;
+  pub use self::r#quote_deposit_result::*;
+  pub use self::r#quote_swap_result::*;
+  pub use self::r#quote_withdraw_result::*;
+  pub use self::r#rate_source::*;
+  pub use self::r#remove_asset_oracle_event::*;
+  pub use self::r#remove_pool_asset_event::*;
   pub use self::r#request_withdraw_event::*;
+  pub use self::r#reward_schedule::*;
   pub use self::r#role::*;
   pub use self::r#slash_event::*;
   pub use self::r#swap_event::*;
+  pub use self::r#swap_route_event::*;
   pub use self::r#update::*;
   pub use self::r#update_action_role_event::*;
+  pub use self::r#update_asset_event::*;
+  pub use self::r#update_asset_limits_event::*;
+  pub use self::r#update_cooldown_duration_event::*;
   pub use self::r#update_deposit_cap_event::*;
+  pub use self::r#update_deposit_value_cap_event::*;
+  pub use self::r#update_oracle_max_age_event::*;
+  pub use self::r#update_pool_swap_fee_event::*;
+  pub use self::r#update_protocol_fee_event::*;
   pub use self::r#update_role_holder_event::*;
+  pub use self::r#update_swap_fee_event::*;
+  pub use self::r#update_target_weights_event::*;
+  pub use self::r#update_vesting_duration_event::*;
+  pub use self::r#withdraw_args::*;
   pub use self::r#withdraw_event::*;
+  pub use self::r#withdraw_single_asset_event::*;
 
@@ -6,6 +6,8 @@
 //!
 
 use solana_pubkey::Pubkey;
+use crate::generated::types::RateSource;
+use crate::generated::types::DepegGuard;
 use borsh::BorshSerialize;
 use borsh::BorshDeserialize;
 
@@ -14,6 +16,18 @@ use borsh::BorshDeserialize;
 pub enum Oracle {
 #[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::DisplayFromStr>"))]
 Pyth(Pubkey),
+#[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::DisplayFromStr>"))]
+Switchboard(Pubkey),
+ExchangeRate {
+#[cfg_attr(feature = "serde", serde(with = "serde_with::As::<serde_with::DisplayFromStr>"))]
+base: Pubkey,
+rate_source: RateSource,
+ },
+Fixed {
+price: i64,
+exponent: i32,
+depeg_guard: Option<DepegGuard>,
+ },
 }
 
 
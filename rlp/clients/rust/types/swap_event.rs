@@ -18,6 +18,8 @@ pub signer: Pubkey,
 pub liquidity_pool: Pubkey,
 pub amount_in: u64,
 pub amount_out: u64,
+pub lp_fee: u64,
+pub protocol_fee: u64,
 }
 
 
@@ -42,9 +42,14 @@ pub const LIQUIDITY_POOL_SEED: &str = "liquidity_pool";
 #[constant]
 pub const LIQUIDITY_POOL_LOCKUP_SEED: &str = "lp_lockup";
 
+/// Default maximum oracle age (in seconds) for assets that don't specify their own.
 #[constant]
 pub const ORACLE_MAXIMUM_AGE: u64 = 2 * 60;
 
+/// Hard cap on `Asset::oracle_max_age`, in seconds (30 minutes).
+#[constant]
+pub const MAX_ORACLE_MAX_AGE: u64 = 15 * ORACLE_MAXIMUM_AGE;
+
 #[constant]
 pub const BPS_PRECISION: u128 = 10_000;
 
@@ -57,5 +62,13 @@ pub const MAX_SLASH_BPS: u64 = 1_000;
 #[constant]
 pub const BPS_DENOMINATOR: u64 = 10_000;
 
+/// Hard cap on `Settings::swap_fee_bps`.
+#[constant]
+pub const MAX_SWAP_FEE_BPS: u16 = 1_000;
+
+/// Hard cap on `LiquidityPool::cooldown_duration`, in seconds (30 days).
+#[constant]
+pub const MAX_COOLDOWN_DURATION: u64 = 30 * 24 * 60 * 60;
+
 #[constant]
 pub const DEAD_SHARES: u64 = 1_000_000;
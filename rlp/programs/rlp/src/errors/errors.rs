@@ -148,4 +148,34 @@ pub enum RlpError {
 
     #[msg("OracleDataTooStale")]
     OracleDataTooStale,
+
+    #[msg("OracleConfidenceTooWide")]
+    OracleConfidenceTooWide,
+
+    #[msg("OracleDepegged")]
+    OracleDepegged,
+
+    #[msg("OracleDeviationTooLarge")]
+    OracleDeviationTooLarge,
+
+    #[msg("TooManyOracles")]
+    TooManyOracles,
+
+    #[msg("RewardScheduleFull")]
+    RewardScheduleFull,
+
+    #[msg("PairFeesFull")]
+    PairFeesFull,
+
+    #[msg("PoolAssetsFull")]
+    PoolAssetsFull,
+
+    #[msg("ReserveNotEmpty")]
+    ReserveNotEmpty,
+
+    #[msg("AssetPaused")]
+    AssetPaused,
+
+    #[msg("AssetCapExceeded")]
+    AssetCapExceeded,
 }
\ No newline at end of file
@@ -15,6 +15,23 @@ pub struct RequestWithdrawEvent {
     pub amount: u64,
 }
 
+#[event]
+pub struct CancelWithdrawalRequestEvent {
+    pub authority: Pubkey,
+    pub liquidity_pool_id: u8,
+    pub cooldown_id: u64,
+    pub amount: u64,
+}
+
+#[event]
+pub struct MergeCooldownsEvent {
+    pub authority: Pubkey,
+    pub liquidity_pool_id: u8,
+    pub cooldown_id: u64,
+    pub merged: Vec<u64>,
+    pub amount: u64,
+}
+
 #[event]
 pub struct WithdrawEvent {
     pub from: Pubkey,
@@ -35,6 +52,29 @@ pub struct AddAssetEvent {
     pub oracle: Pubkey,
 }
 
+#[event]
+pub struct AddAssetOracleEvent {
+    pub admin: Pubkey,
+    pub asset: Pubkey,
+    pub oracle: Pubkey,
+    pub max_deviation_bps: u16,
+}
+
+#[event]
+pub struct RemoveAssetOracleEvent {
+    pub admin: Pubkey,
+    pub asset: Pubkey,
+    pub oracle: Pubkey,
+}
+
+#[event]
+pub struct UpdateAssetEvent {
+    pub admin: Pubkey,
+    pub asset: Pubkey,
+    pub oracle: Pubkey,
+    pub access_level: AccessLevel,
+}
+
 #[event]
 pub struct InitializeRlpEvent {
     pub caller: Pubkey,
@@ -66,6 +106,37 @@ pub struct InitializeLiquidityPoolEvent {
     pub lp_token: Pubkey,
 }
 
+#[event]
+pub struct AddPoolAssetEvent {
+    pub admin: Pubkey,
+    pub liquidity_pool: Pubkey,
+    pub asset: Pubkey
+}
+
+#[event]
+pub struct RemovePoolAssetEvent {
+    pub admin: Pubkey,
+    pub liquidity_pool: Pubkey,
+    pub asset: Pubkey
+}
+
+#[event]
+pub struct MigrateLiquidityPoolEvent {
+    pub admin: Pubkey,
+    pub liquidity_pool: Pubkey,
+    pub asset_count: u8
+}
+
+#[event]
+pub struct UpdateAssetLimitsEvent {
+    pub admin: Pubkey,
+    pub liquidity_pool: Pubkey,
+    pub asset: Pubkey,
+    pub paused: bool,
+    pub max_reserve: Option<u64>,
+    pub max_share_bps: Option<u16>
+}
+
 #[event]
 pub struct UpdateRoleHolderEvent {
     pub address: Pubkey,
@@ -80,6 +151,58 @@ pub struct UpdateDepositCapEvent {
     pub new_cap: Option<u64>
 }
 
+#[event]
+pub struct UpdateDepositValueCapEvent {
+    pub admin: Pubkey,
+    pub liquidity_pool: Pubkey,
+    pub new_value_cap: Option<u128>
+}
+
+#[event]
+pub struct UpdateCooldownDurationEvent {
+    pub admin: Pubkey,
+    pub liquidity_pool: Pubkey,
+    pub old_cooldown_duration: u64,
+    pub new_cooldown_duration: u64
+}
+
+#[event]
+pub struct UpdateOracleMaxAgeEvent {
+    pub admin: Pubkey,
+    pub asset: Pubkey,
+    pub max_age: u64
+}
+
+#[event]
+pub struct MigrateAssetEvent {
+    pub admin: Pubkey,
+    pub asset: Pubkey,
+    pub max_confidence_bps: u16,
+    pub oracle_max_age: u64
+}
+
+#[event]
+pub struct MigrateSettingsEvent {
+    pub admin: Pubkey,
+    pub settings: Pubkey,
+    pub treasury: Pubkey
+}
+
+#[event]
+pub struct InitializeRewardPoolEvent {
+    pub admin: Pubkey,
+    pub liquidity_pool: Pubkey,
+    pub vesting_duration: u64
+}
+
+#[event]
+pub struct UpdateVestingDurationEvent {
+    pub admin: Pubkey,
+    pub liquidity_pool: Pubkey,
+    pub old_vesting_duration: u64,
+    pub new_vesting_duration: u64
+}
+
 #[event]
 pub struct SlashEvent {
     pub admin: Pubkey,
@@ -94,4 +217,72 @@ pub struct SwapEvent {
     pub liquidity_pool: Pubkey,
     pub amount_in: u64,
     pub amount_out: u64,
+    pub lp_fee: u64,
+    pub protocol_fee: u64,
+}
+
+#[event]
+pub struct UpdateSwapFeeEvent {
+    pub admin: Pubkey,
+    pub old_swap_fee_bps: u16,
+    pub new_swap_fee_bps: u16
+}
+
+#[event]
+pub struct SwapRouteEvent {
+    pub signer: Pubkey,
+    pub route: Vec<u8>,
+    pub amount_in: u64,
+    pub amount_out: u64,
+}
+
+#[event]
+pub struct UpdatePoolSwapFeeEvent {
+    pub admin: Pubkey,
+    pub liquidity_pool: Pubkey,
+    pub pair: Option<[u8; 2]>,
+    pub swap_fee_bps: Option<u16>
+}
+
+#[event]
+pub struct UpdateTargetWeightsEvent {
+    pub admin: Pubkey,
+    pub liquidity_pool: Pubkey,
+    pub target_weights_bps: Vec<u16>,
+    pub imbalance_fee_bps: u16,
+    pub max_weight_deviation_bps: u16
+}
+
+#[event]
+pub struct UpdateProtocolFeeEvent {
+    pub admin: Pubkey,
+    pub protocol_fee_bps: u16,
+    pub treasury: Pubkey
+}
+
+#[event]
+pub struct ClaimProtocolFeesEvent {
+    pub admin: Pubkey,
+    pub liquidity_pool: Pubkey,
+    pub asset: Pubkey,
+    pub treasury: Pubkey,
+    pub amount: u64
+}
+
+#[event]
+pub struct WithdrawSingleAssetEvent {
+    pub from: Pubkey,
+    pub asset: Pubkey,
+    pub lp_amount: u64,
+    pub amount_out: u64,
+    pub lp_fee: u64,
+    pub protocol_fee: u64,
+}
+
+#[event]
+pub struct DepositProportionalEvent {
+    pub from: Pubkey,
+    pub liquidity_pool: Pubkey,
+    pub amounts: Vec<u64>,
+    pub lp_tokens: u64,
 }
\ No newline at end of file
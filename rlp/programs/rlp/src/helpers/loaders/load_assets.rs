@@ -25,7 +25,7 @@ pub fn load_assets(
             RlpError::InvalidInput
         );
 
-        let account_data = account_info.try_borrow_mut_data()?;
+        let account_data = account_info.try_borrow_data()?;
         let asset = Asset::try_deserialize(&mut account_data.as_ref())
             .map_err(|_| error!(RlpError::InvalidInput))?;
 
@@ -34,13 +34,15 @@ pub fn load_assets(
             RlpError::InvalidInput
         );
 
-        let (expected_address, _) = Pubkey::find_program_address(
+        let expected_address = Pubkey::create_program_address(
             &[
                 ASSET_SEED.as_bytes(),
                 &asset.mint.to_bytes(),
+                &[asset.bump],
             ],
             &crate::ID
-        );
+        )
+        .map_err(|_| error!(RlpError::InvalidInput))?;
 
         require!(
             account_info.key() == expected_address,
@@ -6,3 +6,7 @@ pub use loaders::*;
 
 pub mod action_check_protocol;
 pub use action_check_protocol::*;
+
+
+pub mod swap_quote;
+pub use swap_quote::*;
\ No newline at end of file
@@ -1,12 +1,11 @@
 use super::OraclePrice;
-use crate::constants::*;
 use crate::errors::RlpError;
 use anchor_lang::prelude::*;
 use borsh::BorshDeserialize;
 use pyth_solana_receiver_sdk::price_update::PriceUpdateV2;
 
 #[inline(never)]
-pub fn get_price_from_pyth(oracle_account: &AccountInfo, clock: &Clock) -> Result<OraclePrice> {
+pub fn get_price_from_pyth(oracle_account: &AccountInfo, clock: &Clock, max_age: u64) -> Result<OraclePrice> {
     let oracle_account_data = oracle_account.try_borrow_data()?;
 
     let mut data_slice = &oracle_account_data[8..];
@@ -19,12 +18,13 @@ pub fn get_price_from_pyth(oracle_account: &AccountInfo, clock: &Clock) -> Resul
     let age = current_timestamp.saturating_sub(price_timestamp);
 
     require!(
-        age <= ORACLE_MAXIMUM_AGE as i64,
-        RlpError::PriceError
+        age <= max_age as i64,
+        RlpError::OracleDataTooStale
     );
 
     Ok(OraclePrice {
         price: oracle.price_message.price,
         exponent: oracle.price_message.exponent,
+        confidence: oracle.price_message.conf,
     })
 }
@@ -0,0 +1,80 @@
+use super::OraclePrice;
+use crate::constants::*;
+use crate::errors::RlpError;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::pubkey;
+
+pub const SWITCHBOARD_ON_DEMAND_PROGRAM_ID: Pubkey = pubkey!("SBondMDrcV3K4kxZR1HNVT7osZxAHVHgYXL5Ze1oMUv");
+
+// Byte layout of Switchboard On-Demand `PullFeedAccountData` (including the 8 byte discriminator).
+const PULL_FEED_DISCRIMINATOR: [u8; 8] = [196, 27, 108, 196, 10, 215, 219, 40];
+const PULL_FEED_LAST_UPDATE_TIMESTAMP_OFFSET: usize = 2216;
+const PULL_FEED_RESULT_VALUE_OFFSET: usize = 2264;
+const PULL_FEED_RESULT_STD_DEV_OFFSET: usize = 2280;
+
+// Switchboard results are fixed point numbers with 18 decimals.
+const SWITCHBOARD_DECIMALS: u32 = 18;
+
+#[inline(never)]
+pub fn get_price_from_switchboard(oracle_account: &AccountInfo, clock: &Clock, max_age: u64) -> Result<OraclePrice> {
+    let oracle_account_data = oracle_account.try_borrow_data()?;
+
+    require!(
+        oracle_account_data.len() >= PULL_FEED_RESULT_STD_DEV_OFFSET + 16,
+        RlpError::InvalidOracle
+    );
+
+    require!(
+        oracle_account_data[..8] == PULL_FEED_DISCRIMINATOR,
+        RlpError::InvalidOracle
+    );
+
+    let price_timestamp = i64::from_le_bytes(
+        oracle_account_data[PULL_FEED_LAST_UPDATE_TIMESTAMP_OFFSET..PULL_FEED_LAST_UPDATE_TIMESTAMP_OFFSET + 8]
+            .try_into()
+            .map_err(|_| RlpError::InvalidOracle)?,
+    );
+
+    let value = i128::from_le_bytes(
+        oracle_account_data[PULL_FEED_RESULT_VALUE_OFFSET..PULL_FEED_RESULT_VALUE_OFFSET + 16]
+            .try_into()
+            .map_err(|_| RlpError::InvalidOracle)?,
+    );
+
+    let std_dev = i128::from_le_bytes(
+        oracle_account_data[PULL_FEED_RESULT_STD_DEV_OFFSET..PULL_FEED_RESULT_STD_DEV_OFFSET + 16]
+            .try_into()
+            .map_err(|_| RlpError::InvalidOracle)?,
+    );
+
+    let current_timestamp = clock.unix_timestamp;
+    let age = current_timestamp.saturating_sub(price_timestamp);
+
+    require!(
+        age <= max_age as i64,
+        RlpError::OracleDataTooStale
+    );
+
+    // Scale the 18 decimal result down to `PRICE_PRECISION` so it fits into `OraclePrice`.
+    let scale = 10_i128.pow(SWITCHBOARD_DECIMALS - PRICE_PRECISION as u32);
+
+    let price: i64 = value
+        .checked_div(scale)
+        .ok_or(RlpError::MathOverflow)?
+        .try_into()
+        .map_err(|_| RlpError::MathOverflow)?;
+
+    // Standard deviation of the oracle submissions is used as the confidence interval.
+    let confidence: u64 = std_dev
+        .unsigned_abs()
+        .checked_div(scale as u128)
+        .ok_or(RlpError::MathOverflow)?
+        .try_into()
+        .map_err(|_| RlpError::MathOverflow)?;
+
+    Ok(OraclePrice {
+        price,
+        exponent: -PRICE_PRECISION,
+        confidence,
+    })
+}
@@ -0,0 +1,98 @@
+use crate::errors::RlpError;
+use anchor_lang::prelude::*;
+use anchor_lang::solana_program::native_token::LAMPORTS_PER_SOL;
+use anchor_lang::solana_program::pubkey;
+use anchor_spl::token::Mint;
+
+pub const SPL_STAKE_POOL_PROGRAM_ID: Pubkey = pubkey!("SPoo1Ku8WFXoNDMHPsrGSTSG1Y47rzgn41SLUNakuHy");
+pub const SINGLE_POOL_PROGRAM_ID: Pubkey = pubkey!("rFLUicC93hnZMVZc2hhd4oetXEDG4ja6TaFVSAftsVe");
+pub const STAKE_PROGRAM_ID: Pubkey = pubkey!("Stake11111111111111111111111111111111111111");
+
+// Stake pool tokens are priced against SOL, so they must share its decimals.
+pub const SOL_DECIMALS: u8 = 9;
+
+pub const SINGLE_POOL_MINT_PREFIX: &[u8] = b"mint";
+pub const SINGLE_POOL_STAKE_PREFIX: &[u8] = b"stake";
+
+// Byte layout of SPL stake pool `StakePool` account.
+const STAKE_POOL_ACCOUNT_TYPE: u8 = 1;
+pub const STAKE_POOL_POOL_MINT_OFFSET: usize = 162;
+const STAKE_POOL_TOTAL_LAMPORTS_OFFSET: usize = 258;
+const STAKE_POOL_POOL_TOKEN_SUPPLY_OFFSET: usize = 266;
+const STAKE_POOL_LAST_UPDATE_EPOCH_OFFSET: usize = 274;
+
+// Byte layout of `StakeStateV2::Stake`.
+const STAKE_STATE_STAKE_TAG: u32 = 2;
+const STAKE_STATE_DELEGATION_STAKE_OFFSET: usize = 156;
+
+/// Exchange rate of a stake pool token, expressed as lamports per pool token.
+#[derive(Debug)]
+pub struct StakePoolRate {
+    pub lamports: u64,
+    pub pool_tokens: u64,
+}
+
+fn read_u64(data: &[u8], offset: usize) -> Result<u64> {
+    let bytes = data
+        .get(offset..offset + 8)
+        .ok_or(RlpError::InvalidOracle)?;
+
+    Ok(u64::from_le_bytes(bytes.try_into().map_err(|_| RlpError::InvalidOracle)?))
+}
+
+#[inline(never)]
+pub fn get_rate_from_spl_stake_pool(stake_pool: &AccountInfo, clock: &Clock) -> Result<StakePoolRate> {
+    require!(
+        stake_pool.owner == &SPL_STAKE_POOL_PROGRAM_ID,
+        RlpError::InvalidOracle
+    );
+
+    let stake_pool_data = stake_pool.try_borrow_data()?;
+
+    require!(
+        stake_pool_data.first() == Some(&STAKE_POOL_ACCOUNT_TYPE),
+        RlpError::InvalidOracle
+    );
+
+    // Totals are only accurate once the pool has been updated for the current epoch.
+    require!(
+        read_u64(&stake_pool_data, STAKE_POOL_LAST_UPDATE_EPOCH_OFFSET)? == clock.epoch,
+        RlpError::OracleDataTooStale
+    );
+
+    Ok(StakePoolRate {
+        lamports: read_u64(&stake_pool_data, STAKE_POOL_TOTAL_LAMPORTS_OFFSET)?,
+        pool_tokens: read_u64(&stake_pool_data, STAKE_POOL_POOL_TOKEN_SUPPLY_OFFSET)?,
+    })
+}
+
+#[inline(never)]
+pub fn get_rate_from_single_pool(pool_mint: &AccountInfo, pool_stake: &AccountInfo) -> Result<StakePoolRate> {
+    require!(
+        pool_stake.owner == &STAKE_PROGRAM_ID,
+        RlpError::InvalidOracle
+    );
+
+    let stake_data = pool_stake.try_borrow_data()?;
+
+    let tag = stake_data
+        .get(..4)
+        .ok_or(RlpError::InvalidOracle)?;
+
+    require!(
+        u32::from_le_bytes(tag.try_into().map_err(|_| RlpError::InvalidOracle)?) == STAKE_STATE_STAKE_TAG,
+        RlpError::InvalidOracle
+    );
+
+    // The single pool keeps the minimum delegation in its stake account, which isn't backed by pool tokens.
+    let lamports = read_u64(&stake_data, STAKE_STATE_DELEGATION_STAKE_OFFSET)?
+        .saturating_sub(LAMPORTS_PER_SOL);
+
+    let mint = Mint::try_deserialize(&mut pool_mint.try_borrow_data()?.as_ref())
+        .map_err(|_| RlpError::InvalidOracle)?;
+
+    Ok(StakePoolRate {
+        lamports,
+        pool_tokens: mint.supply,
+    })
+}
\ No newline at end of file
@@ -2,4 +2,13 @@ pub mod oracle_price;
 pub use oracle_price::*;
 
 pub mod get_price_from_pyth;
-pub use get_price_from_pyth::*;
\ No newline at end of file
+pub use get_price_from_pyth::*;
+
+pub mod get_price_from_switchboard;
+pub use get_price_from_switchboard::*;
+
+pub mod get_rate_from_stake_pool;
+pub use get_rate_from_stake_pool::*;
+
+pub mod resolve_oracle;
+pub use resolve_oracle::*;
\ No newline at end of file
@@ -1,12 +1,154 @@
-use crate::{constants::PRECISION, errors::RlpError};
+use crate::{constants::{BPS_PRECISION, PRECISION}, errors::RlpError};
+use super::StakePoolRate;
 
-#[derive(Debug)]
+#[derive(Debug, Clone, Copy)]
 pub struct OraclePrice {
     pub price: i64,
     pub exponent: i32,
+    pub confidence: u64,
 }
 
 impl OraclePrice {
+    /// Rejects the price if its confidence interval is wider than `max_confidence_bps` of the price.
+    #[inline(never)]
+    pub fn check_confidence(&self, max_confidence_bps: u16) -> Result<(), RlpError> {
+        if self.price <= 0 {
+            return Err(RlpError::PriceError);
+        }
+
+        let confidence_bps = (self.confidence as u128)
+            .checked_mul(BPS_PRECISION)
+            .ok_or(RlpError::MathOverflow)?
+            .checked_div(self.price as u128)
+            .ok_or(RlpError::MathOverflow)?;
+
+        if confidence_bps > max_confidence_bps as u128 {
+            return Err(RlpError::OracleConfidenceTooWide);
+        }
+
+        Ok(())
+    }
+
+    /// Rejects the price if `other` deviates from it by more than `max_deviation_bps`.
+    #[inline(never)]
+    pub fn check_deviation(&self, other: &OraclePrice, max_deviation_bps: u16) -> Result<(), RlpError> {
+        let value = self.mul(1, 0)?;
+        let other_value = other.mul(1, 0)?;
+
+        let deviation_bps = value
+            .abs_diff(other_value)
+            .checked_mul(BPS_PRECISION)
+            .ok_or(RlpError::MathOverflow)?
+            .checked_div(value)
+            .ok_or(RlpError::MathOverflow)?;
+
+        if deviation_bps > max_deviation_bps as u128 {
+            return Err(RlpError::OracleDepegged);
+        }
+
+        Ok(())
+    }
+
+    /// Expresses the price with a smaller (more precise) exponent.
+    #[inline(never)]
+    pub fn rescale(&self, exponent: i32) -> Result<OraclePrice, RlpError> {
+        let scale = 10_i64
+            .checked_pow(
+                self.exponent
+                    .checked_sub(exponent)
+                    .and_then(|diff| u32::try_from(diff).ok())
+                    .ok_or(RlpError::MathOverflow)?
+            )
+            .ok_or(RlpError::MathOverflow)?;
+
+        Ok(OraclePrice {
+            price: self.price.checked_mul(scale).ok_or(RlpError::MathOverflow)?,
+            exponent,
+            confidence: self.confidence.checked_mul(scale as u64).ok_or(RlpError::MathOverflow)?,
+        })
+    }
+
+    /// Median of several prices, rejected if the spread between them exceeds `max_deviation_bps` of the median.
+    #[inline(never)]
+    pub fn median(prices: &[OraclePrice], max_deviation_bps: u16) -> Result<OraclePrice, RlpError> {
+        let exponent = prices
+            .iter()
+            .map(|price| price.exponent)
+            .min()
+            .ok_or(RlpError::PriceError)?;
+
+        let mut prices = prices
+            .iter()
+            .map(|price| price.rescale(exponent))
+            .collect::<Result<Vec<OraclePrice>, RlpError>>()?;
+
+        prices.sort_unstable_by_key(|price| price.price);
+
+        let middle = prices.len() / 2;
+        let median = if prices.len() % 2 == 1 {
+            prices[middle]
+        } else {
+            let (lower, upper) = (prices[middle - 1], prices[middle]);
+
+            OraclePrice {
+                price: ((lower.price as i128 + upper.price as i128) / 2) as i64,
+                exponent,
+                confidence: ((lower.confidence as u128 + upper.confidence as u128) / 2) as u64,
+            }
+        };
+
+        if median.price <= 0 {
+            return Err(RlpError::PriceError);
+        }
+
+        let spread = (prices[prices.len() - 1].price as i128)
+            .checked_sub(prices[0].price as i128)
+            .ok_or(RlpError::MathOverflow)?;
+
+        let deviation_bps = (spread as u128)
+            .checked_mul(BPS_PRECISION)
+            .ok_or(RlpError::MathOverflow)?
+            .checked_div(median.price as u128)
+            .ok_or(RlpError::MathOverflow)?;
+
+        if deviation_bps > max_deviation_bps as u128 {
+            return Err(RlpError::OracleDeviationTooLarge);
+        }
+
+        Ok(median)
+    }
+
+    /// Converts a price quoted per SOL into a price per stake pool token.
+    #[inline(never)]
+    pub fn apply_rate(&self, rate: &StakePoolRate) -> Result<OraclePrice, RlpError> {
+        // An empty pool redeems 1:1.
+        if rate.pool_tokens == 0 {
+            return Ok(*self);
+        }
+
+        let price: i64 = (self.price as i128)
+            .checked_mul(rate.lamports as i128)
+            .ok_or(RlpError::MathOverflow)?
+            .checked_div(rate.pool_tokens as i128)
+            .ok_or(RlpError::MathOverflow)?
+            .try_into()
+            .map_err(|_| RlpError::MathOverflow)?;
+
+        let confidence: u64 = (self.confidence as u128)
+            .checked_mul(rate.lamports as u128)
+            .ok_or(RlpError::MathOverflow)?
+            .checked_div(rate.pool_tokens as u128)
+            .ok_or(RlpError::MathOverflow)?
+            .try_into()
+            .map_err(|_| RlpError::MathOverflow)?;
+
+        Ok(OraclePrice {
+            price,
+            exponent: self.exponent,
+            confidence,
+        })
+    }
+
     #[inline(never)]
     pub fn mul(&self, amount: u64, token_decimals: u8) -> Result<u128, RlpError> {
         if self.price <= 0 {
@@ -54,3 +196,63 @@ impl OraclePrice {
         Ok(value)
     }
 }
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn price(price: i64, exponent: i32) -> OraclePrice {
+        OraclePrice {
+            price,
+            exponent,
+            confidence: 0,
+        }
+    }
+
+    #[test]
+    fn median_picks_the_middle_price() {
+        let median = OraclePrice::median(&[price(102, -2), price(100, -2), price(101, -2)], 500).unwrap();
+
+        assert_eq!(median.price, 101);
+        assert_eq!(median.exponent, -2);
+    }
+
+    #[test]
+    fn median_averages_the_middle_pair() {
+        let prices = [
+            OraclePrice { price: 100, exponent: -2, confidence: 2 },
+            OraclePrice { price: 102, exponent: -2, confidence: 4 },
+        ];
+
+        let median = OraclePrice::median(&prices, 500).unwrap();
+
+        assert_eq!(median.price, 101);
+        assert_eq!(median.confidence, 3);
+    }
+
+    #[test]
+    fn median_rescales_to_the_finest_exponent() {
+        let median = OraclePrice::median(&[price(100, -2), price(1_010, -3)], 500).unwrap();
+
+        assert_eq!(median.price, 1_005);
+        assert_eq!(median.exponent, -3);
+    }
+
+    #[test]
+    fn median_rejects_a_wide_spread() {
+        let prices = [price(100, -2), price(110, -2)];
+
+        // Spread of 10 against a median of 105 is 952 bps.
+        assert!(OraclePrice::median(&prices, 952).is_ok());
+        assert!(matches!(
+            OraclePrice::median(&prices, 951),
+            Err(RlpError::OracleDeviationTooLarge)
+        ));
+    }
+
+    #[test]
+    fn median_rejects_no_prices() {
+        assert!(matches!(OraclePrice::median(&[], 500), Err(RlpError::PriceError)));
+    }
+}
\ No newline at end of file
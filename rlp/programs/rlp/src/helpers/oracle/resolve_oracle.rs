@@ -0,0 +1,81 @@
+use crate::errors::RlpError;
+use crate::states::{Oracle, RateSource};
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+use pyth_solana_receiver_sdk::ID as PYTH_PROGRAM_ID;
+use super::{
+    SINGLE_POOL_MINT_PREFIX,
+    SINGLE_POOL_PROGRAM_ID,
+    SINGLE_POOL_STAKE_PREFIX,
+    SOL_DECIMALS,
+    SPL_STAKE_POOL_PROGRAM_ID,
+    STAKE_POOL_POOL_MINT_OFFSET,
+    SWITCHBOARD_ON_DEMAND_PROGRAM_ID
+};
+
+/// Resolves the stake pool backing an exchange-rate asset.
+/// Expects `[stake_pool]` for SPL stake pools or `[pool, pool_mint, pool_stake]` for single pools.
+fn load_rate_source(accounts: &[AccountInfo], asset_mint: &Account<Mint>) -> Result<RateSource> {
+    require!(
+        asset_mint.decimals == SOL_DECIMALS,
+        RlpError::InvalidInput
+    );
+
+    let pool = accounts.first().ok_or(RlpError::InvalidOracle)?;
+
+    if pool.owner.as_ref() == SPL_STAKE_POOL_PROGRAM_ID.as_ref() {
+        let pool_data = pool.try_borrow_data()?;
+        let pool_mint = pool_data
+            .get(STAKE_POOL_POOL_MINT_OFFSET..STAKE_POOL_POOL_MINT_OFFSET + 32)
+            .ok_or(RlpError::InvalidOracle)?;
+
+        require!(
+            pool_mint == asset_mint.key().as_ref(),
+            RlpError::InvalidOracle
+        );
+
+        Ok(RateSource::SplStakePool(pool.key()))
+    } else if pool.owner.as_ref() == SINGLE_POOL_PROGRAM_ID.as_ref() {
+        let (pool_mint, _) = Pubkey::find_program_address(
+            &[SINGLE_POOL_MINT_PREFIX, pool.key.as_ref()],
+            &SINGLE_POOL_PROGRAM_ID,
+        );
+
+        let (pool_stake, _) = Pubkey::find_program_address(
+            &[SINGLE_POOL_STAKE_PREFIX, pool.key.as_ref()],
+            &SINGLE_POOL_PROGRAM_ID,
+        );
+
+        require!(
+            pool_mint == asset_mint.key(),
+            RlpError::InvalidOracle
+        );
+
+        Ok(RateSource::SinglePool { pool_mint, pool_stake })
+    } else {
+        Err(RlpError::InvalidOracle.into())
+    }
+}
+
+/// Determines the oracle type from the owner of the price feed account.
+/// A Pyth feed followed by stake pool accounts in `rate_accounts` becomes an exchange-rate oracle.
+pub fn resolve_oracle(
+    oracle: &AccountInfo,
+    rate_accounts: &[AccountInfo],
+    asset_mint: &Account<Mint>
+) -> Result<Oracle> {
+    if oracle.owner.as_ref() == PYTH_PROGRAM_ID.as_ref() {
+        if rate_accounts.is_empty() {
+            Ok(Oracle::Pyth(oracle.key()))
+        } else {
+            Ok(Oracle::ExchangeRate {
+                base: oracle.key(),
+                rate_source: load_rate_source(rate_accounts, asset_mint)?,
+            })
+        }
+    } else if oracle.owner.as_ref() == SWITCHBOARD_ON_DEMAND_PROGRAM_ID.as_ref() {
+        Ok(Oracle::Switchboard(oracle.key()))
+    } else {
+        Err(RlpError::InvalidOracle.into())
+    }
+}
\ No newline at end of file
@@ -0,0 +1,235 @@
+use anchor_lang::prelude::*;
+use crate::constants::BPS_PRECISION;
+use crate::errors::RlpError;
+use crate::helpers::OraclePrice;
+
+/// Oracle prices, decimals and output-side liquidity the swap formula in `SWAP.md` works with.
+#[derive(Debug, Clone, Copy)]
+pub struct SwapPricing {
+    pub from_price: OraclePrice,
+    pub from_decimals: u8,
+    pub to_price: OraclePrice,
+    pub to_decimals: u8,
+    /// Output reserve available to swappers (excluding accrued protocol fees).
+    pub reserve_to: u64,
+    pub fee_bps: u16,
+    pub protocol_fee_bps: u16,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SwapQuote {
+    pub amount_in: u64,
+    pub amount_out: u64,
+    /// Haircut applied for trade size against the output reserve, before fees.
+    pub impact_bps: u16,
+    pub lp_fee: u64,
+    pub protocol_fee: u64,
+}
+
+impl SwapPricing {
+    /// `amount_out = oracle_out × (1 - impact_factor) × (1 - fee)`
+    pub fn quote_exact_in(&self, amount_in: u64) -> Result<SwapQuote> {
+        let oracle_amount_out: u64 = self
+            .from_price
+            .mul(amount_in, self.from_decimals)?
+            .checked_div(self.to_price.mul(1, self.to_decimals)?)
+            .ok_or(RlpError::MathOverflow)?
+            .try_into()
+            .map_err(|_| RlpError::MathOverflow)?;
+
+        self.quote_oracle_out(amount_in, oracle_amount_out)
+    }
+
+    /// Applies the impact against `reserve_to` and the fee to an output already converted
+    /// at oracle prices. `from_price` and `from_decimals` aren't used.
+    pub fn quote_oracle_out(&self, amount_in: u64, oracle_amount_out: u64) -> Result<SwapQuote> {
+        let impact_factor = (oracle_amount_out as u128)
+            .checked_mul(BPS_PRECISION)
+            .ok_or(RlpError::MathOverflow)?
+            .checked_div(
+                (self.reserve_to as u128)
+                    .checked_add(oracle_amount_out as u128)
+                    .ok_or(RlpError::MathOverflow)?,
+            )
+            .ok_or(RlpError::MathOverflow)?;
+
+        let impact_complement = BPS_PRECISION
+            .checked_sub(impact_factor)
+            .ok_or(RlpError::MathOverflow)?;
+        let amount_after_impact = (oracle_amount_out as u128)
+            .checked_mul(impact_complement)
+            .ok_or(RlpError::MathOverflow)?
+            .checked_div(BPS_PRECISION)
+            .ok_or(RlpError::MathOverflow)?;
+
+        let fee_complement = BPS_PRECISION
+            .checked_sub(self.fee_bps as u128)
+            .ok_or(RlpError::MathOverflow)?;
+
+        let amount_out = amount_after_impact
+            .checked_mul(fee_complement)
+            .ok_or(RlpError::MathOverflow)?
+            .checked_div(BPS_PRECISION)
+            .ok_or(RlpError::MathOverflow)?;
+
+        let total_fee = amount_after_impact
+            .checked_sub(amount_out)
+            .ok_or(RlpError::MathOverflow)?;
+
+        let protocol_fee = total_fee
+            .checked_mul(self.protocol_fee_bps as u128)
+            .ok_or(RlpError::MathOverflow)?
+            .checked_div(BPS_PRECISION)
+            .ok_or(RlpError::MathOverflow)?;
+
+        let lp_fee = total_fee
+            .checked_sub(protocol_fee)
+            .ok_or(RlpError::MathOverflow)?;
+
+        Ok(SwapQuote {
+            amount_in,
+            amount_out: amount_out as u64,
+            impact_bps: impact_factor as u16,
+            lp_fee: lp_fee as u64,
+            protocol_fee: protocol_fee as u64,
+        })
+    }
+
+    /// Inverts `quote_exact_in`: undoes the fee, then the impact
+    /// (`oracle_out = after_impact × x / (x - after_impact)`), then the oracle conversion,
+    /// rounding up at each step so the quote never pays out less than `amount_out`.
+    pub fn quote_exact_out(&self, amount_out: u64) -> Result<SwapQuote> {
+        let fee_complement = BPS_PRECISION
+            .checked_sub(self.fee_bps as u128)
+            .ok_or(RlpError::MathOverflow)?;
+
+        let amount_after_impact = div_ceil(
+            (amount_out as u128)
+                .checked_mul(BPS_PRECISION)
+                .ok_or(RlpError::MathOverflow)?,
+            fee_complement,
+        )?;
+
+        require!(
+            amount_after_impact < self.reserve_to as u128,
+            RlpError::NotEnoughFunds
+        );
+
+        let oracle_amount_out = div_ceil(
+            amount_after_impact
+                .checked_mul(self.reserve_to as u128)
+                .ok_or(RlpError::MathOverflow)?,
+            (self.reserve_to as u128) - amount_after_impact,
+        )?;
+
+        let amount_in: u64 = div_ceil(
+            oracle_amount_out
+                .checked_mul(self.to_price.mul(1, self.to_decimals)?)
+                .ok_or(RlpError::MathOverflow)?,
+            self.from_price.mul(1, self.from_decimals)?,
+        )?
+        .try_into()
+        .map_err(|_| RlpError::MathOverflow)?;
+
+        // Integer rounding in the forward formula can still leave the quote a unit short.
+        let mut amount_in = amount_in.max(1);
+        for _ in 0..3 {
+            let quote = self.quote_exact_in(amount_in)?;
+            if quote.amount_out >= amount_out {
+                // Anything paid above the requested amount stays in the pool for LPs.
+                return Ok(SwapQuote {
+                    amount_out,
+                    lp_fee: quote
+                        .lp_fee
+                        .checked_add(quote.amount_out - amount_out)
+                        .ok_or(RlpError::MathOverflow)?,
+                    ..quote
+                });
+            }
+            amount_in = amount_in.checked_add(1).ok_or(RlpError::MathOverflow)?;
+        }
+
+        err!(RlpError::InvalidInput)
+    }
+}
+
+fn div_ceil(numerator: u128, denominator: u128) -> Result<u128> {
+    require!(denominator > 0, RlpError::MathOverflow);
+
+    Ok(numerator
+        .checked_add(denominator - 1)
+        .ok_or(RlpError::MathOverflow)?
+        / denominator)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn pricing(from_price: OraclePrice, from_decimals: u8, to_price: OraclePrice, to_decimals: u8) -> SwapPricing {
+        SwapPricing {
+            from_price,
+            from_decimals,
+            to_price,
+            to_decimals,
+            reserve_to: 1_000_000_000,
+            fee_bps: 30,
+            protocol_fee_bps: 2_000,
+        }
+    }
+
+    fn price(price: i64, exponent: i32) -> OraclePrice {
+        OraclePrice {
+            price,
+            exponent,
+            confidence: 0,
+        }
+    }
+
+    #[test]
+    fn exact_out_is_filled_by_its_amount_in() {
+        let cases = [
+            pricing(price(100_000_000, -8), 6, price(100_000_000, -8), 6),
+            pricing(price(15_000_000_000, -8), 9, price(99_980_000, -8), 6),
+            pricing(price(99_980_000, -8), 6, price(15_000_000_000, -8), 9),
+            // Exponent finer than the decimal adjustment, where `mul` rounds per unit.
+            pricing(price(1_234_567_890_123, -12), 9, price(7, -1), 6),
+        ];
+
+        for pricing in cases {
+            for amount_out in (1..100_000_000).step_by(999_983).chain(1..1_000) {
+                let quote = pricing.quote_exact_out(amount_out).unwrap();
+                let filled = pricing.quote_exact_in(quote.amount_in).unwrap();
+
+                assert!(filled.amount_out >= amount_out);
+                assert_eq!(quote.amount_out, amount_out);
+                assert_eq!(quote.amount_in, filled.amount_in);
+                assert_eq!(quote.impact_bps, filled.impact_bps);
+                assert_eq!(quote.protocol_fee, filled.protocol_fee);
+                // Overshoot from rounding up stays with the LPs.
+                assert_eq!(quote.lp_fee, filled.lp_fee + (filled.amount_out - amount_out));
+            }
+        }
+    }
+
+    #[test]
+    fn exact_out_rejects_draining_the_reserve() {
+        let pricing = SwapPricing {
+            fee_bps: 0,
+            ..pricing(price(100_000_000, -8), 6, price(100_000_000, -8), 6)
+        };
+
+        assert!(pricing.quote_exact_out(pricing.reserve_to - 1).is_ok());
+        assert!(pricing.quote_exact_out(pricing.reserve_to).is_err());
+    }
+
+    #[test]
+    fn exact_out_rejects_a_full_fee() {
+        let pricing = SwapPricing {
+            fee_bps: BPS_PRECISION as u16,
+            ..pricing(price(100_000_000, -8), 6, price(100_000_000, -8), 6)
+        };
+
+        assert!(pricing.quote_exact_out(1_000).is_err());
+    }
+}
\ No newline at end of file
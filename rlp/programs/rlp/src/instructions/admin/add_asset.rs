@@ -1,18 +1,30 @@
 use crate::constants::*;
 use crate::errors::RlpError;
 use crate::events::AddAssetEvent;
-use crate::helpers::get_price_from_pyth;
+use crate::helpers::resolve_oracle;
 use crate::states::*;
 use anchor_lang::prelude::*;
 use anchor_spl::token::Mint;
 use pyth_solana_receiver_sdk::ID as PYTH_PROGRAM_ID;
 
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Copy)]
+pub struct FixedPrice {
+    pub price: i64,
+    pub exponent: i32,
+    /// Only used when a depeg guard oracle is passed.
+    pub max_deviation_bps: u16,
+}
+
 #[derive(AnchorDeserialize, AnchorSerialize)]
 pub struct AddAssetArgs {
     pub access_level: AccessLevel,
+    pub max_confidence_bps: u16,
+    pub oracle_max_age: Option<u64>,
+    pub fixed_price: Option<FixedPrice>,
 }
 
-pub fn add_asset(ctx: Context<AddAsset>, args: AddAssetArgs) -> Result<()> {
+/// Remaining accounts: the stake pool accounts when adding an exchange-rate asset (see `resolve_oracle`).
+pub fn add_asset<'a>(ctx: Context<'_, '_, 'a, 'a, AddAsset<'a>>, args: AddAssetArgs) -> Result<()> {
     let settings = &mut ctx.accounts.settings;
     let asset = &mut ctx.accounts.asset;
     let asset_mint = &ctx.accounts.asset_mint;
@@ -21,11 +33,48 @@ pub fn add_asset(ctx: Context<AddAsset>, args: AddAssetArgs) -> Result<()> {
 
     let clock = Clock::get()?;
 
-    let oracle = if oracle.owner.as_ref() == PYTH_PROGRAM_ID.as_ref() {
-        get_price_from_pyth(oracle, &clock)?;
-        Oracle::Pyth(oracle.key())
-    } else {
-        return Err(RlpError::InvalidOracle.into());
+    require!(
+        args.max_confidence_bps > 0 && args.max_confidence_bps as u64 <= BPS_DENOMINATOR,
+        RlpError::InvalidInput
+    );
+
+    let oracle_max_age = args.oracle_max_age.unwrap_or(ORACLE_MAXIMUM_AGE);
+    require!(
+        oracle_max_age > 0 && oracle_max_age <= MAX_ORACLE_MAX_AGE,
+        RlpError::InvalidInput
+    );
+
+    let oracle = match (args.fixed_price, oracle) {
+        (Some(FixedPrice { price, exponent, max_deviation_bps }), oracle_info) => {
+            // With a fixed price the oracle account, if any, is a Pyth feed guarding against a depeg.
+            let depeg_guard = match oracle_info {
+                Some(oracle_info) => {
+                    require!(
+                        oracle_info.owner.as_ref() == PYTH_PROGRAM_ID.as_ref(),
+                        RlpError::InvalidOracle
+                    );
+
+                    require!(
+                        max_deviation_bps > 0 && max_deviation_bps as u64 <= BPS_DENOMINATOR,
+                        RlpError::InvalidInput
+                    );
+
+                    Some(DepegGuard {
+                        oracle: oracle_info.key(),
+                        max_deviation_bps,
+                    })
+                },
+                None => None,
+            };
+
+            Oracle::Fixed {
+                price,
+                exponent,
+                depeg_guard,
+            }
+        },
+        (None, Some(oracle_info)) => resolve_oracle(oracle_info, ctx.remaining_accounts, asset_mint)?,
+        (None, None) => return Err(RlpError::InvalidOracle.into()),
     };
 
     asset.set_inner(Asset {
@@ -34,8 +83,18 @@ pub fn add_asset(ctx: Context<AddAsset>, args: AddAssetArgs) -> Result<()> {
         mint: asset_mint.key(),
         oracle,
         access_level: args.access_level,
+        max_confidence_bps: args.max_confidence_bps,
+        oracle_max_age,
+        additional_oracles: [None; MAX_ADDITIONAL_ORACLES],
+        max_oracle_deviation_bps: 0,
     });
 
+    // Make sure the asset can be priced with its configuration.
+    let mut pricing_accounts = ctx.remaining_accounts.to_vec();
+    pricing_accounts.extend(ctx.accounts.oracle.as_ref().map(|oracle| oracle.to_account_info()));
+
+    asset.get_price(&pricing_accounts, &clock)?;
+
     settings.assets = settings
         .assets
         .checked_add(1)
@@ -44,7 +103,7 @@ pub fn add_asset(ctx: Context<AddAsset>, args: AddAssetArgs) -> Result<()> {
     emit!(AddAssetEvent {
         admin: signer.key(),
         asset: asset_mint.key(),
-        oracle: *oracle.key()
+        oracle: oracle.key().copied().unwrap_or_default()
     });
 
     Ok(())
@@ -91,9 +150,9 @@ pub struct AddAsset<'info> {
     #[account(mut)]
     pub asset_mint: Account<'info, Mint>,
 
-    /// CHECK: We're checking owner of this account later
+    /// CHECK: We're checking owner of this account later. Optional for fixed price assets.
     #[account(mut)]
-    pub oracle: UncheckedAccount<'info>,
+    pub oracle: Option<UncheckedAccount<'info>>,
 
     #[account()]
     pub system_program: Program<'info, System>,
@@ -0,0 +1,119 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+use crate::states::*;
+use crate::constants::*;
+use crate::errors::RlpError;
+use crate::events::AddAssetOracleEvent;
+use crate::helpers::resolve_oracle;
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct AddAssetOracleArgs {
+    /// Spread allowed between the asset's oracles. Required while none is set, kept when `None`.
+    pub max_deviation_bps: Option<u16>
+}
+
+/// Remaining accounts: the stake pool accounts when adding an exchange-rate oracle (see `resolve_oracle`).
+pub fn add_asset_oracle<'a>(
+    ctx: Context<'_, '_, 'a, 'a, AddAssetOracle<'a>>,
+    args: AddAssetOracleArgs
+) -> Result<()> {
+    let AddAssetOracleArgs {
+        max_deviation_bps
+    } = args;
+
+    let asset = &mut ctx.accounts.asset;
+    let oracle_info = &ctx.accounts.oracle;
+
+    let oracle = resolve_oracle(oracle_info, ctx.remaining_accounts, &ctx.accounts.asset_mint)?;
+
+    require!(
+        !asset.oracles().any(|existing| existing == &oracle),
+        RlpError::InvalidInput
+    );
+
+    let slot = asset
+        .additional_oracles
+        .iter_mut()
+        .find(|slot| slot.is_none())
+        .ok_or(RlpError::TooManyOracles)?;
+
+    *slot = Some(oracle);
+
+    if let Some(max_deviation_bps) = max_deviation_bps {
+        require!(
+            max_deviation_bps > 0 && max_deviation_bps as u64 <= BPS_DENOMINATOR,
+            RlpError::InvalidInput
+        );
+
+        asset.max_oracle_deviation_bps = max_deviation_bps;
+    }
+
+    require!(
+        asset.max_oracle_deviation_bps > 0,
+        RlpError::InvalidInput
+    );
+
+    // Make sure the new source can be priced on its own.
+    let mut pricing_accounts = ctx.remaining_accounts.to_vec();
+    pricing_accounts.push(oracle_info.to_account_info());
+
+    oracle
+        .get_price(&pricing_accounts, &Clock::get()?, asset.oracle_max_age)?
+        .check_confidence(asset.max_confidence_bps)?;
+
+    emit!(AddAssetOracleEvent {
+        admin: ctx.accounts.signer.key(),
+        asset: asset.mint,
+        oracle: oracle_info.key(),
+        max_deviation_bps: asset.max_oracle_deviation_bps
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct AddAssetOracle<'info> {
+    #[account(
+        mut,
+    )]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            PERMISSIONS_SEED.as_bytes(),
+            signer.key().as_ref()
+        ],
+        bump = admin.bump,
+        constraint = admin.can_perform_protocol_action(Action::Management, &settings.access_control) @ RlpError::InvalidSigner,
+    )]
+    pub admin: Account<'info, UserPermissions>,
+
+    #[account(
+        seeds = [
+            SETTINGS_SEED.as_bytes()
+        ],
+        bump = settings.bump,
+        constraint = !settings.access_control.killswitch.is_frozen(&Action::Management) @ RlpError::Frozen,
+    )]
+    pub settings: Box<Account<'info, Settings>>,
+
+    #[account(
+        mut,
+        seeds = [
+            ASSET_SEED.as_bytes(),
+            &asset_mint.key().to_bytes()
+        ],
+        bump = asset.bump,
+    )]
+    pub asset: Account<'info, Asset>,
+
+    #[account(
+        address = asset.mint
+    )]
+    pub asset_mint: Account<'info, Mint>,
+
+    /// CHECK: We're checking owner of this account in `resolve_oracle`
+    #[account()]
+    pub oracle: UncheckedAccount<'info>,
+}
\ No newline at end of file
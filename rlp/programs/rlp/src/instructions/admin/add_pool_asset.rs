@@ -0,0 +1,106 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+use crate::states::*;
+use crate::constants::*;
+use crate::errors::RlpError;
+use crate::events::AddPoolAssetEvent;
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct AddPoolAssetArgs {
+    pub liquidity_pool_index: u8
+}
+
+pub fn add_pool_asset(
+    ctx: Context<AddPoolAsset>,
+    args: AddPoolAssetArgs
+) -> Result<()> {
+    let AddPoolAssetArgs {
+        liquidity_pool_index: _
+    } = args;
+
+    let asset = &ctx.accounts.asset;
+    let liquidity_pool = &mut ctx.accounts.liquidity_pool;
+
+    liquidity_pool.add_asset(asset.index)?;
+
+    emit!(AddPoolAssetEvent {
+        admin: ctx.accounts.signer.key(),
+        liquidity_pool: liquidity_pool.key(),
+        asset: asset.mint
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(args: AddPoolAssetArgs)]
+pub struct AddPoolAsset<'info> {
+    #[account(
+        mut,
+    )]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [
+            PERMISSIONS_SEED.as_bytes(),
+            signer.key().as_ref()
+        ],
+        bump = admin.bump,
+        constraint = admin.can_perform_protocol_action(Action::Management, &settings.access_control) @ RlpError::InvalidSigner,
+    )]
+    pub admin: Account<'info, UserPermissions>,
+
+    #[account(
+        seeds = [
+            SETTINGS_SEED.as_bytes()
+        ],
+        bump = settings.bump,
+        constraint = !settings.access_control.killswitch.is_frozen(&Action::Management) @ RlpError::Frozen,
+    )]
+    pub settings: Box<Account<'info, Settings>>,
+
+    #[account(
+        mut,
+        seeds = [
+            LIQUIDITY_POOL_SEED.as_bytes(),
+            &args.liquidity_pool_index.to_le_bytes()
+        ],
+        bump = liquidity_pool.bump,
+        realloc = LiquidityPool::space(liquidity_pool.asset_count as usize + 1),
+        realloc::payer = signer,
+        realloc::zero = false,
+    )]
+    pub liquidity_pool: Account<'info, LiquidityPool>,
+
+    #[account(
+        seeds = [
+            ASSET_SEED.as_bytes(),
+            &asset_mint.key().to_bytes()
+        ],
+        bump = asset.bump,
+    )]
+    pub asset: Account<'info, Asset>,
+
+    #[account(
+        address = asset.mint
+    )]
+    pub asset_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        init_if_needed,
+        payer = signer,
+        associated_token::mint = asset_mint,
+        associated_token::authority = liquidity_pool,
+    )]
+    pub pool_asset_account: Box<Account<'info, TokenAccount>>,
+
+    #[account()]
+    pub system_program: Program<'info, System>,
+
+    #[account()]
+    pub token_program: Program<'info, Token>,
+
+    #[account()]
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
\ No newline at end of file
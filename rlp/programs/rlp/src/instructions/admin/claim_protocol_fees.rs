@@ -0,0 +1,128 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{transfer, Mint, Token, TokenAccount, Transfer};
+use crate::states::*;
+use crate::constants::*;
+use crate::errors::RlpError;
+use crate::events::ClaimProtocolFeesEvent;
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct ClaimProtocolFeesArgs {
+    pub liquidity_pool_index: u8
+}
+
+pub fn claim_protocol_fees(
+    ctx: Context<ClaimProtocolFees>,
+    args: ClaimProtocolFeesArgs
+) -> Result<()> {
+    let ClaimProtocolFeesArgs {
+        liquidity_pool_index: _
+    } = args;
+
+    let asset = &ctx.accounts.asset;
+    let amount = ctx.accounts.liquidity_pool.take_protocol_fees(asset.index)?;
+
+    require!(
+        amount > 0,
+        RlpError::InvalidInput
+    );
+
+    let liquidity_pool = &ctx.accounts.liquidity_pool;
+
+    let lp_seeds = &[
+        LIQUIDITY_POOL_SEED.as_bytes(),
+        &liquidity_pool.index.to_le_bytes(),
+        &[liquidity_pool.bump]
+    ];
+
+    transfer(
+        CpiContext::new_with_signer(
+            ctx.accounts.token_program.to_account_info(),
+            Transfer {
+                from: ctx.accounts.pool_asset_account.to_account_info(),
+                to: ctx.accounts.treasury_asset_account.to_account_info(),
+                authority: liquidity_pool.to_account_info()
+            },
+            &[lp_seeds]
+        ),
+        amount
+    )?;
+
+    emit!(ClaimProtocolFeesEvent {
+        admin: ctx.accounts.signer.key(),
+        liquidity_pool: liquidity_pool.key(),
+        asset: asset.mint,
+        treasury: ctx.accounts.settings.treasury,
+        amount
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(args: ClaimProtocolFeesArgs)]
+pub struct ClaimProtocolFees<'info> {
+    #[account(
+        mut,
+    )]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [
+            PERMISSIONS_SEED.as_bytes(),
+            signer.key().as_ref()
+        ],
+        bump = admin.bump,
+        constraint = admin.can_perform_protocol_action(Action::ClaimProtocolFees, &settings.access_control) @ RlpError::InvalidSigner,
+    )]
+    pub admin: Account<'info, UserPermissions>,
+
+    #[account(
+        seeds = [
+            SETTINGS_SEED.as_bytes()
+        ],
+        bump = settings.bump,
+        constraint = !settings.access_control.killswitch.is_frozen(&Action::ClaimProtocolFees) @ RlpError::Frozen,
+    )]
+    pub settings: Box<Account<'info, Settings>>,
+
+    #[account(
+        mut,
+        seeds = [
+            LIQUIDITY_POOL_SEED.as_bytes(),
+            &args.liquidity_pool_index.to_le_bytes()
+        ],
+        bump = liquidity_pool.bump,
+    )]
+    pub liquidity_pool: Account<'info, LiquidityPool>,
+
+    #[account(
+        seeds = [
+            ASSET_SEED.as_bytes(),
+            &asset_mint.key().to_bytes()
+        ],
+        bump = asset.bump,
+    )]
+    pub asset: Account<'info, Asset>,
+
+    #[account(
+        address = asset.mint
+    )]
+    pub asset_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = asset_mint,
+        associated_token::authority = liquidity_pool,
+    )]
+    pub pool_asset_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = asset_mint,
+        token::authority = settings.treasury,
+    )]
+    pub treasury_asset_account: Box<Account<'info, TokenAccount>>,
+
+    #[account()]
+    pub token_program: Program<'info, Token>,
+}
\ No newline at end of file
@@ -34,6 +34,11 @@ pub fn initialize_lp(
         RlpError::InvalidInput
     );
 
+    require!(
+        cooldown_duration <= MAX_COOLDOWN_DURATION,
+        RlpError::InvalidInput
+    );
+
     for (i, &asset_index) in assets.iter().enumerate() {
         require!(
             asset_index < settings.assets,
@@ -44,11 +49,6 @@ pub fn initialize_lp(
         }
     }
 
-    let mut asset_array = [u8::MAX; MAX_POOL_ASSETS];
-    for (i, &asset_index) in assets.iter().enumerate() {
-        asset_array[i] = asset_index;
-    }
-
     let pool_index = settings.liquidity_pools;
 
     liquidity_pool.set_inner(LiquidityPool {
@@ -59,7 +59,15 @@ pub fn initialize_lp(
         cooldowns: 0,
         deposit_cap,
         asset_count: assets.len() as u8,
-        assets: asset_array,
+        protocol_fees: vec![0; assets.len()],
+        target_weights_bps: vec![0; assets.len()],
+        asset_limits: vec![AssetLimits::default(); assets.len()],
+        assets,
+        swap_fee_bps: None,
+        pair_fees: [None; MAX_PAIR_FEES],
+        imbalance_fee_bps: 0,
+        max_weight_deviation_bps: 0,
+        deposit_value_cap: None,
     });
 
     let signer_seeds = &[
@@ -95,6 +103,7 @@ pub fn initialize_lp(
 }
 
 #[derive(Accounts)]
+#[instruction(args: InitializeLiquidityPoolArgs)]
 pub struct InitializeLiquidityPool<'info> {
     #[account(mut)]
     pub signer: Signer<'info>,
@@ -131,7 +140,7 @@ pub struct InitializeLiquidityPool<'info> {
             &settings.liquidity_pools.to_le_bytes()
         ],
         bump,
-        space = 8 + LiquidityPool::INIT_SPACE
+        space = LiquidityPool::space(args.assets.len())
     )]
     pub liquidity_pool: Account<'info, LiquidityPool>,
 
@@ -18,7 +18,7 @@ pub fn initialize_rlp(ctx: Context<InitializeRlp>, args: InitializeRlpArgs) -> R
     let permissions = &mut ctx.accounts.permissions;
 
     require!(
-        swap_fee_bps <= 10_000,
+        swap_fee_bps <= MAX_SWAP_FEE_BPS,
         RlpError::InvalidInput
     );
 
@@ -35,6 +35,8 @@ pub fn initialize_rlp(ctx: Context<InitializeRlp>, args: InitializeRlpArgs) -> R
         access_control: AccessControl::new_defaults()?,
         liquidity_pools: 0,
         swap_fee_bps,
+        protocol_fee_bps: 0,
+        treasury: signer.key(),
     });
 
     emit!(InitializeRlpEvent {
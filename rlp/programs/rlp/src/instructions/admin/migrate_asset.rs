@@ -0,0 +1,134 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use anchor_spl::token::Mint;
+use crate::states::*;
+use crate::constants::*;
+use crate::errors::RlpError;
+use crate::events::MigrateAssetEvent;
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct MigrateAssetArgs {
+    pub max_confidence_bps: u16,
+    pub oracle_max_age: Option<u64>
+}
+
+/// Rewrites an asset created with the `LegacyAsset` layout into the current one, taking the
+/// oracle settings `add_asset` would. The signer tops up rent for the larger account.
+pub fn migrate_asset(
+    ctx: Context<MigrateAsset>,
+    args: MigrateAssetArgs
+) -> Result<()> {
+    let MigrateAssetArgs {
+        max_confidence_bps,
+        oracle_max_age
+    } = args;
+
+    require!(
+        max_confidence_bps > 0 && max_confidence_bps as u64 <= BPS_DENOMINATOR,
+        RlpError::InvalidInput
+    );
+
+    let oracle_max_age = oracle_max_age.unwrap_or(ORACLE_MAXIMUM_AGE);
+    require!(
+        oracle_max_age > 0 && oracle_max_age <= MAX_ORACLE_MAX_AGE,
+        RlpError::InvalidInput
+    );
+
+    let asset_info = ctx.accounts.asset.to_account_info();
+
+    require!(
+        asset_info.owner == &crate::ID,
+        RlpError::InvalidInput
+    );
+
+    require!(
+        asset_info.data_len() == 8 + LegacyAsset::INIT_SPACE,
+        RlpError::InvalidInput
+    );
+
+    let asset = {
+        let data = asset_info.try_borrow_data()?;
+
+        require!(
+            data.starts_with(Asset::DISCRIMINATOR),
+            RlpError::InvalidInput
+        );
+
+        LegacyAsset::deserialize(&mut &data[8..])
+            .map_err(|_| error!(RlpError::InvalidInput))?
+            .into_asset(max_confidence_bps, oracle_max_age)
+    };
+
+    let space = 8 + Asset::INIT_SPACE;
+    let rent_minimum = Rent::get()?.minimum_balance(space);
+    let lamports = asset_info.lamports();
+
+    if rent_minimum > lamports {
+        transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.signer.to_account_info(),
+                    to: asset_info.clone()
+                }
+            ),
+            rent_minimum - lamports
+        )?;
+    }
+
+    asset_info.realloc(space, false)?;
+    asset.try_serialize(&mut &mut asset_info.try_borrow_mut_data()?[..])?;
+
+    emit!(MigrateAssetEvent {
+        admin: ctx.accounts.signer.key(),
+        asset: asset.mint,
+        max_confidence_bps,
+        oracle_max_age
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct MigrateAsset<'info> {
+    #[account(
+        mut,
+    )]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [
+            PERMISSIONS_SEED.as_bytes(),
+            signer.key().as_ref()
+        ],
+        bump = admin.bump,
+        constraint = admin.can_perform_protocol_action(Action::Management, &settings.access_control) @ RlpError::InvalidSigner,
+    )]
+    pub admin: Account<'info, UserPermissions>,
+
+    #[account(
+        seeds = [
+            SETTINGS_SEED.as_bytes()
+        ],
+        bump = settings.bump,
+        constraint = !settings.access_control.killswitch.is_frozen(&Action::Management) @ RlpError::Frozen,
+    )]
+    pub settings: Box<Account<'info, Settings>>,
+
+    /// CHECK: Still in the legacy layout, which `Account<Asset>` can't deserialize.
+    #[account(
+        mut,
+        seeds = [
+            ASSET_SEED.as_bytes(),
+            &asset_mint.key().to_bytes()
+        ],
+        bump,
+    )]
+    pub asset: UncheckedAccount<'info>,
+
+    #[account()]
+    pub asset_mint: Account<'info, Mint>,
+
+    #[account()]
+    pub system_program: Program<'info, System>,
+}
\ No newline at end of file
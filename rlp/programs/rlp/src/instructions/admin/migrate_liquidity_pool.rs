@@ -0,0 +1,118 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use crate::states::*;
+use crate::constants::*;
+use crate::errors::RlpError;
+use crate::events::MigrateLiquidityPoolEvent;
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct MigrateLiquidityPoolArgs {
+    pub liquidity_pool_index: u8
+}
+
+/// Rewrites a pool created with the fixed-size `LegacyLiquidityPool` layout into the current
+/// one, resizing the account to its assets. The signer tops up rent if the account grows.
+pub fn migrate_liquidity_pool(
+    ctx: Context<MigrateLiquidityPool>,
+    args: MigrateLiquidityPoolArgs
+) -> Result<()> {
+    let MigrateLiquidityPoolArgs {
+        liquidity_pool_index: _
+    } = args;
+
+    let liquidity_pool_info = ctx.accounts.liquidity_pool.to_account_info();
+
+    require!(
+        liquidity_pool_info.owner == &crate::ID,
+        RlpError::InvalidInput
+    );
+
+    // Current layouts never take the legacy size, see `LiquidityPool::space`.
+    require!(
+        liquidity_pool_info.data_len() == 8 + LegacyLiquidityPool::INIT_SPACE,
+        RlpError::InvalidInput
+    );
+
+    let liquidity_pool: LiquidityPool = {
+        let data = liquidity_pool_info.try_borrow_data()?;
+
+        require!(
+            data.starts_with(LiquidityPool::DISCRIMINATOR),
+            RlpError::InvalidInput
+        );
+
+        LegacyLiquidityPool::deserialize(&mut &data[8..])
+            .map_err(|_| error!(RlpError::InvalidInput))?
+            .into()
+    };
+
+    let space = LiquidityPool::space(liquidity_pool.asset_count as usize);
+    let rent_minimum = Rent::get()?.minimum_balance(space);
+    let lamports = liquidity_pool_info.lamports();
+
+    if rent_minimum > lamports {
+        transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.signer.to_account_info(),
+                    to: liquidity_pool_info.clone()
+                }
+            ),
+            rent_minimum - lamports
+        )?;
+    }
+
+    liquidity_pool_info.realloc(space, false)?;
+    liquidity_pool.try_serialize(&mut &mut liquidity_pool_info.try_borrow_mut_data()?[..])?;
+
+    emit!(MigrateLiquidityPoolEvent {
+        admin: ctx.accounts.signer.key(),
+        liquidity_pool: liquidity_pool_info.key(),
+        asset_count: liquidity_pool.asset_count
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(args: MigrateLiquidityPoolArgs)]
+pub struct MigrateLiquidityPool<'info> {
+    #[account(
+        mut,
+    )]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [
+            PERMISSIONS_SEED.as_bytes(),
+            signer.key().as_ref()
+        ],
+        bump = admin.bump,
+        constraint = admin.can_perform_protocol_action(Action::Management, &settings.access_control) @ RlpError::InvalidSigner,
+    )]
+    pub admin: Account<'info, UserPermissions>,
+
+    #[account(
+        seeds = [
+            SETTINGS_SEED.as_bytes()
+        ],
+        bump = settings.bump,
+        constraint = !settings.access_control.killswitch.is_frozen(&Action::Management) @ RlpError::Frozen,
+    )]
+    pub settings: Box<Account<'info, Settings>>,
+
+    /// CHECK: Still in the legacy layout, which `Account<LiquidityPool>` can't deserialize.
+    #[account(
+        mut,
+        seeds = [
+            LIQUIDITY_POOL_SEED.as_bytes(),
+            &args.liquidity_pool_index.to_le_bytes()
+        ],
+        bump,
+    )]
+    pub liquidity_pool: UncheckedAccount<'info>,
+
+    #[account()]
+    pub system_program: Program<'info, System>,
+}
\ No newline at end of file
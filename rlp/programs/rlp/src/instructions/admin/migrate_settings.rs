@@ -0,0 +1,117 @@
+use anchor_lang::prelude::*;
+use anchor_lang::system_program::{transfer, Transfer};
+use crate::states::*;
+use crate::constants::*;
+use crate::errors::RlpError;
+use crate::events::MigrateSettingsEvent;
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct MigrateSettingsArgs {
+    pub treasury: Pubkey
+}
+
+/// Rewrites settings created with the `LegacySettings` layout into the current one, with protocol
+/// fees off, `treasury` as the fee recipient and the default roles for actions added since. The
+/// signer tops up rent for the larger account.
+pub fn migrate_settings(
+    ctx: Context<MigrateSettings>,
+    args: MigrateSettingsArgs
+) -> Result<()> {
+    let MigrateSettingsArgs {
+        treasury
+    } = args;
+
+    let settings_info = ctx.accounts.settings.to_account_info();
+
+    require!(
+        settings_info.owner == &crate::ID,
+        RlpError::InvalidInput
+    );
+
+    require!(
+        settings_info.data_len() == 8 + LegacySettings::INIT_SPACE,
+        RlpError::InvalidInput
+    );
+
+    let settings = {
+        let data = settings_info.try_borrow_data()?;
+
+        require!(
+            data.starts_with(Settings::DISCRIMINATOR),
+            RlpError::InvalidInput
+        );
+
+        LegacySettings::deserialize(&mut &data[8..])
+            .map_err(|_| error!(RlpError::InvalidInput))?
+            .into_settings(treasury)?
+    };
+
+    // `Account<Settings>` can't load the legacy layout, so the management gate runs here.
+    require!(
+        ctx.accounts.admin.can_perform_protocol_action(Action::Management, &settings.access_control),
+        RlpError::InvalidSigner
+    );
+
+    require!(
+        !settings.access_control.killswitch.is_frozen(&Action::Management),
+        RlpError::Frozen
+    );
+
+    let space = 8 + Settings::INIT_SPACE;
+    let rent_minimum = Rent::get()?.minimum_balance(space);
+    let lamports = settings_info.lamports();
+
+    if rent_minimum > lamports {
+        transfer(
+            CpiContext::new(
+                ctx.accounts.system_program.to_account_info(),
+                Transfer {
+                    from: ctx.accounts.signer.to_account_info(),
+                    to: settings_info.clone()
+                }
+            ),
+            rent_minimum - lamports
+        )?;
+    }
+
+    settings_info.realloc(space, false)?;
+    settings.try_serialize(&mut &mut settings_info.try_borrow_mut_data()?[..])?;
+
+    emit!(MigrateSettingsEvent {
+        admin: ctx.accounts.signer.key(),
+        settings: settings_info.key(),
+        treasury
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct MigrateSettings<'info> {
+    #[account(
+        mut,
+    )]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [
+            PERMISSIONS_SEED.as_bytes(),
+            signer.key().as_ref()
+        ],
+        bump = admin.bump,
+    )]
+    pub admin: Account<'info, UserPermissions>,
+
+    /// CHECK: Still in the legacy layout, which `Account<Settings>` can't deserialize.
+    #[account(
+        mut,
+        seeds = [
+            SETTINGS_SEED.as_bytes()
+        ],
+        bump,
+    )]
+    pub settings: UncheckedAccount<'info>,
+
+    #[account()]
+    pub system_program: Program<'info, System>,
+}
\ No newline at end of file
@@ -1,6 +1,18 @@
 pub mod add_asset;
 pub use add_asset::*;
 
+pub mod add_asset_oracle;
+pub use add_asset_oracle::*;
+
+pub mod remove_asset_oracle;
+pub use remove_asset_oracle::*;
+
+pub mod update_asset;
+pub use update_asset::*;
+
+pub mod migrate_asset;
+pub use migrate_asset::*;
+
 pub mod initialize_rlp;
 pub use initialize_rlp::*;
 
@@ -10,9 +22,48 @@ pub use freeze_functionality::*;
 pub mod update_deposit_cap;
 pub use update_deposit_cap::*;
 
+pub mod update_deposit_value_cap;
+pub use update_deposit_value_cap::*;
+
+pub mod update_cooldown_duration;
+pub use update_cooldown_duration::*;
+
+pub mod update_oracle_max_age;
+pub use update_oracle_max_age::*;
+
+pub mod update_swap_fee;
+pub use update_swap_fee::*;
+
+pub mod update_pool_swap_fee;
+pub use update_pool_swap_fee::*;
+
+pub mod update_target_weights;
+pub use update_target_weights::*;
+
+pub mod update_protocol_fee;
+pub use update_protocol_fee::*;
+
+pub mod claim_protocol_fees;
+pub use claim_protocol_fees::*;
+
 pub mod initialize_lp;
 pub use initialize_lp::*;
 
+pub mod add_pool_asset;
+pub use add_pool_asset::*;
+
+pub mod remove_pool_asset;
+pub use remove_pool_asset::*;
+
+pub mod migrate_liquidity_pool;
+pub use migrate_liquidity_pool::*;
+
+pub mod migrate_settings;
+pub use migrate_settings::*;
+
+pub mod update_asset_limits;
+pub use update_asset_limits::*;
+
 pub mod action_update;
 pub use action_update::*;
 
@@ -0,0 +1,75 @@
+use anchor_lang::prelude::*;
+use crate::states::*;
+use crate::constants::*;
+use crate::errors::RlpError;
+use crate::events::RemoveAssetOracleEvent;
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct RemoveAssetOracleArgs {
+    /// Slot in `additional_oracles`.
+    pub index: u8
+}
+
+/// Drops an additional oracle, freeing its slot. Replacing one is a removal followed by
+/// `add_asset_oracle`. The primary oracle is rotated through `update_asset` instead.
+pub fn remove_asset_oracle(
+    ctx: Context<RemoveAssetOracle>,
+    args: RemoveAssetOracleArgs
+) -> Result<()> {
+    let RemoveAssetOracleArgs {
+        index
+    } = args;
+
+    let asset = &mut ctx.accounts.asset;
+
+    let oracle = asset
+        .additional_oracles
+        .get_mut(index as usize)
+        .and_then(|slot| slot.take())
+        .ok_or(RlpError::InvalidOracle)?;
+
+    emit!(RemoveAssetOracleEvent {
+        admin: ctx.accounts.signer.key(),
+        asset: asset.mint,
+        oracle: oracle.key().copied().unwrap_or_default()
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct RemoveAssetOracle<'info> {
+    #[account(
+        mut,
+    )]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [
+            PERMISSIONS_SEED.as_bytes(),
+            signer.key().as_ref()
+        ],
+        bump = admin.bump,
+        constraint = admin.can_perform_protocol_action(Action::Management, &settings.access_control) @ RlpError::InvalidSigner,
+    )]
+    pub admin: Account<'info, UserPermissions>,
+
+    #[account(
+        seeds = [
+            SETTINGS_SEED.as_bytes()
+        ],
+        bump = settings.bump,
+        constraint = !settings.access_control.killswitch.is_frozen(&Action::Management) @ RlpError::Frozen,
+    )]
+    pub settings: Box<Account<'info, Settings>>,
+
+    #[account(
+        mut,
+        seeds = [
+            ASSET_SEED.as_bytes(),
+            &asset.mint.to_bytes()
+        ],
+        bump = asset.bump,
+    )]
+    pub asset: Account<'info, Asset>,
+}
\ No newline at end of file
@@ -0,0 +1,102 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, TokenAccount};
+use crate::states::*;
+use crate::constants::*;
+use crate::errors::RlpError;
+use crate::events::RemovePoolAssetEvent;
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct RemovePoolAssetArgs {
+    pub liquidity_pool_index: u8
+}
+
+/// Retires an asset from the pool. Its reserve, accrued protocol fees included, has to be
+/// emptied first.
+pub fn remove_pool_asset(
+    ctx: Context<RemovePoolAsset>,
+    args: RemovePoolAssetArgs
+) -> Result<()> {
+    let RemovePoolAssetArgs {
+        liquidity_pool_index: _
+    } = args;
+
+    let asset = &ctx.accounts.asset;
+    let asset_index = asset.index;
+    let asset_mint = asset.mint;
+
+    let liquidity_pool = &mut ctx.accounts.liquidity_pool;
+    liquidity_pool.remove_asset(asset_index)?;
+
+    emit!(RemovePoolAssetEvent {
+        admin: ctx.accounts.signer.key(),
+        liquidity_pool: liquidity_pool.key(),
+        asset: asset_mint
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(args: RemovePoolAssetArgs)]
+pub struct RemovePoolAsset<'info> {
+    #[account(
+        mut,
+    )]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [
+            PERMISSIONS_SEED.as_bytes(),
+            signer.key().as_ref()
+        ],
+        bump = admin.bump,
+        constraint = admin.can_perform_protocol_action(Action::Management, &settings.access_control) @ RlpError::InvalidSigner,
+    )]
+    pub admin: Account<'info, UserPermissions>,
+
+    #[account(
+        seeds = [
+            SETTINGS_SEED.as_bytes()
+        ],
+        bump = settings.bump,
+        constraint = !settings.access_control.killswitch.is_frozen(&Action::Management) @ RlpError::Frozen,
+    )]
+    pub settings: Box<Account<'info, Settings>>,
+
+    #[account(
+        mut,
+        seeds = [
+            LIQUIDITY_POOL_SEED.as_bytes(),
+            &args.liquidity_pool_index.to_le_bytes()
+        ],
+        bump = liquidity_pool.bump,
+        realloc = LiquidityPool::space(liquidity_pool.asset_count.saturating_sub(1) as usize),
+        realloc::payer = signer,
+        realloc::zero = false,
+    )]
+    pub liquidity_pool: Account<'info, LiquidityPool>,
+
+    #[account(
+        seeds = [
+            ASSET_SEED.as_bytes(),
+            &asset_mint.key().to_bytes()
+        ],
+        bump = asset.bump,
+    )]
+    pub asset: Account<'info, Asset>,
+
+    #[account(
+        address = asset.mint
+    )]
+    pub asset_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        associated_token::mint = asset_mint,
+        associated_token::authority = liquidity_pool,
+        constraint = pool_asset_account.amount == 0 @ RlpError::ReserveNotEmpty,
+    )]
+    pub pool_asset_account: Box<Account<'info, TokenAccount>>,
+
+    #[account()]
+    pub system_program: Program<'info, System>,
+}
\ No newline at end of file
@@ -0,0 +1,129 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+use pyth_solana_receiver_sdk::ID as PYTH_PROGRAM_ID;
+use crate::states::*;
+use crate::constants::*;
+use crate::errors::RlpError;
+use crate::events::UpdateAssetEvent;
+use crate::helpers::resolve_oracle;
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct UpdateAssetArgs {
+    pub access_level: Option<AccessLevel>
+}
+
+/// Flips the access level and/or rotates the primary oracle to the `oracle` account. For fixed
+/// price assets with a depeg guard, the guard feed is rotated and the fixed price is kept.
+///
+/// Remaining accounts: the stake pool accounts when rotating to an exchange-rate oracle (see `resolve_oracle`).
+pub fn update_asset<'a>(
+    ctx: Context<'_, '_, 'a, 'a, UpdateAsset<'a>>,
+    args: UpdateAssetArgs
+) -> Result<()> {
+    let UpdateAssetArgs {
+        access_level
+    } = args;
+
+    let asset = &mut ctx.accounts.asset;
+    let oracle_info = &ctx.accounts.oracle;
+
+    require!(
+        access_level.is_some() || oracle_info.is_some(),
+        RlpError::InvalidInput
+    );
+
+    if let Some(oracle_info) = oracle_info {
+        let oracle = match asset.oracle {
+            Oracle::Fixed { price, exponent, depeg_guard: Some(depeg_guard) } => {
+                require!(
+                    oracle_info.owner.as_ref() == PYTH_PROGRAM_ID.as_ref(),
+                    RlpError::InvalidOracle
+                );
+
+                Oracle::Fixed {
+                    price,
+                    exponent,
+                    depeg_guard: Some(DepegGuard {
+                        oracle: oracle_info.key(),
+                        ..depeg_guard
+                    }),
+                }
+            },
+            _ => resolve_oracle(oracle_info, ctx.remaining_accounts, &ctx.accounts.asset_mint)?,
+        };
+
+        require!(
+            !asset.additional_oracles.iter().flatten().any(|existing| existing == &oracle),
+            RlpError::InvalidInput
+        );
+
+        // Make sure the new feed parses and can be priced before switching to it.
+        let mut pricing_accounts = ctx.remaining_accounts.to_vec();
+        pricing_accounts.push(oracle_info.to_account_info());
+
+        oracle
+            .get_price(&pricing_accounts, &Clock::get()?, asset.oracle_max_age)?
+            .check_confidence(asset.max_confidence_bps)?;
+
+        asset.oracle = oracle;
+    }
+
+    if let Some(access_level) = access_level {
+        asset.access_level = access_level;
+    }
+
+    emit!(UpdateAssetEvent {
+        admin: ctx.accounts.signer.key(),
+        asset: asset.mint,
+        oracle: asset.oracle.key().copied().unwrap_or_default(),
+        access_level: asset.access_level
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateAsset<'info> {
+    #[account(
+        mut,
+    )]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [
+            PERMISSIONS_SEED.as_bytes(),
+            signer.key().as_ref()
+        ],
+        bump = admin.bump,
+        constraint = admin.can_perform_protocol_action(Action::Management, &settings.access_control) @ RlpError::InvalidSigner,
+    )]
+    pub admin: Account<'info, UserPermissions>,
+
+    #[account(
+        seeds = [
+            SETTINGS_SEED.as_bytes()
+        ],
+        bump = settings.bump,
+        constraint = !settings.access_control.killswitch.is_frozen(&Action::Management) @ RlpError::Frozen,
+    )]
+    pub settings: Box<Account<'info, Settings>>,
+
+    #[account(
+        mut,
+        seeds = [
+            ASSET_SEED.as_bytes(),
+            &asset_mint.key().to_bytes()
+        ],
+        bump = asset.bump,
+    )]
+    pub asset: Account<'info, Asset>,
+
+    #[account(
+        address = asset.mint
+    )]
+    pub asset_mint: Account<'info, Mint>,
+
+    /// CHECK: We're checking owner of this account in `resolve_oracle`. Omitted when only the access level changes.
+    #[account()]
+    pub oracle: Option<UncheckedAccount<'info>>,
+}
\ No newline at end of file
@@ -0,0 +1,105 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+use crate::states::*;
+use crate::constants::*;
+use crate::errors::RlpError;
+use crate::events::UpdateAssetLimitsEvent;
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct UpdateAssetLimitsArgs {
+    pub liquidity_pool_index: u8,
+    pub paused: bool,
+    pub max_reserve: Option<u64>,
+    pub max_share_bps: Option<u16>
+}
+
+/// Sets the inflow limits of a pool asset. Pausing an asset only blocks deposits and swaps
+/// into it, withdrawals and swaps out of it keep working.
+pub fn update_asset_limits(
+    ctx: Context<UpdateAssetLimits>,
+    args: UpdateAssetLimitsArgs
+) -> Result<()> {
+    let UpdateAssetLimitsArgs {
+        liquidity_pool_index: _,
+        paused,
+        max_reserve,
+        max_share_bps
+    } = args;
+
+    require!(
+        max_share_bps.map_or(true, |max_share_bps| max_share_bps > 0 && max_share_bps as u64 <= BPS_DENOMINATOR),
+        RlpError::InvalidInput
+    );
+
+    let asset = &ctx.accounts.asset;
+    let liquidity_pool = &mut ctx.accounts.liquidity_pool;
+
+    liquidity_pool.set_asset_limits(asset.index, AssetLimits {
+        paused,
+        max_reserve,
+        max_share_bps
+    })?;
+
+    emit!(UpdateAssetLimitsEvent {
+        admin: ctx.accounts.signer.key(),
+        liquidity_pool: liquidity_pool.key(),
+        asset: asset.mint,
+        paused,
+        max_reserve,
+        max_share_bps
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(args: UpdateAssetLimitsArgs)]
+pub struct UpdateAssetLimits<'info> {
+    #[account(
+        mut,
+    )]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [
+            PERMISSIONS_SEED.as_bytes(),
+            signer.key().as_ref()
+        ],
+        bump = admin.bump,
+        constraint = admin.can_perform_protocol_action(Action::Management, &settings.access_control) @ RlpError::InvalidSigner,
+    )]
+    pub admin: Account<'info, UserPermissions>,
+
+    #[account(
+        seeds = [
+            SETTINGS_SEED.as_bytes()
+        ],
+        bump = settings.bump,
+        constraint = !settings.access_control.killswitch.is_frozen(&Action::Management) @ RlpError::Frozen,
+    )]
+    pub settings: Box<Account<'info, Settings>>,
+
+    #[account(
+        mut,
+        seeds = [
+            LIQUIDITY_POOL_SEED.as_bytes(),
+            &args.liquidity_pool_index.to_le_bytes()
+        ],
+        bump = liquidity_pool.bump,
+    )]
+    pub liquidity_pool: Account<'info, LiquidityPool>,
+
+    #[account(
+        seeds = [
+            ASSET_SEED.as_bytes(),
+            &asset_mint.key().to_bytes()
+        ],
+        bump = asset.bump,
+    )]
+    pub asset: Account<'info, Asset>,
+
+    #[account(
+        address = asset.mint
+    )]
+    pub asset_mint: Box<Account<'info, Mint>>,
+}
\ No newline at end of file
@@ -0,0 +1,80 @@
+use anchor_lang::prelude::*;
+use crate::states::*;
+use crate::constants::*;
+use crate::errors::RlpError;
+use crate::events::UpdateCooldownDurationEvent;
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct UpdateCooldownDurationArgs {
+    pub liquidity_pool_index: u8,
+    pub cooldown_duration: u64
+}
+
+/// Only applies to new withdrawal requests, existing cooldowns keep their `unlock_ts`.
+pub fn update_cooldown_duration(
+    ctx: Context<UpdateCooldownDuration>,
+    args: UpdateCooldownDurationArgs
+) -> Result<()> {
+    let UpdateCooldownDurationArgs {
+        liquidity_pool_index: _,
+        cooldown_duration
+    } = args;
+
+    require!(
+        cooldown_duration <= MAX_COOLDOWN_DURATION,
+        RlpError::InvalidInput
+    );
+
+    let liquidity_pool = &mut ctx.accounts.liquidity_pool;
+    let old_cooldown_duration = liquidity_pool.cooldown_duration;
+
+    liquidity_pool.cooldown_duration = cooldown_duration;
+
+    emit!(UpdateCooldownDurationEvent {
+        admin: ctx.accounts.signer.key(),
+        liquidity_pool: liquidity_pool.key(),
+        old_cooldown_duration,
+        new_cooldown_duration: cooldown_duration
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(args: UpdateCooldownDurationArgs)]
+pub struct UpdateCooldownDuration<'info> {
+    #[account(
+        mut,
+    )]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            PERMISSIONS_SEED.as_bytes(),
+            signer.key().as_ref()
+        ],
+        bump = admin.bump,
+        constraint = admin.can_perform_protocol_action(Action::Management, &settings.access_control) @ RlpError::InvalidSigner,
+    )]
+    pub admin: Account<'info, UserPermissions>,
+
+    #[account(
+        seeds = [
+            SETTINGS_SEED.as_bytes()
+        ],
+        bump = settings.bump,
+        constraint = !settings.access_control.killswitch.is_frozen(&Action::Management) @ RlpError::Frozen,
+    )]
+    pub settings: Box<Account<'info, Settings>>,
+
+    #[account(
+        mut,
+        seeds = [
+            LIQUIDITY_POOL_SEED.as_bytes(),
+            &args.liquidity_pool_index.to_le_bytes()
+        ],
+        bump = liquidity_pool.bump,
+    )]
+    pub liquidity_pool: Account<'info, LiquidityPool>,
+}
\ No newline at end of file
@@ -0,0 +1,73 @@
+use anchor_lang::prelude::*;
+use crate::states::*;
+use crate::constants::*;
+use crate::errors::RlpError;
+use crate::events::UpdateDepositValueCapEvent;
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct UpdateDepositValueCapArgs {
+    pub liquidity_pool_index: u8,
+    /// Maximum pool value, at `PRECISION` decimals. `None` removes the cap.
+    pub new_value_cap: Option<u128>
+}
+
+/// Caps the pool value deposits may grow the pool to, independently of the LP supply cap
+/// set by `update_deposit_cap`.
+pub fn update_deposit_value_cap(
+    ctx: Context<UpdateDepositValueCap>,
+    args: UpdateDepositValueCapArgs
+) -> Result<()> {
+    let UpdateDepositValueCapArgs {
+        liquidity_pool_index: _,
+        new_value_cap
+    } = args;
+
+    let liquidity_pool = &mut ctx.accounts.liquidity_pool;
+    liquidity_pool.deposit_value_cap = new_value_cap;
+
+    emit!(UpdateDepositValueCapEvent {
+        admin: ctx.accounts.signer.key(),
+        liquidity_pool: liquidity_pool.key(),
+        new_value_cap
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(args: UpdateDepositValueCapArgs)]
+pub struct UpdateDepositValueCap<'info> {
+    #[account(
+        mut,
+    )]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [
+            PERMISSIONS_SEED.as_bytes(),
+            signer.key().as_ref()
+        ],
+        bump = admin.bump,
+        constraint = admin.can_perform_protocol_action(Action::UpdateDepositCap, &settings.access_control) @ RlpError::InvalidSigner,
+    )]
+    pub admin: Account<'info, UserPermissions>,
+
+    #[account(
+        seeds = [
+            SETTINGS_SEED.as_bytes()
+        ],
+        bump = settings.bump,
+        constraint = !settings.access_control.killswitch.is_frozen(&Action::UpdateDepositCap) @ RlpError::Frozen,
+    )]
+    pub settings: Box<Account<'info, Settings>>,
+
+    #[account(
+        mut,
+        seeds = [
+            LIQUIDITY_POOL_SEED.as_bytes(),
+            &args.liquidity_pool_index.to_le_bytes()
+        ],
+        bump = liquidity_pool.bump,
+    )]
+    pub liquidity_pool: Account<'info, LiquidityPool>,
+}
\ No newline at end of file
@@ -0,0 +1,72 @@
+use anchor_lang::prelude::*;
+use crate::states::*;
+use crate::constants::*;
+use crate::errors::RlpError;
+use crate::events::UpdateOracleMaxAgeEvent;
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct UpdateOracleMaxAgeArgs {
+    pub max_age: u64
+}
+
+pub fn update_oracle_max_age(
+    ctx: Context<UpdateOracleMaxAge>,
+    args: UpdateOracleMaxAgeArgs
+) -> Result<()> {
+    let UpdateOracleMaxAgeArgs {
+        max_age
+    } = args;
+
+    require!(
+        max_age > 0 && max_age <= MAX_ORACLE_MAX_AGE,
+        RlpError::InvalidInput
+    );
+
+    let asset = &mut ctx.accounts.asset;
+    asset.oracle_max_age = max_age;
+
+    emit!(UpdateOracleMaxAgeEvent {
+        admin: ctx.accounts.signer.key(),
+        asset: asset.mint,
+        max_age
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateOracleMaxAge<'info> {
+    #[account(
+        mut,
+    )]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [
+            PERMISSIONS_SEED.as_bytes(),
+            signer.key().as_ref()
+        ],
+        bump = admin.bump,
+        constraint = admin.can_perform_protocol_action(Action::Management, &settings.access_control) @ RlpError::InvalidSigner,
+    )]
+    pub admin: Account<'info, UserPermissions>,
+
+    #[account(
+        seeds = [
+            SETTINGS_SEED.as_bytes()
+        ],
+        bump = settings.bump,
+        constraint = !settings.access_control.killswitch.is_frozen(&Action::Management) @ RlpError::Frozen,
+    )]
+    pub settings: Box<Account<'info, Settings>>,
+
+    #[account(
+        mut,
+        seeds = [
+            ASSET_SEED.as_bytes(),
+            &asset.mint.to_bytes()
+        ],
+        bump = asset.bump,
+    )]
+    pub asset: Account<'info, Asset>,
+}
\ No newline at end of file
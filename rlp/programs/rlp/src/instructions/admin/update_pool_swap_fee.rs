@@ -0,0 +1,100 @@
+use anchor_lang::prelude::*;
+use crate::states::*;
+use crate::constants::*;
+use crate::errors::RlpError;
+use crate::events::UpdatePoolSwapFeeEvent;
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct UpdatePoolSwapFeeArgs {
+    pub liquidity_pool_index: u8,
+    /// Asset indices of the pair to override, or `None` to set the pool-wide fee.
+    pub pair: Option<[u8; 2]>,
+    /// New fee, or `None` to clear it and fall back to the next fee in line.
+    pub swap_fee_bps: Option<u16>
+}
+
+pub fn update_pool_swap_fee(
+    ctx: Context<UpdatePoolSwapFee>,
+    args: UpdatePoolSwapFeeArgs
+) -> Result<()> {
+    let UpdatePoolSwapFeeArgs {
+        liquidity_pool_index: _,
+        pair,
+        swap_fee_bps
+    } = args;
+
+    if let Some(fee) = swap_fee_bps {
+        require!(
+            fee <= MAX_SWAP_FEE_BPS,
+            RlpError::InvalidInput
+        );
+    }
+
+    let liquidity_pool = &mut ctx.accounts.liquidity_pool;
+
+    match pair {
+        Some([asset_a, asset_b]) => {
+            require!(
+                asset_a != asset_b,
+                RlpError::InvalidInput
+            );
+            require!(
+                liquidity_pool.has_asset(asset_a) && liquidity_pool.has_asset(asset_b),
+                RlpError::AssetNotWhitelisted
+            );
+
+            liquidity_pool.set_pair_fee(asset_a, asset_b, swap_fee_bps)?;
+        }
+        None => {
+            liquidity_pool.swap_fee_bps = swap_fee_bps;
+        }
+    }
+
+    emit!(UpdatePoolSwapFeeEvent {
+        admin: ctx.accounts.signer.key(),
+        liquidity_pool: liquidity_pool.key(),
+        pair,
+        swap_fee_bps
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(args: UpdatePoolSwapFeeArgs)]
+pub struct UpdatePoolSwapFee<'info> {
+    #[account(
+        mut,
+    )]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            PERMISSIONS_SEED.as_bytes(),
+            signer.key().as_ref()
+        ],
+        bump = admin.bump,
+        constraint = admin.can_perform_protocol_action(Action::Management, &settings.access_control) @ RlpError::InvalidSigner,
+    )]
+    pub admin: Account<'info, UserPermissions>,
+
+    #[account(
+        seeds = [
+            SETTINGS_SEED.as_bytes()
+        ],
+        bump = settings.bump,
+        constraint = !settings.access_control.killswitch.is_frozen(&Action::Management) @ RlpError::Frozen,
+    )]
+    pub settings: Box<Account<'info, Settings>>,
+
+    #[account(
+        mut,
+        seeds = [
+            LIQUIDITY_POOL_SEED.as_bytes(),
+            &args.liquidity_pool_index.to_le_bytes()
+        ],
+        bump = liquidity_pool.bump,
+    )]
+    pub liquidity_pool: Account<'info, LiquidityPool>,
+}
\ No newline at end of file
@@ -0,0 +1,67 @@
+use anchor_lang::prelude::*;
+use crate::states::*;
+use crate::constants::*;
+use crate::errors::RlpError;
+use crate::events::UpdateProtocolFeeEvent;
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct UpdateProtocolFeeArgs {
+    pub protocol_fee_bps: u16,
+    pub treasury: Pubkey
+}
+
+pub fn update_protocol_fee(
+    ctx: Context<UpdateProtocolFee>,
+    args: UpdateProtocolFeeArgs
+) -> Result<()> {
+    let UpdateProtocolFeeArgs {
+        protocol_fee_bps,
+        treasury
+    } = args;
+
+    require!(
+        protocol_fee_bps as u64 <= BPS_DENOMINATOR,
+        RlpError::InvalidInput
+    );
+
+    let settings = &mut ctx.accounts.settings;
+    settings.protocol_fee_bps = protocol_fee_bps;
+    settings.treasury = treasury;
+
+    emit!(UpdateProtocolFeeEvent {
+        admin: ctx.accounts.signer.key(),
+        protocol_fee_bps,
+        treasury
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateProtocolFee<'info> {
+    #[account(
+        mut,
+    )]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            PERMISSIONS_SEED.as_bytes(),
+            signer.key().as_ref()
+        ],
+        bump = admin.bump,
+        constraint = admin.can_perform_protocol_action(Action::Management, &settings.access_control) @ RlpError::InvalidSigner,
+    )]
+    pub admin: Account<'info, UserPermissions>,
+
+    #[account(
+        mut,
+        seeds = [
+            SETTINGS_SEED.as_bytes()
+        ],
+        bump = settings.bump,
+        constraint = !settings.access_control.killswitch.is_frozen(&Action::Management) @ RlpError::Frozen,
+    )]
+    pub settings: Box<Account<'info, Settings>>,
+}
\ No newline at end of file
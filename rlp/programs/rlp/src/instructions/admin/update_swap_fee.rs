@@ -0,0 +1,65 @@
+use anchor_lang::prelude::*;
+use crate::states::*;
+use crate::constants::*;
+use crate::errors::RlpError;
+use crate::events::UpdateSwapFeeEvent;
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct UpdateSwapFeeArgs {
+    pub swap_fee_bps: u16
+}
+
+pub fn update_swap_fee(
+    ctx: Context<UpdateSwapFee>,
+    args: UpdateSwapFeeArgs
+) -> Result<()> {
+    let UpdateSwapFeeArgs {
+        swap_fee_bps
+    } = args;
+
+    require!(
+        swap_fee_bps <= MAX_SWAP_FEE_BPS,
+        RlpError::InvalidInput
+    );
+
+    let settings = &mut ctx.accounts.settings;
+    let old_swap_fee_bps = settings.swap_fee_bps;
+    settings.swap_fee_bps = swap_fee_bps;
+
+    emit!(UpdateSwapFeeEvent {
+        admin: ctx.accounts.signer.key(),
+        old_swap_fee_bps,
+        new_swap_fee_bps: swap_fee_bps
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct UpdateSwapFee<'info> {
+    #[account(
+        mut,
+    )]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            PERMISSIONS_SEED.as_bytes(),
+            signer.key().as_ref()
+        ],
+        bump = admin.bump,
+        constraint = admin.can_perform_protocol_action(Action::Management, &settings.access_control) @ RlpError::InvalidSigner,
+    )]
+    pub admin: Account<'info, UserPermissions>,
+
+    #[account(
+        mut,
+        seeds = [
+            SETTINGS_SEED.as_bytes()
+        ],
+        bump = settings.bump,
+        constraint = !settings.access_control.killswitch.is_frozen(&Action::Management) @ RlpError::Frozen,
+    )]
+    pub settings: Box<Account<'info, Settings>>,
+}
\ No newline at end of file
@@ -0,0 +1,101 @@
+use anchor_lang::prelude::*;
+use crate::states::*;
+use crate::constants::*;
+use crate::errors::RlpError;
+use crate::events::UpdateTargetWeightsEvent;
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct UpdateTargetWeightsArgs {
+    pub liquidity_pool_index: u8,
+    /// One weight per pool asset, in pool order, summing to 10 000. All zero disables weights.
+    pub target_weights_bps: Vec<u16>,
+    pub imbalance_fee_bps: u16,
+    pub max_weight_deviation_bps: u16
+}
+
+pub fn update_target_weights(
+    ctx: Context<UpdateTargetWeights>,
+    args: UpdateTargetWeightsArgs
+) -> Result<()> {
+    let UpdateTargetWeightsArgs {
+        liquidity_pool_index: _,
+        target_weights_bps,
+        imbalance_fee_bps,
+        max_weight_deviation_bps
+    } = args;
+
+    let liquidity_pool = &mut ctx.accounts.liquidity_pool;
+
+    require!(
+        target_weights_bps.len() == liquidity_pool.asset_count as usize,
+        RlpError::InvalidInput
+    );
+
+    let total_weight: u64 = target_weights_bps
+        .iter()
+        .map(|&weight| weight as u64)
+        .sum();
+
+    require!(
+        total_weight == BPS_DENOMINATOR || total_weight == 0,
+        RlpError::InvalidInput
+    );
+
+    require!(
+        imbalance_fee_bps as u64 <= BPS_DENOMINATOR && max_weight_deviation_bps as u64 <= BPS_DENOMINATOR,
+        RlpError::InvalidInput
+    );
+
+    liquidity_pool.target_weights_bps = target_weights_bps.clone();
+    liquidity_pool.imbalance_fee_bps = imbalance_fee_bps;
+    liquidity_pool.max_weight_deviation_bps = max_weight_deviation_bps;
+
+    emit!(UpdateTargetWeightsEvent {
+        admin: ctx.accounts.signer.key(),
+        liquidity_pool: liquidity_pool.key(),
+        target_weights_bps,
+        imbalance_fee_bps,
+        max_weight_deviation_bps
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(args: UpdateTargetWeightsArgs)]
+pub struct UpdateTargetWeights<'info> {
+    #[account(
+        mut,
+    )]
+    pub signer: Signer<'info>,
+
+    #[account(
+        mut,
+        seeds = [
+            PERMISSIONS_SEED.as_bytes(),
+            signer.key().as_ref()
+        ],
+        bump = admin.bump,
+        constraint = admin.can_perform_protocol_action(Action::Management, &settings.access_control) @ RlpError::InvalidSigner,
+    )]
+    pub admin: Account<'info, UserPermissions>,
+
+    #[account(
+        seeds = [
+            SETTINGS_SEED.as_bytes()
+        ],
+        bump = settings.bump,
+        constraint = !settings.access_control.killswitch.is_frozen(&Action::Management) @ RlpError::Frozen,
+    )]
+    pub settings: Box<Account<'info, Settings>>,
+
+    #[account(
+        mut,
+        seeds = [
+            LIQUIDITY_POOL_SEED.as_bytes(),
+            &args.liquidity_pool_index.to_le_bytes()
+        ],
+        bump = liquidity_pool.bump,
+    )]
+    pub liquidity_pool: Account<'info, LiquidityPool>,
+}
\ No newline at end of file
@@ -8,4 +8,7 @@ pub mod user;
 pub use user::*;
 
 pub mod swap;
-pub use swap::*;
\ No newline at end of file
+pub use swap::*;
+
+pub mod rewards;
+pub use rewards::*;
\ No newline at end of file
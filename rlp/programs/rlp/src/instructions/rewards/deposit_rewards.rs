@@ -0,0 +1,145 @@
+use crate::constants::*;
+use crate::errors::RlpError;
+use crate::events::DepositRewardEvent;
+use crate::helpers::action_check_protocol;
+use crate::states::{Action, Asset, LiquidityPool, RewardPool, Settings, UserPermissions};
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{Mint, Token, TokenAccount};
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct DepositRewardsArgs {
+    pub liquidity_pool_index: u8,
+    pub amount: u64,
+}
+
+/// Transfers rewards into the pool reserve without minting LP tokens. The rewards
+/// vest linearly over the reward pool's window, raising the value of every LP token.
+pub fn deposit_rewards(ctx: Context<DepositRewards>, args: DepositRewardsArgs) -> Result<()> {
+    let DepositRewardsArgs {
+        liquidity_pool_index: _,
+        amount,
+    } = args;
+
+    let settings = &ctx.accounts.settings;
+    let permissions = &ctx.accounts.permissions;
+
+    action_check_protocol(
+        Action::DepositRewards,
+        permissions.as_deref(),
+        &settings.access_control,
+    )?;
+
+    require!(amount > 0, RlpError::InvalidInput);
+
+    let liquidity_pool = &ctx.accounts.liquidity_pool;
+    let asset = &ctx.accounts.asset;
+
+    require!(
+        liquidity_pool.has_asset(asset.index),
+        RlpError::AssetNotWhitelisted
+    );
+
+    let signer = &ctx.accounts.signer;
+
+    liquidity_pool.deposit(
+        signer,
+        amount,
+        &ctx.accounts.user_asset_account,
+        &ctx.accounts.pool_asset_account,
+        &ctx.accounts.token_program,
+    )?;
+
+    let clock = Clock::get()?;
+
+    ctx.accounts
+        .reward_pool
+        .stream(asset.index, amount, clock.unix_timestamp as u64)?;
+
+    emit!(DepositRewardEvent {
+        authority: signer.key(),
+        asset: ctx.accounts.asset_mint.key(),
+        amount,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(args: DepositRewardsArgs)]
+pub struct DepositRewards<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [
+            SETTINGS_SEED.as_bytes(),
+        ],
+        bump,
+        constraint = !settings.access_control.killswitch.is_frozen(&Action::DepositRewards) @ RlpError::Frozen,
+    )]
+    pub settings: Box<Account<'info, Settings>>,
+
+    #[account(
+        seeds = [
+            PERMISSIONS_SEED.as_bytes(),
+            signer.key().as_ref()
+        ],
+        bump = permissions.bump
+    )]
+    pub permissions: Option<Account<'info, UserPermissions>>,
+
+    #[account(
+        seeds = [
+            LIQUIDITY_POOL_SEED.as_bytes(),
+            &args.liquidity_pool_index.to_le_bytes()
+        ],
+        bump,
+        constraint = liquidity_pool.index == args.liquidity_pool_index,
+    )]
+    pub liquidity_pool: Account<'info, LiquidityPool>,
+
+    #[account(
+        mut,
+        seeds = [
+            REWARD_POOL_SEED.as_bytes(),
+            liquidity_pool.key().as_ref()
+        ],
+        bump = reward_pool.bump,
+    )]
+    pub reward_pool: Account<'info, RewardPool>,
+
+    #[account(
+        seeds = [
+            ASSET_SEED.as_bytes(),
+            &asset_mint.key().to_bytes()
+        ],
+        bump,
+    )]
+    pub asset: Account<'info, Asset>,
+
+    #[account(
+        address = asset.mint
+    )]
+    pub asset_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        token::mint = asset_mint,
+        token::authority = signer,
+    )]
+    pub user_asset_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        associated_token::mint = asset_mint,
+        associated_token::authority = liquidity_pool,
+    )]
+    pub pool_asset_account: Box<Account<'info, TokenAccount>>,
+
+    #[account()]
+    pub token_program: Program<'info, Token>,
+
+    #[account()]
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
\ No newline at end of file
@@ -0,0 +1,91 @@
+use anchor_lang::prelude::*;
+use crate::states::*;
+use crate::constants::*;
+use crate::errors::RlpError;
+use crate::events::InitializeRewardPoolEvent;
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct InitializeRewardPoolArgs {
+    pub liquidity_pool_index: u8,
+    pub vesting_duration: u64
+}
+
+pub fn initialize_reward_pool(
+    ctx: Context<InitializeRewardPool>,
+    args: InitializeRewardPoolArgs
+) -> Result<()> {
+    let InitializeRewardPoolArgs {
+        liquidity_pool_index: _,
+        vesting_duration
+    } = args;
+
+    let liquidity_pool = &ctx.accounts.liquidity_pool;
+    let reward_pool = &mut ctx.accounts.reward_pool;
+
+    reward_pool.set_inner(RewardPool {
+        bump: ctx.bumps.reward_pool,
+        liquidity_pool: liquidity_pool.key(),
+        vesting_duration,
+        schedules: [RewardSchedule::default(); MAX_REWARD_SCHEDULES],
+    });
+
+    emit!(InitializeRewardPoolEvent {
+        admin: ctx.accounts.signer.key(),
+        liquidity_pool: liquidity_pool.key(),
+        vesting_duration
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(args: InitializeRewardPoolArgs)]
+pub struct InitializeRewardPool<'info> {
+    #[account(
+        mut,
+    )]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [
+            PERMISSIONS_SEED.as_bytes(),
+            signer.key().as_ref()
+        ],
+        bump = admin.bump,
+        constraint = admin.can_perform_protocol_action(Action::Management, &settings.access_control) @ RlpError::InvalidSigner,
+    )]
+    pub admin: Account<'info, UserPermissions>,
+
+    #[account(
+        seeds = [
+            SETTINGS_SEED.as_bytes()
+        ],
+        bump = settings.bump,
+        constraint = !settings.access_control.killswitch.is_frozen(&Action::Management) @ RlpError::Frozen,
+    )]
+    pub settings: Box<Account<'info, Settings>>,
+
+    #[account(
+        seeds = [
+            LIQUIDITY_POOL_SEED.as_bytes(),
+            &args.liquidity_pool_index.to_le_bytes()
+        ],
+        bump = liquidity_pool.bump,
+    )]
+    pub liquidity_pool: Account<'info, LiquidityPool>,
+
+    #[account(
+        init,
+        payer = signer,
+        seeds = [
+            REWARD_POOL_SEED.as_bytes(),
+            liquidity_pool.key().as_ref()
+        ],
+        bump,
+        space = 8 + RewardPool::INIT_SPACE
+    )]
+    pub reward_pool: Account<'info, RewardPool>,
+
+    #[account()]
+    pub system_program: Program<'info, System>,
+}
\ No newline at end of file
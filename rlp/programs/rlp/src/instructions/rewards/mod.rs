@@ -0,0 +1,8 @@
+pub mod initialize_reward_pool;
+pub use initialize_reward_pool::*;
+
+pub mod deposit_rewards;
+pub use deposit_rewards::*;
+
+pub mod update_vesting_duration;
+pub use update_vesting_duration::*;
\ No newline at end of file
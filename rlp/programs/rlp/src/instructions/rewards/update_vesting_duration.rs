@@ -0,0 +1,83 @@
+use anchor_lang::prelude::*;
+use crate::states::*;
+use crate::constants::*;
+use crate::errors::RlpError;
+use crate::events::UpdateVestingDurationEvent;
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct UpdateVestingDurationArgs {
+    pub liquidity_pool_index: u8,
+    pub vesting_duration: u64
+}
+
+/// Only applies to rewards deposited afterwards, running schedules keep their `end_ts`.
+pub fn update_vesting_duration(
+    ctx: Context<UpdateVestingDuration>,
+    args: UpdateVestingDurationArgs
+) -> Result<()> {
+    let UpdateVestingDurationArgs {
+        liquidity_pool_index: _,
+        vesting_duration
+    } = args;
+
+    let reward_pool = &mut ctx.accounts.reward_pool;
+    let old_vesting_duration = reward_pool.vesting_duration;
+
+    reward_pool.vesting_duration = vesting_duration;
+
+    emit!(UpdateVestingDurationEvent {
+        admin: ctx.accounts.signer.key(),
+        liquidity_pool: ctx.accounts.liquidity_pool.key(),
+        old_vesting_duration,
+        new_vesting_duration: vesting_duration
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(args: UpdateVestingDurationArgs)]
+pub struct UpdateVestingDuration<'info> {
+    #[account(
+        mut,
+    )]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [
+            PERMISSIONS_SEED.as_bytes(),
+            signer.key().as_ref()
+        ],
+        bump = admin.bump,
+        constraint = admin.can_perform_protocol_action(Action::Management, &settings.access_control) @ RlpError::InvalidSigner,
+    )]
+    pub admin: Account<'info, UserPermissions>,
+
+    #[account(
+        seeds = [
+            SETTINGS_SEED.as_bytes()
+        ],
+        bump = settings.bump,
+        constraint = !settings.access_control.killswitch.is_frozen(&Action::Management) @ RlpError::Frozen,
+    )]
+    pub settings: Box<Account<'info, Settings>>,
+
+    #[account(
+        seeds = [
+            LIQUIDITY_POOL_SEED.as_bytes(),
+            &args.liquidity_pool_index.to_le_bytes()
+        ],
+        bump = liquidity_pool.bump,
+    )]
+    pub liquidity_pool: Account<'info, LiquidityPool>,
+
+    #[account(
+        mut,
+        seeds = [
+            REWARD_POOL_SEED.as_bytes(),
+            liquidity_pool.key().as_ref()
+        ],
+        bump = reward_pool.bump,
+    )]
+    pub reward_pool: Account<'info, RewardPool>,
+}
\ No newline at end of file
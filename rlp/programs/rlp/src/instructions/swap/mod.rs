@@ -1,2 +1,11 @@
 pub mod swap;
-pub use swap::*;
\ No newline at end of file
+pub use swap::*;
+
+pub mod swap_exact_out;
+pub use swap_exact_out::*;
+
+pub mod swap_route;
+pub use swap_route::*;
+
+pub mod quote_swap;
+pub use quote_swap::*;
\ No newline at end of file
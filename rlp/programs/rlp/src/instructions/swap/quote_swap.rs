@@ -0,0 +1,152 @@
+use crate::constants::*;
+use crate::errors::RlpError;
+use crate::instructions::{price_swap, PoolContext, SwapMode, SwapSide};
+use crate::states::*;
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, TokenAccount};
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct QuoteSwapArgs {
+    pub amount_in: u64,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct QuoteSwapResult {
+    pub amount_out: u64,
+    pub impact_bps: u16,
+    pub fee_bps: u16,
+    /// Total fee taken from the output, LP and protocol share combined.
+    pub fee: u64,
+}
+
+/// Prices a `swap` of `amount_in` without moving funds. The result is set as return data.
+pub fn quote_swap<'a>(
+    ctx: Context<'_, '_, 'a, 'a, QuoteSwap<'a>>,
+    args: QuoteSwapArgs,
+) -> Result<QuoteSwapResult> {
+    let QuoteSwapArgs { amount_in } = args;
+
+    require!(amount_in > 0, RlpError::InvalidInput);
+
+    let liquidity_pool = &ctx.accounts.liquidity_pool;
+    let reward_pool = RewardPool::load(&ctx.accounts.reward_pool, &liquidity_pool.key())?;
+
+    let (quote, fee_bps) = price_swap(
+        PoolContext {
+            liquidity_pool,
+            settings: &ctx.accounts.settings,
+            reward_pool: reward_pool.as_ref(),
+        },
+        ctx.accounts.admin.as_deref(),
+        SwapSide {
+            mint: &ctx.accounts.token_from,
+            asset: &ctx.accounts.token_from_asset,
+            oracle: ctx.accounts.token_from_oracle.as_ref(),
+            reserve: &ctx.accounts.token_from_pool,
+        },
+        SwapSide {
+            mint: &ctx.accounts.token_to,
+            asset: &ctx.accounts.token_to_asset,
+            oracle: ctx.accounts.token_to_oracle.as_ref(),
+            reserve: &ctx.accounts.token_to_pool,
+        },
+        ctx.remaining_accounts,
+        SwapMode::ExactIn {
+            amount_in,
+            min_out: None,
+        },
+    )?;
+
+    Ok(QuoteSwapResult {
+        amount_out: quote.amount_out,
+        impact_bps: quote.impact_bps,
+        fee_bps,
+        fee: quote
+            .lp_fee
+            .checked_add(quote.protocol_fee)
+            .ok_or(RlpError::MathOverflow)?,
+    })
+}
+
+#[derive(Accounts)]
+pub struct QuoteSwap<'info> {
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [
+            PERMISSIONS_SEED.as_bytes(),
+            signer.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub admin: Option<Account<'info, UserPermissions>>,
+
+    #[account(
+        seeds = [
+            SETTINGS_SEED.as_bytes()
+        ],
+        bump,
+    )]
+    pub settings: Box<Account<'info, Settings>>,
+
+    #[account(
+        seeds = [
+            LIQUIDITY_POOL_SEED.as_bytes(),
+            &liquidity_pool.index.to_le_bytes()
+        ],
+        bump,
+    )]
+    pub liquidity_pool: Account<'info, LiquidityPool>,
+
+    /// CHECK: Checked against the reward pool PDA, may not be initialized yet.
+    #[account()]
+    pub reward_pool: UncheckedAccount<'info>,
+
+    #[account()]
+    pub token_from: Box<Account<'info, Mint>>,
+
+    #[account(
+        seeds = [
+            ASSET_SEED.as_bytes(),
+            token_from.key().as_ref()
+        ],
+        bump
+    )]
+    pub token_from_asset: Account<'info, Asset>,
+
+    /// CHECK: Directly checking the address. Not needed for fixed price assets.
+    #[account(
+        constraint = token_from_asset.oracle.key() == Some(&token_from_oracle.key()) @ RlpError::InvalidOracle
+    )]
+    pub token_from_oracle: Option<AccountInfo<'info>>,
+
+    #[account()]
+    pub token_to: Box<Account<'info, Mint>>,
+
+    #[account(
+        seeds = [
+            ASSET_SEED.as_bytes(),
+            token_to.key().as_ref()
+        ],
+        bump
+    )]
+    pub token_to_asset: Account<'info, Asset>,
+
+    /// CHECK: Directly checking the address. Not needed for fixed price assets.
+    #[account(
+        constraint = token_to_asset.oracle.key() == Some(&token_to_oracle.key()) @ RlpError::InvalidOracle
+    )]
+    pub token_to_oracle: Option<AccountInfo<'info>>,
+
+    #[account(
+        associated_token::authority = liquidity_pool,
+        associated_token::mint = token_from
+    )]
+    pub token_from_pool: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        associated_token::authority = liquidity_pool,
+        associated_token::mint = token_to
+    )]
+    pub token_to_pool: Box<Account<'info, TokenAccount>>,
+}
\ No newline at end of file
@@ -1,7 +1,8 @@
 use crate::errors::RlpError;
 use crate::events::SwapEvent;
 use crate::states::*;
-use crate::{constants::*, helpers::action_check_protocol};
+use crate::constants::*;
+use crate::helpers::{action_check_protocol, SwapPricing, SwapQuote};
 use anchor_lang::prelude::*;
 use anchor_spl::{
     associated_token::AssociatedToken,
@@ -14,39 +15,88 @@ pub struct SwapArgs {
     pub min_out: Option<u64>,
 }
 
-pub fn swap(ctx: Context<Swap>, args: SwapArgs) -> Result<()> {
+pub fn swap<'a>(ctx: Context<'_, '_, 'a, 'a, Swap<'a>>, args: SwapArgs) -> Result<()> {
     let SwapArgs { min_out, amount_in } = args;
 
     require!(amount_in > 0, RlpError::InvalidInput);
 
-    let clock = &Clock::get()?;
+    process_swap(ctx, SwapMode::ExactIn { amount_in, min_out })
+}
 
-    let signer = &ctx.accounts.signer;
-    let liquidity_pool = &ctx.accounts.liquidity_pool;
+#[derive(Clone, Copy)]
+pub enum SwapMode {
+    ExactIn { amount_in: u64, min_out: Option<u64> },
+    ExactOut { amount_out: u64, max_in: u64 },
+}
+
+/// Swaps touching a private asset require the `Swap` permission, others follow the access map.
+pub fn check_swap_permissions(
+    token_from_asset: &Asset,
+    token_to_asset: &Asset,
+    admin: Option<&UserPermissions>,
+    settings: &Settings,
+) -> Result<()> {
+    if token_from_asset.access_level == AccessLevel::Private
+        || token_to_asset.access_level == AccessLevel::Private
+    {
+        require!(
+            admin.is_some_and(|admin| admin
+                .can_perform_protocol_action(Action::Swap, &settings.access_control)),
+            RlpError::PermissionsTooLow
+        );
+    } else {
+        action_check_protocol(Action::Swap, admin, &settings.access_control)?;
+    }
+
+    Ok(())
+}
 
-    let token_from = &ctx.accounts.token_from;
-    let token_to = &ctx.accounts.token_to;
+/// The pool a swap is priced against, with the accounts its valuation depends on.
+pub struct PoolContext<'a, 'info> {
+    pub liquidity_pool: &'a Account<'info, LiquidityPool>,
+    pub settings: &'a Account<'info, Settings>,
+    pub reward_pool: Option<&'a RewardPool>,
+}
+
+/// One leg of a swap, or the asset of a deposit, as seen by the pool.
+pub struct SwapSide<'a, 'info> {
+    pub mint: &'a Mint,
+    pub asset: &'a Asset,
+    pub oracle: Option<&'a AccountInfo<'info>>,
+    pub reserve: &'a TokenAccount,
+}
+
+/// Runs every check `swap` does and prices the trade, without moving any funds.
+pub fn price_swap<'info>(
+    pool: PoolContext<'_, 'info>,
+    admin: Option<&UserPermissions>,
+    from: SwapSide<'_, 'info>,
+    to: SwapSide<'_, 'info>,
+    remaining_accounts: &[AccountInfo<'info>],
+    mode: SwapMode,
+) -> Result<(SwapQuote, u16)> {
+    let PoolContext {
+        liquidity_pool,
+        settings,
+        reward_pool,
+    } = pool;
+
+    let clock = &Clock::get()?;
 
     require!(
-        token_from.key() != token_to.key(),
+        from.asset.mint != to.asset.mint,
         RlpError::InvalidInput
     );
 
-    let token_from_asset = &ctx.accounts.token_from_asset;
-    let token_to_asset = &ctx.accounts.token_to_asset;
-
     require!(
-        liquidity_pool.has_asset(token_from_asset.index),
+        liquidity_pool.has_asset(from.asset.index),
         RlpError::AssetNotWhitelisted
     );
     require!(
-        liquidity_pool.has_asset(token_to_asset.index),
+        liquidity_pool.has_asset(to.asset.index),
         RlpError::AssetNotWhitelisted
     );
 
-    let admin = &ctx.accounts.admin;
-    let settings = &ctx.accounts.settings;
-
     require!(
         !settings
             .access_control
@@ -55,92 +105,178 @@ pub fn swap(ctx: Context<Swap>, args: SwapArgs) -> Result<()> {
         RlpError::Frozen
     );
 
-    if token_from_asset.access_level == AccessLevel::Private
-        || token_to_asset.access_level == AccessLevel::Private
-    {
-        require!(
-            admin.is_some()
-                && admin
-                    .as_ref()
-                    .unwrap()
-                    .can_perform_protocol_action(Action::Swap, &settings.access_control),
-            RlpError::PermissionsTooLow
-        );
+    check_swap_permissions(from.asset, to.asset, admin, settings)?;
+
+    // Oracle feeds plus any additional pricing accounts passed as remaining accounts. Pools with
+    // target weights, or a share cap on the asset swapped in, need the full pool valuation layout
+    // there instead, see `calculate_total_pool_value`.
+    let mut pricing_accounts = remaining_accounts.to_vec();
+    pricing_accounts.extend(from.oracle.cloned());
+    pricing_accounts.extend(to.oracle.cloned());
+
+    let token_from_price = from.asset.get_price(&pricing_accounts, clock)?;
+    let token_to_price = to.asset.get_price(&pricing_accounts, clock)?;
+
+    // Rewards that are still vesting and accrued protocol fees sit in the reserve but aren't
+    // available to swappers.
+    let now = clock.unix_timestamp as u64;
+    let reserve_to_amount =
+        liquidity_pool.withdrawable_reserve(to.asset.index, to.reserve.amount, reward_pool, now)?;
+    let reserve_from_amount =
+        liquidity_pool.withdrawable_reserve(from.asset.index, from.reserve.amount, reward_pool, now)?;
+
+    let mut fee_bps =
+        liquidity_pool.resolve_swap_fee(from.asset.index, to.asset.index, settings);
+
+    let share_capped = liquidity_pool
+        .asset_limits_for(from.asset.index)
+        .max_share_bps
+        .is_some();
+
+    let total_value = if liquidity_pool.has_target_weights() || share_capped {
+        liquidity_pool
+            .calculate_total_pool_value(
+                remaining_accounts,
+                liquidity_pool,
+                settings,
+                reward_pool,
+                clock,
+            )?
+            .to_imprecise()
+            .ok_or(RlpError::MathOverflow)?
     } else {
-        action_check_protocol(
-            Action::Swap,
-            admin.as_deref(),
-            &settings.access_control,
+        0
+    };
+
+    if liquidity_pool.has_target_weights() {
+        let trade_value = match mode {
+            SwapMode::ExactIn { amount_in, .. } => token_from_price.mul(amount_in, from.mint.decimals)?,
+            SwapMode::ExactOut { amount_out, .. } => token_to_price.mul(amount_out, to.mint.decimals)?,
+        };
+
+        fee_bps = liquidity_pool.weighted_swap_fee(
+            fee_bps,
+            from.asset.index,
+            to.asset.index,
+            SwapComposition {
+                total_value,
+                value_in: token_from_price.mul(reserve_from_amount, from.mint.decimals)?,
+                value_out: token_to_price.mul(reserve_to_amount, to.mint.decimals)?,
+                trade_value,
+            },
         )?;
     }
 
-    let token_from_oracle = &ctx.accounts.token_from_oracle;
-    let token_to_oracle = &ctx.accounts.token_to_oracle;
+    let pricing = SwapPricing {
+        from_price: token_from_price,
+        from_decimals: from.mint.decimals,
+        to_price: token_to_price,
+        to_decimals: to.mint.decimals,
+        reserve_to: reserve_to_amount,
+        fee_bps,
+        protocol_fee_bps: settings.protocol_fee_bps,
+    };
+
+    let quote = match mode {
+        SwapMode::ExactIn { amount_in, min_out } => {
+            let quote = pricing.quote_exact_in(amount_in)?;
+
+            if let Some(min_amount) = min_out {
+                require!(
+                    quote.amount_out >= min_amount,
+                    RlpError::SlippageExceeded
+                );
+            }
+
+            quote
+        }
+        SwapMode::ExactOut { amount_out, max_in } => {
+            let quote = pricing.quote_exact_out(amount_out)?;
+
+            require!(
+                quote.amount_in <= max_in,
+                RlpError::SlippageExceeded
+            );
+
+            quote
+        }
+    };
+
+    require!(
+        reserve_to_amount as u128
+            >= (quote.amount_out as u128)
+                .checked_add(quote.protocol_fee as u128)
+                .ok_or(RlpError::MathOverflow)?,
+        RlpError::NotEnoughFunds
+    );
+
+    liquidity_pool.check_asset_inflow(
+        from.asset.index,
+        from.reserve
+            .amount
+            .checked_add(quote.amount_in)
+            .ok_or(RlpError::MathOverflow)?,
+    )?;
 
-    let token_from_price = token_from_asset.get_price(token_from_oracle, clock)?;
-    let token_to_price = token_to_asset.get_price(token_to_oracle, clock)?;
+    if share_capped {
+        // Swaps move value between assets, the pool total only grows by the fees kept.
+        let value_in_after = token_from_price.mul(
+            reserve_from_amount
+                .checked_add(quote.amount_in)
+                .ok_or(RlpError::MathOverflow)?,
+            from.mint.decimals,
+        )?;
 
-    let token_from_signer_account = &ctx.accounts.token_from_signer_account;
-    let token_to_signer_account = &ctx.accounts.token_to_signer_account;
+        liquidity_pool.check_asset_share(from.asset.index, value_in_after, total_value, total_value)?;
+    }
+
+    Ok((quote, fee_bps))
+}
+
+pub fn process_swap<'a>(ctx: Context<'_, '_, 'a, 'a, Swap<'a>>, mode: SwapMode) -> Result<()> {
+    let signer = &ctx.accounts.signer;
+    let liquidity_pool = &ctx.accounts.liquidity_pool;
 
     let token_from_pool = &ctx.accounts.token_from_pool;
     let token_to_pool = &ctx.accounts.token_to_pool;
 
-    let token_to_decimals = &ctx.accounts.token_to.decimals;
-    let token_from_decimals = &ctx.accounts.token_from.decimals;
-
-    let token_program = &ctx.accounts.token_program;
+    let reward_pool = RewardPool::load(&ctx.accounts.reward_pool, &liquidity_pool.key())?;
+
+    let (quote, _) = price_swap(
+        PoolContext {
+            liquidity_pool,
+            settings: &ctx.accounts.settings,
+            reward_pool: reward_pool.as_ref(),
+        },
+        ctx.accounts.admin.as_deref(),
+        SwapSide {
+            mint: &ctx.accounts.token_from,
+            asset: &ctx.accounts.token_from_asset,
+            oracle: ctx.accounts.token_from_oracle.as_ref(),
+            reserve: token_from_pool,
+        },
+        SwapSide {
+            mint: &ctx.accounts.token_to,
+            asset: &ctx.accounts.token_to_asset,
+            oracle: ctx.accounts.token_to_oracle.as_ref(),
+            reserve: token_to_pool,
+        },
+        ctx.remaining_accounts,
+        mode,
+    )?;
 
-    let fee = &ctx.accounts.settings.swap_fee_bps;
-    let reserve_to_amount = token_to_pool.amount;
-
-    let oracle_amount_out: u64 = token_from_price
-        .mul(amount_in, *token_from_decimals)?
-        .checked_div(token_to_price.mul(1, *token_to_decimals)?)
-        .ok_or(RlpError::MathOverflow)?
-        .try_into()
-        .map_err(|_| RlpError::MathOverflow)?;
-
-    let impact_factor = (oracle_amount_out as u128)
-        .checked_mul(BPS_PRECISION)
-        .ok_or(RlpError::MathOverflow)?
-        .checked_div(
-            (reserve_to_amount as u128)
-                .checked_add(oracle_amount_out as u128)
-                .ok_or(RlpError::MathOverflow)?,
-        )
-        .ok_or(RlpError::MathOverflow)?;
-
-    let impact_complement = BPS_PRECISION
-        .checked_sub(impact_factor)
-        .ok_or(RlpError::MathOverflow)?;
-    let amount_after_impact = (oracle_amount_out as u128)
-        .checked_mul(impact_complement)
-        .ok_or(RlpError::MathOverflow)?
-        .checked_div(BPS_PRECISION)
-        .ok_or(RlpError::MathOverflow)?;
-
-    let fee_complement = BPS_PRECISION
-        .checked_sub(*fee as u128)
-        .ok_or(RlpError::MathOverflow)?;
-
-    let amount_out = amount_after_impact
-        .checked_mul(fee_complement)
-        .ok_or(RlpError::MathOverflow)?
-        .checked_div(BPS_PRECISION)
-        .ok_or(RlpError::MathOverflow)?;
+    let SwapQuote {
+        amount_in,
+        amount_out,
+        lp_fee,
+        protocol_fee,
+        ..
+    } = quote;
 
-    require!(
-        token_to_pool.amount as u128 >= amount_out,
-        RlpError::NotEnoughFunds
-    );
+    let token_from_signer_account = &ctx.accounts.token_from_signer_account;
+    let token_to_signer_account = &ctx.accounts.token_to_signer_account;
 
-    if let Some(min_amount) = min_out {
-        require!(
-            amount_out as u128 >= min_amount as u128,
-            RlpError::SlippageExceeded
-        );
-    }
+    let token_program = &ctx.accounts.token_program;
 
     let lp_seeds = &[
         LIQUIDITY_POOL_SEED.as_bytes(),
@@ -170,14 +306,22 @@ pub fn swap(ctx: Context<Swap>, args: SwapArgs) -> Result<()> {
             },
             &[lp_seeds],
         ),
-        amount_out as u64,
+        amount_out,
     )?;
 
+    let liquidity_pool_key = liquidity_pool.key();
+
+    ctx.accounts
+        .liquidity_pool
+        .accrue_protocol_fee(ctx.accounts.token_to_asset.index, protocol_fee)?;
+
     emit!(SwapEvent {
         signer: signer.key(),
-        liquidity_pool: liquidity_pool.key(),
+        liquidity_pool: liquidity_pool_key,
         amount_in,
-        amount_out: amount_out as u64,
+        amount_out,
+        lp_fee,
+        protocol_fee,
     });
 
     Ok(())
@@ -206,6 +350,7 @@ pub struct Swap<'info> {
     pub settings: Box<Account<'info, Settings>>,
 
     #[account(
+        mut,
         seeds = [
             LIQUIDITY_POOL_SEED.as_bytes(),
             &liquidity_pool.index.to_le_bytes()
@@ -214,6 +359,10 @@ pub struct Swap<'info> {
     )]
     pub liquidity_pool: Account<'info, LiquidityPool>,
 
+    /// CHECK: Checked against the reward pool PDA, may not be initialized yet.
+    #[account()]
+    pub reward_pool: UncheckedAccount<'info>,
+
     #[account()]
     pub token_from: Box<Account<'info, Mint>>,
 
@@ -226,11 +375,11 @@ pub struct Swap<'info> {
     )]
     pub token_from_asset: Account<'info, Asset>,
 
-    /// CHECK: Directly checking the address
+    /// CHECK: Directly checking the address. Not needed for fixed price assets.
     #[account(
-        constraint = token_from_oracle.key() == *token_from_asset.oracle.key() @ RlpError::InvalidOracle
+        constraint = token_from_asset.oracle.key() == Some(&token_from_oracle.key()) @ RlpError::InvalidOracle
     )]
-    pub token_from_oracle: AccountInfo<'info>,
+    pub token_from_oracle: Option<AccountInfo<'info>>,
 
     #[account()]
     pub token_to: Box<Account<'info, Mint>>,
@@ -244,11 +393,11 @@ pub struct Swap<'info> {
     )]
     pub token_to_asset: Account<'info, Asset>,
 
-    /// CHECK: Directly checking the address
+    /// CHECK: Directly checking the address. Not needed for fixed price assets.
     #[account(
-        constraint = token_to_oracle.key() == *token_to_asset.oracle.key() @ RlpError::InvalidOracle
+        constraint = token_to_asset.oracle.key() == Some(&token_to_oracle.key()) @ RlpError::InvalidOracle
     )]
-    pub token_to_oracle: AccountInfo<'info>,
+    pub token_to_oracle: Option<AccountInfo<'info>>,
 
     #[account(
         mut,
@@ -0,0 +1,21 @@
+use crate::errors::RlpError;
+use crate::instructions::{process_swap, Swap, SwapMode};
+use anchor_lang::prelude::*;
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct SwapExactOutArgs {
+    pub amount_out: u64,
+    pub max_in: u64,
+}
+
+/// Swaps for exactly `amount_out` of `token_to`, paying at most `max_in` of `token_from`.
+pub fn swap_exact_out<'a>(
+    ctx: Context<'_, '_, 'a, 'a, Swap<'a>>,
+    args: SwapExactOutArgs,
+) -> Result<()> {
+    let SwapExactOutArgs { amount_out, max_in } = args;
+
+    require!(amount_out > 0, RlpError::InvalidInput);
+
+    process_swap(ctx, SwapMode::ExactOut { amount_out, max_in })
+}
\ No newline at end of file
@@ -0,0 +1,313 @@
+use crate::constants::*;
+use crate::errors::RlpError;
+use crate::events::{SwapEvent, SwapRouteEvent};
+use crate::helpers::{SwapPricing, SwapQuote};
+use crate::instructions::check_swap_permissions;
+use crate::states::*;
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::get_associated_token_address;
+use anchor_spl::token::{transfer, Mint, Token, TokenAccount, Transfer};
+
+pub const MAX_ROUTE_HOPS: usize = 3;
+
+/// Accounts each hop takes from the remaining accounts:
+/// [liquidity_pool, asset_in, asset_out, pool_reserve_in, pool_reserve_out, mint_out, reward_pool]
+pub const ROUTE_HOP_ACCOUNTS: usize = 7;
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct SwapRouteArgs {
+    pub amount_in: u64,
+    pub min_out: Option<u64>,
+    /// Liquidity pool index of every hop, in order.
+    pub route: Vec<u8>,
+}
+
+/// Swaps `token_from` into `token_to` through a chain of liquidity pools. Intermediate
+/// amounts move straight from one pool's reserve into the next, slippage is only checked
+/// on the final output.
+///
+/// Remaining accounts hold `ROUTE_HOP_ACCOUNTS` per hop, followed by the pricing accounts
+/// of every asset along the route.
+pub fn swap_route<'a>(
+    ctx: Context<'_, '_, 'a, 'a, SwapRoute<'a>>,
+    args: SwapRouteArgs,
+) -> Result<()> {
+    let SwapRouteArgs {
+        amount_in,
+        min_out,
+        route,
+    } = args;
+
+    require!(amount_in > 0, RlpError::InvalidInput);
+    require!(
+        !route.is_empty() && route.len() <= MAX_ROUTE_HOPS,
+        RlpError::InvalidInput
+    );
+
+    for (i, pool_index) in route.iter().enumerate() {
+        require!(!route[..i].contains(pool_index), RlpError::InvalidInput);
+    }
+
+    let clock = &Clock::get()?;
+    let signer = &ctx.accounts.signer;
+    let settings = &ctx.accounts.settings;
+    let admin = &ctx.accounts.admin;
+    let token_program = &ctx.accounts.token_program;
+
+    require!(
+        !settings
+            .access_control
+            .killswitch
+            .is_frozen(&Action::Swap),
+        RlpError::Frozen
+    );
+
+    let remaining_accounts = ctx.remaining_accounts;
+    let hop_accounts_len = route.len() * ROUTE_HOP_ACCOUNTS;
+
+    require!(
+        remaining_accounts.len() >= hop_accounts_len,
+        RlpError::InvalidInput
+    );
+
+    let mut amount = amount_in;
+    let mut mint_in = ctx.accounts.token_from.key();
+    let mut decimals_in = ctx.accounts.token_from.decimals;
+
+    for (hop, &pool_index) in route.iter().enumerate() {
+        let accounts = &remaining_accounts[hop * ROUTE_HOP_ACCOUNTS..(hop + 1) * ROUTE_HOP_ACCOUNTS];
+
+        let mut liquidity_pool: Account<LiquidityPool> = Account::try_from(&accounts[0])?;
+        let (expected_liquidity_pool, _) = Pubkey::find_program_address(
+            &[LIQUIDITY_POOL_SEED.as_bytes(), &pool_index.to_le_bytes()],
+            &crate::ID,
+        );
+
+        require!(
+            liquidity_pool.key() == expected_liquidity_pool,
+            RlpError::InvalidInput
+        );
+
+        // Pricing against target weights needs the whole pool valued, which routes don't carry.
+        require!(
+            !liquidity_pool.has_target_weights(),
+            RlpError::PoolImbalance
+        );
+
+        let asset_in: Account<Asset> = Account::try_from(&accounts[1])?;
+        let asset_out: Account<Asset> = Account::try_from(&accounts[2])?;
+
+        require!(asset_in.mint == mint_in, RlpError::InvalidInput);
+        require!(asset_out.mint != mint_in, RlpError::InvalidInput);
+
+        require!(
+            liquidity_pool.has_asset(asset_in.index),
+            RlpError::AssetNotWhitelisted
+        );
+        require!(
+            liquidity_pool.has_asset(asset_out.index),
+            RlpError::AssetNotWhitelisted
+        );
+
+        check_swap_permissions(&asset_in, &asset_out, admin.as_deref(), settings)?;
+
+        let reserve_in_info = &accounts[3];
+        let reserve_out: Account<TokenAccount> = Account::try_from(&accounts[4])?;
+        let mint_out: Account<Mint> = Account::try_from(&accounts[5])?;
+
+        require!(
+            reserve_in_info.key() == get_associated_token_address(&liquidity_pool.key(), &asset_in.mint),
+            RlpError::InvalidInput
+        );
+        require!(
+            reserve_out.key() == get_associated_token_address(&liquidity_pool.key(), &asset_out.mint),
+            RlpError::InvalidInput
+        );
+        require!(mint_out.key() == asset_out.mint, RlpError::InvalidInput);
+
+        let reward_pool = RewardPool::load(&accounts[6], &liquidity_pool.key())?;
+
+        // Like target weights, share caps need the whole pool valued.
+        let limits_in = liquidity_pool.asset_limits_for(asset_in.index);
+        require!(
+            limits_in.max_share_bps.is_none(),
+            RlpError::AssetCapExceeded
+        );
+
+        let from_price = asset_in.get_price(remaining_accounts, clock)?;
+        let to_price = asset_out.get_price(remaining_accounts, clock)?;
+
+        // Rewards that are still vesting and accrued protocol fees sit in the reserve but
+        // aren't available to swappers.
+        let reserve_to_amount = liquidity_pool.withdrawable_reserve(
+            asset_out.index,
+            reserve_out.amount,
+            reward_pool.as_ref(),
+            clock.unix_timestamp as u64,
+        )?;
+
+        let pricing = SwapPricing {
+            from_price,
+            from_decimals: decimals_in,
+            to_price,
+            to_decimals: mint_out.decimals,
+            reserve_to: reserve_to_amount,
+            fee_bps: liquidity_pool.resolve_swap_fee(asset_in.index, asset_out.index, settings),
+            protocol_fee_bps: settings.protocol_fee_bps,
+        };
+
+        let SwapQuote {
+            amount_in: hop_amount_in,
+            amount_out: hop_amount_out,
+            lp_fee,
+            protocol_fee,
+            ..
+        } = pricing.quote_exact_in(amount)?;
+
+        require!(
+            reserve_to_amount as u128
+                >= (hop_amount_out as u128)
+                    .checked_add(protocol_fee as u128)
+                    .ok_or(RlpError::MathOverflow)?,
+            RlpError::NotEnoughFunds
+        );
+
+        let reserve_in_after = if limits_in.max_reserve.is_some() {
+            let reserve_in: Account<TokenAccount> = Account::try_from(reserve_in_info)?;
+
+            // Later hops were already paid into this reserve by the previous pool.
+            if hop == 0 {
+                reserve_in
+                    .amount
+                    .checked_add(hop_amount_in)
+                    .ok_or(RlpError::MathOverflow)?
+            } else {
+                reserve_in.amount
+            }
+        } else {
+            0
+        };
+
+        liquidity_pool.check_asset_inflow(asset_in.index, reserve_in_after)?;
+
+        // Later hops are funded by the previous pool paying straight into this reserve.
+        if hop == 0 {
+            transfer(
+                CpiContext::new(
+                    token_program.to_account_info(),
+                    Transfer {
+                        from: ctx.accounts.token_from_signer_account.to_account_info(),
+                        to: reserve_in_info.to_account_info(),
+                        authority: signer.to_account_info(),
+                    },
+                ),
+                hop_amount_in,
+            )?;
+        }
+
+        let destination = if hop + 1 == route.len() {
+            ctx.accounts.token_to_signer_account.to_account_info()
+        } else {
+            remaining_accounts[(hop + 1) * ROUTE_HOP_ACCOUNTS + 3].to_account_info()
+        };
+
+        let lp_seeds = &[
+            LIQUIDITY_POOL_SEED.as_bytes(),
+            &liquidity_pool.index.to_le_bytes(),
+            &[liquidity_pool.bump],
+        ];
+
+        transfer(
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                Transfer {
+                    from: reserve_out.to_account_info(),
+                    to: destination,
+                    authority: liquidity_pool.to_account_info(),
+                },
+                &[lp_seeds],
+            ),
+            hop_amount_out,
+        )?;
+
+        liquidity_pool.accrue_protocol_fee(asset_out.index, protocol_fee)?;
+        liquidity_pool.exit(&crate::ID)?;
+
+        emit!(SwapEvent {
+            signer: signer.key(),
+            liquidity_pool: liquidity_pool.key(),
+            amount_in: hop_amount_in,
+            amount_out: hop_amount_out,
+            lp_fee,
+            protocol_fee,
+        });
+
+        amount = hop_amount_out;
+        mint_in = asset_out.mint;
+        decimals_in = mint_out.decimals;
+    }
+
+    require!(
+        mint_in == ctx.accounts.token_to.key(),
+        RlpError::InvalidInput
+    );
+
+    if let Some(min_amount) = min_out {
+        require!(amount >= min_amount, RlpError::SlippageExceeded);
+    }
+
+    emit!(SwapRouteEvent {
+        signer: signer.key(),
+        route,
+        amount_in,
+        amount_out: amount,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+pub struct SwapRoute<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [
+            PERMISSIONS_SEED.as_bytes(),
+            signer.key().as_ref(),
+        ],
+        bump,
+    )]
+    pub admin: Option<Account<'info, UserPermissions>>,
+
+    #[account(
+        seeds = [
+            SETTINGS_SEED.as_bytes()
+        ],
+        bump,
+    )]
+    pub settings: Box<Account<'info, Settings>>,
+
+    #[account()]
+    pub token_from: Box<Account<'info, Mint>>,
+
+    #[account()]
+    pub token_to: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        token::mint = token_from,
+        token::authority = signer,
+    )]
+    pub token_from_signer_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = token_to,
+        token::authority = signer,
+    )]
+    pub token_to_signer_account: Box<Account<'info, TokenAccount>>,
+
+    #[account()]
+    pub token_program: Program<'info, Token>,
+}
\ No newline at end of file
@@ -0,0 +1,135 @@
+use anchor_lang::prelude::*;
+use crate::constants::*;
+use crate::events::CancelWithdrawalRequestEvent;
+use crate::states::*;
+use anchor_spl::token::{
+    Mint,
+    TokenAccount,
+    Token,
+    transfer,
+    Transfer,
+    close_account,
+    CloseAccount
+};
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct CancelWithdrawalRequestArgs {
+    pub liquidity_pool_id: u8,
+    pub cooldown_id: u64
+}
+
+/// Returns the LP tokens escrowed by `request_withdrawal` and closes the cooldown and its vault.
+pub fn cancel_withdrawal_request(
+    ctx: Context<CancelWithdrawalRequest>,
+    args: CancelWithdrawalRequestArgs
+) -> Result<()> {
+    let CancelWithdrawalRequestArgs {
+        liquidity_pool_id,
+        cooldown_id
+    } = args;
+
+    let signer = &ctx.accounts.signer;
+    let liquidity_pool = &ctx.accounts.liquidity_pool;
+    let cooldown = &ctx.accounts.cooldown;
+    let cooldown_lp_token_account = &ctx.accounts.cooldown_lp_token_account;
+    let token_program = &ctx.accounts.token_program;
+
+    let amount = cooldown_lp_token_account.amount;
+
+    let cooldown_seeds = &[
+        COOLDOWN_SEED.as_bytes(),
+        &liquidity_pool.index.to_le_bytes(),
+        &cooldown_id.to_le_bytes(),
+        &[cooldown.bump]
+    ];
+
+    if amount > 0 {
+        transfer(
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                Transfer {
+                    from: cooldown_lp_token_account.to_account_info(),
+                    to: ctx.accounts.signer_lp_token_account.to_account_info(),
+                    authority: cooldown.to_account_info()
+                },
+                &[cooldown_seeds]
+            ),
+            amount
+        )?;
+    }
+
+    close_account(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            CloseAccount {
+                account: cooldown_lp_token_account.to_account_info(),
+                destination: signer.to_account_info(),
+                authority: cooldown.to_account_info()
+            },
+            &[cooldown_seeds]
+        )
+    )?;
+
+    emit!(CancelWithdrawalRequestEvent {
+        authority: signer.key(),
+        liquidity_pool_id,
+        cooldown_id,
+        amount
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(
+    args: CancelWithdrawalRequestArgs
+)]
+pub struct CancelWithdrawalRequest<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [
+            LIQUIDITY_POOL_SEED.as_bytes(),
+            &args.liquidity_pool_id.to_le_bytes()
+        ],
+        bump = liquidity_pool.bump,
+    )]
+    pub liquidity_pool: Box<Account<'info, LiquidityPool>>,
+
+    #[account(
+        address = liquidity_pool.lp_token
+    )]
+    pub lp_token_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        token::mint = lp_token_mint,
+        token::authority = signer,
+    )]
+    pub signer_lp_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [
+            COOLDOWN_SEED.as_bytes(),
+            &liquidity_pool.index.to_le_bytes(),
+            &args.cooldown_id.to_le_bytes()
+        ],
+        bump = cooldown.bump,
+        close = signer,
+        constraint = cooldown.liquidity_pool_id == args.liquidity_pool_id,
+        constraint = cooldown.authority == signer.key()
+    )]
+    pub cooldown: Box<Account<'info, Cooldown>>,
+
+    #[account(
+        mut,
+        associated_token::mint = lp_token_mint,
+        associated_token::authority = cooldown,
+    )]
+    pub cooldown_lp_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account()]
+    pub token_program: Program<'info, Token>,
+}
\ No newline at end of file
@@ -2,7 +2,8 @@ use crate::constants::*;
 use crate::errors::RlpError;
 use crate::events::DepositEvent;
 use crate::helpers::action_check_protocol;
-use crate::states::{Action, Asset, LiquidityPool, Settings, UserPermissions};
+use crate::instructions::{PoolContext, SwapSide};
+use crate::states::{Action, Asset, LiquidityPool, RewardPool, Settings, UserPermissions};
 use anchor_lang::prelude::*;
 use anchor_spl::associated_token::AssociatedToken;
 use anchor_spl::token::{transfer, Mint, Token, TokenAccount, Transfer};
@@ -35,77 +36,153 @@ pub fn deposit<'a>(ctx: Context<'_, '_, 'a, 'a, Deposit<'a>>, args: DepositArgs)
     let liquidity_pool = &ctx.accounts.liquidity_pool;
     let lp_token = &ctx.accounts.lp_token;
     let token_program = &ctx.accounts.token_program;
-    let token_decimals = &ctx.accounts.asset_mint.decimals;
 
     require!(amount > 0, crate::errors::RlpError::InvalidInput);
 
-    let asset = &ctx.accounts.asset;
+    let reward_pool = RewardPool::load(&ctx.accounts.reward_pool, &liquidity_pool.key())?;
+
+    let lp_tokens_to_mint = price_deposit(
+        PoolContext {
+            liquidity_pool,
+            settings,
+            reward_pool: reward_pool.as_ref(),
+        },
+        lp_token,
+        SwapSide {
+            mint: &ctx.accounts.asset_mint,
+            asset: &ctx.accounts.asset,
+            oracle: ctx.accounts.oracle.as_ref(),
+            reserve: &ctx.accounts.pool_asset_account,
+        },
+        ctx.remaining_accounts,
+        amount,
+    )?;
+
     require!(
-        liquidity_pool.has_asset(asset.index),
+        min_lp_tokens <= lp_tokens_to_mint,
+        RlpError::SlippageExceeded
+    );
+
+    liquidity_pool.deposit(
+        signer,
+        amount,
+        &ctx.accounts.user_asset_account,
+        &ctx.accounts.pool_asset_account,
+        token_program,
+    )?;
+
+    liquidity_pool.mint_lp_token(
+        lp_tokens_to_mint,
+        liquidity_pool,
+        lp_token,
+        &ctx.accounts.user_lp_account,
+        token_program,
+    )?;
+
+    emit!(DepositEvent {
+        from: signer.key(),
+        asset: ctx.accounts.asset_mint.key(),
+        amount,
+    });
+
+    Ok(())
+}
+
+/// Runs every limit check `deposit` does and prices `amount` of the deposited asset in LP
+/// tokens, without moving any funds. The pool is valued before the deposit.
+pub fn price_deposit<'info>(
+    pool: PoolContext<'_, 'info>,
+    lp_token: &Account<'info, Mint>,
+    deposit: SwapSide<'_, 'info>,
+    remaining_accounts: &[AccountInfo<'info>],
+    amount: u64,
+) -> Result<u64> {
+    let PoolContext {
+        liquidity_pool,
+        settings,
+        reward_pool,
+    } = pool;
+
+    require!(
+        liquidity_pool.has_asset(deposit.asset.index),
         RlpError::AssetNotWhitelisted
     );
 
     let clock = Clock::get()?;
 
     let total_pool_value_before = liquidity_pool.calculate_total_pool_value(
-        &ctx.remaining_accounts,
+        remaining_accounts,
         liquidity_pool,
         settings,
+        reward_pool,
         &clock,
     )?;
 
-    liquidity_pool.deposit(
-        signer,
-        amount,
-        &ctx.accounts.user_asset_account,
-        &ctx.accounts.pool_asset_account,
-        token_program,
-    )?;
+    let mut pricing_accounts = remaining_accounts.to_vec();
+    pricing_accounts.extend(deposit.oracle.cloned());
 
-    let asset = &ctx.accounts.asset;
-    let oracle = &ctx.accounts.oracle;
-    let deposit_asset_price = asset.get_price(oracle, &clock)?;
+    let deposit_asset_price = deposit.asset.get_price(&pricing_accounts, &clock)?;
 
-    let deposit_value = PreciseNumber::new(deposit_asset_price.mul(amount, *token_decimals)?)
+    let deposit_value = PreciseNumber::new(deposit_asset_price.mul(amount, deposit.mint.decimals)?)
         .ok_or(RlpError::MathOverflow)?;
 
-    let lp_tokens_to_mint = liquidity_pool.calculate_lp_tokens_on_deposit(
+    let reserve_amount = deposit.reserve.amount;
+
+    liquidity_pool.check_asset_inflow(
+        deposit.asset.index,
+        reserve_amount
+            .checked_add(amount)
+            .ok_or(RlpError::MathOverflow)?,
+    )?;
+
+    if liquidity_pool.asset_limits_for(deposit.asset.index).max_share_bps.is_some() {
+        let total_value_before = total_pool_value_before
+            .to_imprecise()
+            .ok_or(RlpError::MathOverflow)?;
+        let deposit_value = deposit_value
+            .to_imprecise()
+            .ok_or(RlpError::MathOverflow)?;
+
+        let withdrawable_after = liquidity_pool
+            .withdrawable_reserve(
+                deposit.asset.index,
+                reserve_amount,
+                reward_pool,
+                clock.unix_timestamp as u64,
+            )?
+            .checked_add(amount)
+            .ok_or(RlpError::MathOverflow)?;
+
+        liquidity_pool.check_asset_share(
+            deposit.asset.index,
+            deposit_asset_price.mul(withdrawable_after, deposit.mint.decimals)?,
+            total_value_before,
+            total_value_before
+                .checked_add(deposit_value)
+                .ok_or(RlpError::MathOverflow)?,
+        )?;
+    }
+
+    liquidity_pool.check_deposit_value_cap(&total_pool_value_before, &deposit_value)?;
+
+    let lp_tokens = liquidity_pool.calculate_lp_tokens_on_deposit(
         lp_token,
         total_pool_value_before,
         deposit_value,
     )?;
 
-    require!(
-        min_lp_tokens <= lp_tokens_to_mint,
-        RlpError::SlippageExceeded
-    );
-
     if let Some(cap) = liquidity_pool.deposit_cap {
         require!(
             lp_token
                 .supply
-                .checked_add(lp_tokens_to_mint)
+                .checked_add(lp_tokens)
                 .ok_or(RlpError::MathOverflow)?
                 <= cap,
             RlpError::DepositCapOverflow
         );
     }
 
-    liquidity_pool.mint_lp_token(
-        lp_tokens_to_mint,
-        liquidity_pool,
-        lp_token,
-        &ctx.accounts.user_lp_account,
-        token_program,
-    )?;
-
-    emit!(DepositEvent {
-        from: signer.key(),
-        asset: ctx.accounts.asset_mint.key(),
-        amount,
-    });
-
-    Ok(())
+    Ok(lp_tokens)
 }
 
 #[derive(Accounts)]
@@ -142,6 +219,10 @@ pub struct Deposit<'info> {
     )]
     pub liquidity_pool: Account<'info, LiquidityPool>,
 
+    /// CHECK: Checked against the reward pool PDA, may not be initialized yet.
+    #[account()]
+    pub reward_pool: UncheckedAccount<'info>,
+
     #[account(
         mut,
         address = liquidity_pool.lp_token
@@ -184,11 +265,11 @@ pub struct Deposit<'info> {
     )]
     pub pool_asset_account: Box<Account<'info, TokenAccount>>,
 
-    /// CHECK: Directly checking the address
+    /// CHECK: Directly checking the address. Not needed for fixed price assets.
     #[account(
-        constraint = asset.oracle.key() == &oracle.key()
+        constraint = asset.oracle.key() == Some(&oracle.key()) @ RlpError::InvalidOracle
     )]
-    pub oracle: AccountInfo<'info>,
+    pub oracle: Option<AccountInfo<'info>>,
 
     #[account()]
     pub token_program: Program<'info, Token>,
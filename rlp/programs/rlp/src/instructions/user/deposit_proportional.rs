@@ -0,0 +1,298 @@
+use crate::constants::*;
+use crate::errors::RlpError;
+use crate::events::DepositProportionalEvent;
+use crate::helpers::{
+    action_check_protocol, load_assets, load_oracle_prices, load_reserves, load_user_token_accounts,
+};
+use crate::states::{Action, Asset, LiquidityPool, RewardPool, Settings, UserPermissions};
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::AssociatedToken;
+use anchor_spl::token::{transfer, Mint, Token, TokenAccount, Transfer};
+use spl_math::precise_number::PreciseNumber;
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct DepositProportionalArgs {
+    pub liquidity_pool_index: u8,
+    /// Maximum amount per pool asset, in `LiquidityPool::assets` order.
+    pub amounts: Vec<u64>,
+    pub min_lp_tokens: u64,
+}
+
+/// Deposits a basket matching the current reserve ratios. LP tokens are minted pro-rata to the
+/// reserves, oracle prices are only read to enforce `deposit_value_cap`. Only the proportional
+/// part of `amounts` is taken.
+/// Remaining accounts: the pool's assets, its reserves and the signer's token accounts, followed
+/// by the pool's pricing accounts (as in `calculate_total_pool_value`) when the pool has a value cap.
+pub fn deposit_proportional<'a>(
+    ctx: Context<'_, '_, 'a, 'a, DepositProportional<'a>>,
+    args: DepositProportionalArgs,
+) -> Result<()> {
+    let DepositProportionalArgs {
+        liquidity_pool_index: _,
+        amounts,
+        min_lp_tokens,
+    } = args;
+
+    let settings = &ctx.accounts.settings;
+    let permissions = &ctx.accounts.permissions;
+
+    action_check_protocol(
+        Action::Deposit,
+        permissions.as_deref(),
+        &settings.access_control,
+    )?;
+
+    let signer = &ctx.accounts.signer;
+    let liquidity_pool = &ctx.accounts.liquidity_pool;
+    let lp_token = &ctx.accounts.lp_token;
+    let token_program = &ctx.accounts.token_program;
+    let remaining_accounts = &ctx.remaining_accounts;
+
+    require!(
+        amounts.len() == liquidity_pool.asset_count as usize,
+        RlpError::InvalidInput
+    );
+
+    require!(
+        remaining_accounts.len() >= liquidity_pool.asset_count as usize * 3,
+        RlpError::InvalidInput
+    );
+
+    let pricing_accounts = &remaining_accounts[liquidity_pool.asset_count as usize * 3..];
+    require!(
+        liquidity_pool.deposit_value_cap.is_some() || pricing_accounts.is_empty(),
+        RlpError::InvalidInput
+    );
+
+    // The first deposit has no ratios to match and goes through `deposit`.
+    let lp_token_supply = lp_token.supply;
+    require!(lp_token_supply > 0, RlpError::InvalidInput);
+
+    let clock = Clock::get()?;
+
+    let assets: Vec<(Pubkey, Asset)> = load_assets(liquidity_pool, remaining_accounts)?;
+    let asset_datas = assets.iter().map(|(_, asset)| asset).collect::<Vec<&Asset>>();
+    let reserves = load_reserves(liquidity_pool, &asset_datas, remaining_accounts)?;
+    let user_token_accounts = load_user_token_accounts(signer, &asset_datas, remaining_accounts)?;
+
+    let reward_pool = RewardPool::load(&ctx.accounts.reward_pool, &liquidity_pool.key())?;
+
+    let mut reserve_amounts: Vec<u64> = Vec::with_capacity(assets.len());
+    for (asset, (_, reserve)) in asset_datas.iter().zip(reserves.iter()) {
+        reserve_amounts.push(liquidity_pool.withdrawable_reserve(
+            asset.index,
+            reserve.amount,
+            reward_pool.as_ref(),
+            clock.unix_timestamp as u64,
+        )?);
+    }
+
+    // The scarcest asset relative to its reserve bounds the LP tokens minted.
+    let mut lp_tokens_to_mint: Option<u128> = None;
+    for (amount, reserve_amount) in amounts.iter().zip(reserve_amounts.iter()) {
+        if *reserve_amount == 0 {
+            continue;
+        }
+
+        let lp_tokens = (*amount as u128)
+            .checked_mul(lp_token_supply as u128)
+            .ok_or(RlpError::MathOverflow)?
+            .checked_div(*reserve_amount as u128)
+            .ok_or(RlpError::MathOverflow)?;
+
+        lp_tokens_to_mint = Some(lp_tokens_to_mint.map_or(lp_tokens, |current| current.min(lp_tokens)));
+    }
+
+    let lp_tokens_to_mint: u64 = lp_tokens_to_mint
+        .ok_or(RlpError::InvalidInput)?
+        .try_into()
+        .map_err(|_| RlpError::MathOverflow)?;
+
+    require!(lp_tokens_to_mint > 0, RlpError::InvalidInput);
+
+    require!(
+        min_lp_tokens <= lp_tokens_to_mint,
+        RlpError::SlippageExceeded
+    );
+
+    if let Some(cap) = liquidity_pool.deposit_cap {
+        require!(
+            lp_token_supply
+                .checked_add(lp_tokens_to_mint)
+                .ok_or(RlpError::MathOverflow)?
+                <= cap,
+            RlpError::DepositCapOverflow
+        );
+    }
+
+    let mut deposited_amounts: Vec<u64> = Vec::with_capacity(assets.len());
+
+    for i in 0..assets.len() {
+        // Rounded up so existing LPs are never diluted.
+        let deposit_amount: u64 = (lp_tokens_to_mint as u128)
+            .checked_mul(reserve_amounts[i] as u128)
+            .ok_or(RlpError::MathOverflow)?
+            .checked_add(lp_token_supply as u128 - 1)
+            .ok_or(RlpError::MathOverflow)?
+            .checked_div(lp_token_supply as u128)
+            .ok_or(RlpError::MathOverflow)?
+            .try_into()
+            .map_err(|_| RlpError::MathOverflow)?;
+
+        require!(
+            deposit_amount <= amounts[i],
+            RlpError::SlippageExceeded
+        );
+
+        // Proportional deposits keep every share as is, only pauses and reserve caps apply.
+        let (_, reserve) = &reserves[i];
+        liquidity_pool.check_asset_inflow(
+            asset_datas[i].index,
+            reserve
+                .amount
+                .checked_add(deposit_amount)
+                .ok_or(RlpError::MathOverflow)?,
+        )?;
+
+        deposited_amounts.push(deposit_amount);
+    }
+
+    if liquidity_pool.deposit_value_cap.is_some() {
+        // Valued before any transfer, the reserves still hold their previous balances.
+        let total_pool_value_before = liquidity_pool.calculate_total_pool_value(
+            pricing_accounts,
+            liquidity_pool,
+            settings,
+            reward_pool.as_ref(),
+            &clock,
+        )?;
+
+        let prices = load_oracle_prices(&clock, &asset_datas, pricing_accounts)?;
+
+        let mut deposit_value = PreciseNumber::new(0).ok_or(RlpError::MathOverflow)?;
+        for ((asset, price), deposit_amount) in asset_datas
+            .iter()
+            .zip(prices.iter())
+            .zip(deposited_amounts.iter())
+        {
+            // Mints were checked against the assets while valuing the pool.
+            let mint_info = pricing_accounts
+                .iter()
+                .find(|account| account.key().eq(&asset.mint))
+                .ok_or(RlpError::InvalidInput)?;
+            let mint: Account<Mint> = Account::try_from(mint_info)?;
+
+            let asset_value = PreciseNumber::new(price.mul(*deposit_amount, mint.decimals)?)
+                .ok_or(RlpError::MathOverflow)?;
+            deposit_value = deposit_value
+                .checked_add(&asset_value)
+                .ok_or(RlpError::MathOverflow)?;
+        }
+
+        liquidity_pool.check_deposit_value_cap(&total_pool_value_before, &deposit_value)?;
+    }
+
+    for (i, deposit_amount) in deposited_amounts.iter().enumerate() {
+        if *deposit_amount > 0 {
+            let (reserve_key, _) = &reserves[i];
+            let (user_token_account_key, _) = &user_token_accounts[i];
+
+            let reserve_account = remaining_accounts
+                .iter()
+                .find(|account| account.key().eq(reserve_key))
+                .ok_or(RlpError::InvalidInput)?;
+
+            let user_token_account_info = remaining_accounts
+                .iter()
+                .find(|account| account.key().eq(user_token_account_key))
+                .ok_or(RlpError::InvalidInput)?;
+
+            transfer(
+                CpiContext::new(
+                    token_program.to_account_info(),
+                    Transfer {
+                        from: user_token_account_info.to_account_info(),
+                        to: reserve_account.to_account_info(),
+                        authority: signer.to_account_info(),
+                    },
+                ),
+                *deposit_amount,
+            )?;
+        }
+    }
+
+    liquidity_pool.mint_lp_token(
+        lp_tokens_to_mint,
+        liquidity_pool,
+        lp_token,
+        &ctx.accounts.user_lp_account,
+        token_program,
+    )?;
+
+    emit!(DepositProportionalEvent {
+        from: signer.key(),
+        liquidity_pool: liquidity_pool.key(),
+        amounts: deposited_amounts,
+        lp_tokens: lp_tokens_to_mint,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(args: DepositProportionalArgs)]
+pub struct DepositProportional<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [
+            SETTINGS_SEED.as_bytes(),
+        ],
+        bump,
+        constraint = !settings.access_control.killswitch.is_frozen(&Action::Deposit) @ RlpError::Frozen,
+    )]
+    pub settings: Box<Account<'info, Settings>>,
+
+    #[account(
+        seeds = [
+            PERMISSIONS_SEED.as_bytes(),
+            signer.key().as_ref()
+        ],
+        bump = permissions.bump
+    )]
+    pub permissions: Option<Account<'info, UserPermissions>>,
+
+    #[account(
+        seeds = [
+            LIQUIDITY_POOL_SEED.as_bytes(),
+            &args.liquidity_pool_index.to_le_bytes()
+        ],
+        bump,
+        constraint = liquidity_pool.index == args.liquidity_pool_index,
+    )]
+    pub liquidity_pool: Account<'info, LiquidityPool>,
+
+    /// CHECK: Checked against the reward pool PDA, may not be initialized yet.
+    #[account()]
+    pub reward_pool: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = liquidity_pool.lp_token
+    )]
+    pub lp_token: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = lp_token,
+        associated_token::authority = signer,
+    )]
+    pub user_lp_account: Box<Account<'info, TokenAccount>>,
+
+    #[account()]
+    pub token_program: Program<'info, Token>,
+
+    #[account()]
+    pub associated_token_program: Program<'info, AssociatedToken>,
+}
\ No newline at end of file
@@ -0,0 +1,178 @@
+use anchor_lang::prelude::*;
+use anchor_spl::associated_token::get_associated_token_address;
+use crate::constants::*;
+use crate::events::MergeCooldownsEvent;
+use crate::states::*;
+use crate::errors::*;
+use anchor_spl::token::{
+    Mint,
+    TokenAccount,
+    Token,
+    transfer,
+    Transfer,
+    close_account,
+    CloseAccount
+};
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct MergeCooldownsArgs {
+    pub liquidity_pool_id: u8,
+    pub cooldown_id: u64
+}
+
+/// Moves the LP tokens of matured cooldowns into `cooldown` and closes them.
+/// Remaining accounts: `[cooldown, cooldown_lp_token_account]` for every merged cooldown.
+pub fn merge_cooldowns<'a>(
+    ctx: Context<'_, '_, 'a, 'a, MergeCooldowns<'a>>,
+    args: MergeCooldownsArgs
+) -> Result<()> {
+    let MergeCooldownsArgs {
+        liquidity_pool_id,
+        cooldown_id
+    } = args;
+
+    let signer = &ctx.accounts.signer;
+    let liquidity_pool = &ctx.accounts.liquidity_pool;
+    let lp_token_mint = &ctx.accounts.lp_token_mint;
+    let cooldown = &ctx.accounts.cooldown;
+    let cooldown_lp_token_account = &ctx.accounts.cooldown_lp_token_account;
+    let token_program = &ctx.accounts.token_program;
+    let remaining_accounts = ctx.remaining_accounts;
+
+    require!(cooldown.is_cooled()?, RlpError::CooldownInForce);
+
+    require!(
+        !remaining_accounts.is_empty() && remaining_accounts.len() % 2 == 0,
+        RlpError::InvalidInput
+    );
+
+    let mut merged: Vec<u64> = Vec::with_capacity(remaining_accounts.len() / 2);
+
+    for accounts in remaining_accounts.chunks(2) {
+        let (source_info, source_vault_info) = (&accounts[0], &accounts[1]);
+
+        require!(
+            source_info.key() != cooldown.key(),
+            RlpError::InvalidInput
+        );
+
+        let source = Account::<Cooldown>::try_from(source_info)?;
+
+        require!(
+            source.authority == signer.key() && source.liquidity_pool_id == liquidity_pool_id,
+            RlpError::InvalidInput
+        );
+
+        require!(source.is_cooled()?, RlpError::CooldownInForce);
+
+        let source_seeds = &[
+            COOLDOWN_SEED.as_bytes(),
+            &liquidity_pool.index.to_le_bytes(),
+            &source.index.to_le_bytes(),
+            &[source.bump]
+        ];
+
+        let expected_source = Pubkey::create_program_address(source_seeds, &crate::ID)
+            .map_err(|_| RlpError::InvalidInput)?;
+
+        require!(
+            source_info.key() == expected_source,
+            RlpError::InvalidInput
+        );
+
+        require!(
+            source_vault_info.key() == get_associated_token_address(&source_info.key(), &lp_token_mint.key()),
+            RlpError::InvalidInput
+        );
+
+        let source_vault = Account::<TokenAccount>::try_from(source_vault_info)?;
+
+        if source_vault.amount > 0 {
+            transfer(
+                CpiContext::new_with_signer(
+                    token_program.to_account_info(),
+                    Transfer {
+                        from: source_vault_info.to_account_info(),
+                        to: cooldown_lp_token_account.to_account_info(),
+                        authority: source_info.to_account_info()
+                    },
+                    &[source_seeds]
+                ),
+                source_vault.amount
+            )?;
+        }
+
+        close_account(
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                CloseAccount {
+                    account: source_vault_info.to_account_info(),
+                    destination: signer.to_account_info(),
+                    authority: source_info.to_account_info()
+                },
+                &[source_seeds]
+            )
+        )?;
+
+        source.close(signer.to_account_info())?;
+
+        merged.push(source.index);
+    }
+
+    ctx.accounts.cooldown_lp_token_account.reload()?;
+
+    emit!(MergeCooldownsEvent {
+        authority: signer.key(),
+        liquidity_pool_id,
+        cooldown_id,
+        merged,
+        amount: ctx.accounts.cooldown_lp_token_account.amount
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(
+    args: MergeCooldownsArgs
+)]
+pub struct MergeCooldowns<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [
+            LIQUIDITY_POOL_SEED.as_bytes(),
+            &args.liquidity_pool_id.to_le_bytes()
+        ],
+        bump = liquidity_pool.bump,
+    )]
+    pub liquidity_pool: Box<Account<'info, LiquidityPool>>,
+
+    #[account(
+        address = liquidity_pool.lp_token
+    )]
+    pub lp_token_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        seeds = [
+            COOLDOWN_SEED.as_bytes(),
+            &liquidity_pool.index.to_le_bytes(),
+            &args.cooldown_id.to_le_bytes()
+        ],
+        bump = cooldown.bump,
+        constraint = cooldown.liquidity_pool_id == args.liquidity_pool_id,
+        constraint = cooldown.authority == signer.key()
+    )]
+    pub cooldown: Box<Account<'info, Cooldown>>,
+
+    #[account(
+        mut,
+        associated_token::mint = lp_token_mint,
+        associated_token::authority = cooldown,
+    )]
+    pub cooldown_lp_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account()]
+    pub token_program: Program<'info, Token>,
+}
\ No newline at end of file
@@ -1,8 +1,29 @@
 pub mod deposit;
 pub use deposit::*;
 
+pub mod deposit_proportional;
+pub use deposit_proportional::*;
+
 pub mod request_withdraw;
 pub use request_withdraw::*;
 
+pub mod cancel_withdrawal_request;
+pub use cancel_withdrawal_request::*;
+
 pub mod withdraw;
-pub use withdraw::*;
\ No newline at end of file
+pub use withdraw::*;
+
+pub mod withdraw_partial;
+pub use withdraw_partial::*;
+
+pub mod merge_cooldowns;
+pub use merge_cooldowns::*;
+
+pub mod quote_deposit;
+pub use quote_deposit::*;
+
+pub mod quote_withdraw;
+pub use quote_withdraw::*;
+
+pub mod withdraw_single_asset;
+pub use withdraw_single_asset::*;
\ No newline at end of file
@@ -0,0 +1,131 @@
+use crate::constants::*;
+use crate::errors::RlpError;
+use crate::helpers::action_check_protocol;
+use crate::instructions::{price_deposit, PoolContext, SwapSide};
+use crate::states::{Action, Asset, LiquidityPool, RewardPool, Settings, UserPermissions};
+use anchor_lang::prelude::*;
+use anchor_spl::token::{Mint, TokenAccount};
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct QuoteDepositArgs {
+    pub liquidity_pool_index: u8,
+    pub amount: u64,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct QuoteDepositResult {
+    pub lp_tokens: u64,
+}
+
+/// Prices a `deposit` of `amount` without moving funds, with the same limit checks. The result is
+/// set as return data.
+pub fn quote_deposit<'a>(
+    ctx: Context<'_, '_, 'a, 'a, QuoteDeposit<'a>>,
+    args: QuoteDepositArgs,
+) -> Result<QuoteDepositResult> {
+    let QuoteDepositArgs {
+        liquidity_pool_index: _,
+        amount,
+    } = args;
+
+    let settings = &ctx.accounts.settings;
+
+    action_check_protocol(
+        Action::Deposit,
+        ctx.accounts.permissions.as_deref(),
+        &settings.access_control,
+    )?;
+
+    require!(amount > 0, RlpError::InvalidInput);
+
+    let liquidity_pool = &ctx.accounts.liquidity_pool;
+    let reward_pool = RewardPool::load(&ctx.accounts.reward_pool, &liquidity_pool.key())?;
+
+    let lp_tokens = price_deposit(
+        PoolContext {
+            liquidity_pool,
+            settings,
+            reward_pool: reward_pool.as_ref(),
+        },
+        &ctx.accounts.lp_token,
+        SwapSide {
+            mint: &ctx.accounts.asset_mint,
+            asset: &ctx.accounts.asset,
+            oracle: ctx.accounts.oracle.as_ref(),
+            reserve: &ctx.accounts.pool_asset_account,
+        },
+        ctx.remaining_accounts,
+        amount,
+    )?;
+
+    Ok(QuoteDepositResult { lp_tokens })
+}
+
+#[derive(Accounts)]
+#[instruction(args: QuoteDepositArgs)]
+pub struct QuoteDeposit<'info> {
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [
+            SETTINGS_SEED.as_bytes(),
+        ],
+        bump,
+        constraint = !settings.access_control.killswitch.is_frozen(&Action::Deposit) @ RlpError::Frozen,
+    )]
+    pub settings: Box<Account<'info, Settings>>,
+
+    #[account(
+        seeds = [
+            PERMISSIONS_SEED.as_bytes(),
+            signer.key().as_ref()
+        ],
+        bump = permissions.bump
+    )]
+    pub permissions: Option<Account<'info, UserPermissions>>,
+
+    #[account(
+        seeds = [
+            LIQUIDITY_POOL_SEED.as_bytes(),
+            &args.liquidity_pool_index.to_le_bytes()
+        ],
+        bump,
+        constraint = liquidity_pool.index == args.liquidity_pool_index,
+    )]
+    pub liquidity_pool: Account<'info, LiquidityPool>,
+
+    /// CHECK: Checked against the reward pool PDA, may not be initialized yet.
+    #[account()]
+    pub reward_pool: UncheckedAccount<'info>,
+
+    #[account(
+        address = liquidity_pool.lp_token
+    )]
+    pub lp_token: Box<Account<'info, Mint>>,
+
+    #[account(
+        seeds = [
+            ASSET_SEED.as_bytes(),
+            &asset_mint.key().to_bytes()
+        ],
+        bump,
+    )]
+    pub asset: Account<'info, Asset>,
+
+    #[account(
+        address = asset.mint
+    )]
+    pub asset_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        associated_token::mint = asset_mint,
+        associated_token::authority = liquidity_pool,
+    )]
+    pub pool_asset_account: Box<Account<'info, TokenAccount>>,
+
+    /// CHECK: Directly checking the address. Not needed for fixed price assets.
+    #[account(
+        constraint = asset.oracle.key() == Some(&oracle.key()) @ RlpError::InvalidOracle
+    )]
+    pub oracle: Option<AccountInfo<'info>>,
+}
\ No newline at end of file
@@ -0,0 +1,128 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::Mint;
+use crate::errors::RlpError;
+use crate::helpers::action_check_protocol;
+use crate::states::*;
+use crate::constants::*;
+use crate::helpers::{
+    load_assets,
+    load_reserves
+};
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Copy)]
+pub struct QuoteWithdrawArgs {
+    pub liquidity_pool_index: u8,
+    pub lp_token_amount: u64,
+}
+
+#[derive(AnchorDeserialize, AnchorSerialize)]
+pub struct QuoteWithdrawResult {
+    /// Amount paid out per pool asset, in `LiquidityPool::assets` order.
+    pub amounts: Vec<u64>,
+}
+
+/// Prices a `withdraw` of `lp_token_amount` without moving funds. The result is set as
+/// return data. Remaining accounts are the pool's assets followed by its reserves.
+pub fn quote_withdraw<'a>(
+    ctx: Context<'_, '_, 'a, 'a, QuoteWithdraw<'a>>,
+    args: QuoteWithdrawArgs
+) -> Result<QuoteWithdrawResult> {
+
+    let QuoteWithdrawArgs {
+        liquidity_pool_index: _,
+        lp_token_amount,
+    } = args;
+
+    let settings = &ctx.accounts.settings;
+
+    action_check_protocol(
+        Action::Withdraw,
+        ctx.accounts.permissions.as_deref(),
+        &settings.access_control,
+    )?;
+
+    let liquidity_pool = &ctx.accounts.liquidity_pool;
+    let lp_token_supply = ctx.accounts.lp_token_mint.supply;
+
+    require!(
+        lp_token_amount > 0 && lp_token_amount <= lp_token_supply,
+        RlpError::InvalidInput
+    );
+
+    let remaining_accounts = &ctx.remaining_accounts;
+    require!(
+        remaining_accounts.len() == liquidity_pool.asset_count as usize * 2,
+        RlpError::InvalidInput
+    );
+
+    let clock = Clock::get()?;
+
+    let assets: Vec<(Pubkey, Asset)> = load_assets(liquidity_pool, remaining_accounts)?;
+    let asset_datas = assets.iter().map(|(_, asset)| asset).collect::<Vec<&Asset>>();
+    let reserves = load_reserves(liquidity_pool, &asset_datas, remaining_accounts)?;
+
+    let reward_pool = RewardPool::load(&ctx.accounts.reward_pool, &liquidity_pool.key())?;
+
+    let mut amounts = Vec::with_capacity(assets.len());
+
+    for (asset, (_, reserve)) in asset_datas.iter().zip(reserves.iter()) {
+        let reserve_amount = liquidity_pool.withdrawable_reserve(
+            asset.index,
+            reserve.amount,
+            reward_pool.as_ref(),
+            clock.unix_timestamp as u64,
+        )?;
+
+        amounts.push(liquidity_pool.calculate_withdraw_amount(
+            reserve_amount,
+            lp_token_amount,
+            lp_token_supply,
+        )?);
+    }
+
+    Ok(QuoteWithdrawResult { amounts })
+}
+
+#[derive(Accounts)]
+#[instruction(
+    args: QuoteWithdrawArgs
+)]
+pub struct QuoteWithdraw<'info> {
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [
+            SETTINGS_SEED.as_bytes()
+        ],
+        bump = settings.bump,
+        constraint = !settings.access_control.killswitch.is_frozen(&Action::Withdraw) @ RlpError::Frozen
+    )]
+    pub settings: Box<Account<'info, Settings>>,
+
+    #[account(
+        seeds = [
+            PERMISSIONS_SEED.as_bytes(),
+            signer.key().as_ref()
+        ],
+        bump = permissions.bump,
+    )]
+    pub permissions: Option<Account<'info, UserPermissions>>,
+
+    #[account(
+        seeds = [
+            LIQUIDITY_POOL_SEED.as_bytes(),
+            &args.liquidity_pool_index.to_le_bytes()
+        ],
+        bump = liquidity_pool.bump,
+    )]
+    pub liquidity_pool: Account<'info, LiquidityPool>,
+
+    /// CHECK: Checked against the reward pool PDA, may not be initialized yet.
+    #[account()]
+    pub reward_pool: UncheckedAccount<'info>,
+
+    #[account(
+        address = liquidity_pool.lp_token
+    )]
+    pub lp_token_mint: Box<Account<'info, Mint>>,
+}
\ No newline at end of file
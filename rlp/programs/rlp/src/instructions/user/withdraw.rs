@@ -2,7 +2,6 @@ use anchor_lang::prelude::*;
 use anchor_spl::token::close_account;
 use anchor_spl::token::CloseAccount;
 use anchor_spl::token::Token;
-use spl_math::precise_number::PreciseNumber;
 use crate::errors::RlpError;
 use crate::helpers::action_check_protocol;
 use crate::states::*;
@@ -32,6 +31,16 @@ pub fn withdraw<'a>(
     ctx: Context<'_, '_, 'a, 'a, Withdraw<'a>>,
     args: WithdrawArgs
 ) -> Result<()> {
+    process_withdraw(ctx, args, None)
+}
+
+/// Redeems `amount` LP tokens of a matured cooldown, or all of them. The cooldown and its
+/// vault are closed once empty.
+pub fn process_withdraw<'a>(
+    ctx: Context<'_, '_, 'a, 'a, Withdraw<'a>>,
+    args: WithdrawArgs,
+    amount: Option<u64>
+) -> Result<()> {
 
     let WithdrawArgs {
         liquidity_pool_id: _,
@@ -54,7 +63,8 @@ pub fn withdraw<'a>(
     let token_program = &ctx.accounts.token_program;
     let signer = &ctx.accounts.signer;
 
-    let lp_token_amount = cooldown_lp_token_account.amount;
+    let cooldown_balance = cooldown_lp_token_account.amount;
+    let lp_token_amount = amount.unwrap_or(cooldown_balance);
     let lp_token_supply = lp_token_mint.supply;
 
     let clock = Clock::get()?;
@@ -69,6 +79,11 @@ pub fn withdraw<'a>(
         RlpError::InvalidInput
     );
 
+    require!(
+        lp_token_amount <= cooldown_balance,
+        RlpError::NotEnoughFunds
+    );
+
     let remaining_accounts = &ctx.remaining_accounts;
     require!(
         remaining_accounts.len() == liquidity_pool.asset_count as usize * 3,
@@ -86,28 +101,24 @@ pub fn withdraw<'a>(
     let reserves = load_reserves(liquidity_pool, &asset_datas, remaining_accounts)?;
     let user_token_accounts = load_user_token_accounts(signer, &asset_datas, remaining_accounts)?;
 
+    let reward_pool = RewardPool::load(&ctx.accounts.reward_pool, &liquidity_pool.key())?;
+
     for i in 0..assets.len() {
         let (reserve_key, reserve) = &reserves[i];
         let (user_token_account_key, _) = &user_token_accounts[i];
 
-        let user_pool_share_amount = PreciseNumber::new(reserve.amount as u128)
-            .ok_or(RlpError::MathOverflow)?
-            .checked_mul(
-                &PreciseNumber::new(lp_token_amount as u128)
-                .ok_or(RlpError::MathOverflow)?
-            )
-            .ok_or(RlpError::MathOverflow)?
-            .checked_div(
-                &PreciseNumber::new(lp_token_supply as u128)
-                .ok_or(RlpError::MathOverflow)?
-            )
-            .ok_or(RlpError::MathOverflow)?
-            .to_imprecise()
-            .ok_or(RlpError::MathOverflow)?;
+        let reserve_amount = liquidity_pool.withdrawable_reserve(
+            asset_datas[i].index,
+            reserve.amount,
+            reward_pool.as_ref(),
+            clock.unix_timestamp as u64,
+        )?;
 
-        let user_pool_share_amount: u64 = user_pool_share_amount
-            .try_into()
-            .map_err(|_| error!(RlpError::MathOverflow))?;
+        let user_pool_share_amount = liquidity_pool.calculate_withdraw_amount(
+            reserve_amount,
+            lp_token_amount,
+            lp_token_supply,
+        )?;
 
         if user_pool_share_amount > 0 {
             let reserve_account = remaining_accounts
@@ -155,17 +166,21 @@ pub fn withdraw<'a>(
         lp_token_amount
     )?;
 
-    close_account(
-        CpiContext::new_with_signer(
-            token_program.to_account_info(),
-            CloseAccount {
-                account: cooldown_lp_token_account.to_account_info(),
-                destination: signer.to_account_info(),
-                authority: cooldown.to_account_info()
-            },
-            &[cooldown_seeds]
-        )
-    )?;
+    if lp_token_amount == cooldown_balance {
+        close_account(
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                CloseAccount {
+                    account: cooldown_lp_token_account.to_account_info(),
+                    destination: signer.to_account_info(),
+                    authority: cooldown.to_account_info()
+                },
+                &[cooldown_seeds]
+            )
+        )?;
+
+        cooldown.close(signer.to_account_info())?;
+    }
 
     emit!(WithdrawEvent {
         amount: lp_token_amount,
@@ -212,6 +227,10 @@ pub struct Withdraw<'info> {
     )]
     pub liquidity_pool: Account<'info, LiquidityPool>,
 
+    /// CHECK: Checked against the reward pool PDA, may not be initialized yet.
+    #[account()]
+    pub reward_pool: UncheckedAccount<'info>,
+
     #[account(
         mut,
         address = liquidity_pool.lp_token
@@ -233,7 +252,6 @@ pub struct Withdraw<'info> {
             &args.cooldown_id.to_le_bytes()
         ],
         bump = cooldown.bump,
-        close = signer,
         constraint = cooldown.liquidity_pool_id == args.liquidity_pool_id,
         constraint = cooldown.authority == signer.key()
     )]
@@ -0,0 +1,25 @@
+use anchor_lang::prelude::*;
+use crate::errors::RlpError;
+use crate::instructions::{process_withdraw, Withdraw, WithdrawArgs};
+
+/// `withdraw` comes first, the shared `Withdraw` accounts are derived from it.
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Copy)]
+pub struct WithdrawPartialArgs {
+    pub withdraw: WithdrawArgs,
+    pub amount: u64,
+}
+
+/// Redeems `amount` LP tokens of a matured cooldown, leaving the rest in place.
+pub fn withdraw_partial<'a>(
+    ctx: Context<'_, '_, 'a, 'a, Withdraw<'a>>,
+    args: WithdrawPartialArgs
+) -> Result<()> {
+    let WithdrawPartialArgs {
+        withdraw,
+        amount,
+    } = args;
+
+    require!(amount > 0, RlpError::InvalidInput);
+
+    process_withdraw(ctx, withdraw, Some(amount))
+}
\ No newline at end of file
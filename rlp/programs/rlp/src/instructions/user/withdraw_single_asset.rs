@@ -0,0 +1,348 @@
+use anchor_lang::prelude::*;
+use anchor_spl::token::close_account;
+use anchor_spl::token::CloseAccount;
+use anchor_spl::token::Token;
+use spl_math::precise_number::PreciseNumber;
+use crate::errors::RlpError;
+use crate::helpers::{action_check_protocol, SwapPricing, SwapQuote};
+use crate::states::*;
+use crate::constants::*;
+use anchor_spl::token::{
+    Mint,
+    TokenAccount,
+    transfer,
+    Transfer,
+    burn,
+    Burn
+};
+use crate::events::WithdrawSingleAssetEvent;
+
+#[derive(AnchorDeserialize, AnchorSerialize, Clone, Copy)]
+pub struct WithdrawSingleAssetArgs {
+    pub liquidity_pool_id: u8,
+    pub cooldown_id: u64,
+    pub min_out: Option<u64>,
+}
+
+/// Redeems a cooldown for a single asset. The LP tokens are valued against the whole pool and
+/// paid out with the same impact and fee as a swap into that asset.
+/// Remaining accounts follow the `calculate_total_pool_value` layout.
+pub fn withdraw_single_asset<'a>(
+    ctx: Context<'_, '_, 'a, 'a, WithdrawSingleAsset<'a>>,
+    args: WithdrawSingleAssetArgs
+) -> Result<()> {
+
+    let WithdrawSingleAssetArgs {
+        liquidity_pool_id: _,
+        cooldown_id,
+        min_out,
+    } = args;
+
+    let settings = &ctx.accounts.settings;
+    let permissions = &ctx.accounts.permissions;
+
+    action_check_protocol(
+        Action::Withdraw,
+        permissions.as_deref(),
+        &settings.access_control,
+    )?;
+
+    let cooldown = &ctx.accounts.cooldown;
+    let liquidity_pool = &ctx.accounts.liquidity_pool;
+    let cooldown_lp_token_account = &ctx.accounts.cooldown_lp_token_account;
+    let lp_token_mint = &ctx.accounts.lp_token_mint;
+    let token_program = &ctx.accounts.token_program;
+    let signer = &ctx.accounts.signer;
+    let asset = &ctx.accounts.asset;
+    let asset_mint = &ctx.accounts.asset_mint;
+    let pool_asset_account = &ctx.accounts.pool_asset_account;
+
+    let lp_token_amount = cooldown_lp_token_account.amount;
+    let lp_token_supply = lp_token_mint.supply;
+
+    let clock = Clock::get()?;
+
+    require!(
+        clock.unix_timestamp as u64 >= cooldown.unlock_ts,
+        RlpError::CooldownInForce
+    );
+
+    require!(
+        lp_token_amount > 0 && lp_token_supply > 0,
+        RlpError::InvalidInput
+    );
+
+    require!(
+        liquidity_pool.has_asset(asset.index),
+        RlpError::AssetNotWhitelisted
+    );
+
+    let reward_pool = RewardPool::load(&ctx.accounts.reward_pool, &liquidity_pool.key())?;
+
+    let total_pool_value = liquidity_pool.calculate_total_pool_value(
+        ctx.remaining_accounts,
+        liquidity_pool,
+        settings,
+        reward_pool.as_ref(),
+        &clock,
+    )?;
+
+    let lp_value = total_pool_value
+        .checked_mul(
+            &PreciseNumber::new(lp_token_amount as u128)
+                .ok_or(RlpError::MathOverflow)?
+        )
+        .ok_or(RlpError::MathOverflow)?
+        .checked_div(
+            &PreciseNumber::new(lp_token_supply as u128)
+                .ok_or(RlpError::MathOverflow)?
+        )
+        .ok_or(RlpError::MathOverflow)?
+        .to_imprecise()
+        .ok_or(RlpError::MathOverflow)?;
+
+    let mut pricing_accounts = ctx.remaining_accounts.to_vec();
+    pricing_accounts.extend(ctx.accounts.oracle.clone());
+
+    let asset_price = asset.get_price(&pricing_accounts, &clock)?;
+
+    let oracle_amount_out: u64 = lp_value
+        .checked_div(asset_price.mul(1, asset_mint.decimals)?)
+        .ok_or(RlpError::MathOverflow)?
+        .try_into()
+        .map_err(|_| RlpError::MathOverflow)?;
+
+    let reserve_amount = liquidity_pool.withdrawable_reserve(
+        asset.index,
+        pool_asset_account.amount,
+        reward_pool.as_ref(),
+        clock.unix_timestamp as u64,
+    )?;
+
+    // Priced as a swap of the LP share into the chosen asset. The share has no pair of its own,
+    // so the pool fee applies, weighted by how far the payout moves the asset off target.
+    let mut fee_bps = liquidity_pool.resolve_swap_fee(asset.index, asset.index, settings);
+
+    if liquidity_pool.has_target_weights() {
+        fee_bps = liquidity_pool.weighted_withdraw_fee(
+            fee_bps,
+            asset.index,
+            SwapComposition {
+                total_value: total_pool_value
+                    .to_imprecise()
+                    .ok_or(RlpError::MathOverflow)?,
+                value_in: 0,
+                value_out: asset_price.mul(reserve_amount, asset_mint.decimals)?,
+                trade_value: lp_value,
+            },
+        )?;
+    }
+
+    let pricing = SwapPricing {
+        from_price: asset_price,
+        from_decimals: asset_mint.decimals,
+        to_price: asset_price,
+        to_decimals: asset_mint.decimals,
+        reserve_to: reserve_amount,
+        fee_bps,
+        protocol_fee_bps: settings.protocol_fee_bps,
+    };
+
+    let SwapQuote {
+        amount_out,
+        lp_fee,
+        protocol_fee,
+        ..
+    } = pricing.quote_oracle_out(lp_token_amount, oracle_amount_out)?;
+
+    if let Some(min_amount) = min_out {
+        require!(
+            amount_out >= min_amount,
+            RlpError::SlippageExceeded
+        );
+    }
+
+    require!(
+        reserve_amount as u128
+            >= (amount_out as u128)
+                .checked_add(protocol_fee as u128)
+                .ok_or(RlpError::MathOverflow)?,
+        RlpError::NotEnoughFunds
+    );
+
+    let lp_seeds = &[
+        LIQUIDITY_POOL_SEED.as_bytes(),
+        &liquidity_pool.index.to_le_bytes(),
+        &[liquidity_pool.bump]
+    ];
+
+    if amount_out > 0 {
+        transfer(
+            CpiContext::new_with_signer(
+                token_program.to_account_info(),
+                Transfer {
+                    from: pool_asset_account.to_account_info(),
+                    to: ctx.accounts.user_asset_account.to_account_info(),
+                    authority: liquidity_pool.to_account_info()
+                },
+                &[lp_seeds]
+            ),
+            amount_out
+        )?;
+    }
+
+    let cooldown_seeds = &[
+        COOLDOWN_SEED.as_bytes(),
+        &liquidity_pool.index.to_le_bytes(),
+        &cooldown_id.to_le_bytes(),
+        &[cooldown.bump]
+    ];
+
+    burn(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            Burn {
+                authority: cooldown.to_account_info(),
+                from: cooldown_lp_token_account.to_account_info(),
+                mint: lp_token_mint.to_account_info()
+            },
+            &[cooldown_seeds]
+        ),
+        lp_token_amount
+    )?;
+
+    close_account(
+        CpiContext::new_with_signer(
+            token_program.to_account_info(),
+            CloseAccount {
+                account: cooldown_lp_token_account.to_account_info(),
+                destination: signer.to_account_info(),
+                authority: cooldown.to_account_info()
+            },
+            &[cooldown_seeds]
+        )
+    )?;
+
+    let asset_index = asset.index;
+    let asset_key = asset_mint.key();
+
+    ctx.accounts
+        .liquidity_pool
+        .accrue_protocol_fee(asset_index, protocol_fee)?;
+
+    emit!(WithdrawSingleAssetEvent {
+        from: signer.key(),
+        asset: asset_key,
+        lp_amount: lp_token_amount,
+        amount_out,
+        lp_fee,
+        protocol_fee,
+    });
+
+    Ok(())
+}
+
+#[derive(Accounts)]
+#[instruction(
+    args: WithdrawSingleAssetArgs
+)]
+pub struct WithdrawSingleAsset<'info> {
+    #[account(mut)]
+    pub signer: Signer<'info>,
+
+    #[account(
+        seeds = [
+            SETTINGS_SEED.as_bytes()
+        ],
+        bump = settings.bump,
+        constraint = !settings.access_control.killswitch.is_frozen(&Action::Withdraw) @ RlpError::Frozen
+    )]
+    pub settings: Box<Account<'info, Settings>>,
+
+    #[account(
+        seeds = [
+            PERMISSIONS_SEED.as_bytes(),
+            signer.key().as_ref()
+        ],
+        bump = permissions.bump,
+    )]
+    pub permissions: Option<Account<'info, UserPermissions>>,
+
+    #[account(
+        mut,
+        seeds = [
+            LIQUIDITY_POOL_SEED.as_bytes(),
+            &args.liquidity_pool_id.to_le_bytes()
+        ],
+        bump = liquidity_pool.bump,
+    )]
+    pub liquidity_pool: Account<'info, LiquidityPool>,
+
+    /// CHECK: Checked against the reward pool PDA, may not be initialized yet.
+    #[account()]
+    pub reward_pool: UncheckedAccount<'info>,
+
+    #[account(
+        mut,
+        address = liquidity_pool.lp_token
+    )]
+    pub lp_token_mint: Box<Account<'info, Mint>>,
+
+    #[account(
+        mut,
+        associated_token::mint = lp_token_mint,
+        associated_token::authority = cooldown,
+    )]
+    pub cooldown_lp_token_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        seeds = [
+            COOLDOWN_SEED.as_bytes(),
+            &liquidity_pool.index.to_le_bytes(),
+            &args.cooldown_id.to_le_bytes()
+        ],
+        bump = cooldown.bump,
+        close = signer,
+        constraint = cooldown.liquidity_pool_id == args.liquidity_pool_id,
+        constraint = cooldown.authority == signer.key()
+    )]
+    pub cooldown: Account<'info, Cooldown>,
+
+    #[account(
+        seeds = [
+            ASSET_SEED.as_bytes(),
+            &asset_mint.key().to_bytes()
+        ],
+        bump,
+    )]
+    pub asset: Account<'info, Asset>,
+
+    #[account(
+        address = asset.mint
+    )]
+    pub asset_mint: Box<Account<'info, Mint>>,
+
+    /// CHECK: Directly checking the address. Not needed for fixed price assets.
+    #[account(
+        constraint = asset.oracle.key() == Some(&oracle.key()) @ RlpError::InvalidOracle
+    )]
+    pub oracle: Option<AccountInfo<'info>>,
+
+    #[account(
+        mut,
+        associated_token::mint = asset_mint,
+        associated_token::authority = liquidity_pool,
+    )]
+    pub pool_asset_account: Box<Account<'info, TokenAccount>>,
+
+    #[account(
+        mut,
+        token::mint = asset_mint,
+        token::authority = signer,
+    )]
+    pub user_asset_account: Box<Account<'info, TokenAccount>>,
+
+    #[account()]
+    pub token_program: Program<'info, Token>,
+}
\ No newline at end of file
@@ -29,13 +29,76 @@ pub mod rlp {
         instructions::initialize_lp(ctx, args)
     }
 
-    pub fn add_asset(
-        ctx: Context<AddAsset>,
+    pub fn add_pool_asset(
+        ctx: Context<AddPoolAsset>,
+        args: AddPoolAssetArgs
+    ) -> Result<()> {
+        instructions::add_pool_asset(ctx, args)
+    }
+
+    pub fn remove_pool_asset(
+        ctx: Context<RemovePoolAsset>,
+        args: RemovePoolAssetArgs
+    ) -> Result<()> {
+        instructions::remove_pool_asset(ctx, args)
+    }
+
+    pub fn migrate_liquidity_pool(
+        ctx: Context<MigrateLiquidityPool>,
+        args: MigrateLiquidityPoolArgs
+    ) -> Result<()> {
+        instructions::migrate_liquidity_pool(ctx, args)
+    }
+
+    pub fn migrate_settings(
+        ctx: Context<MigrateSettings>,
+        args: MigrateSettingsArgs
+    ) -> Result<()> {
+        instructions::migrate_settings(ctx, args)
+    }
+
+    pub fn update_asset_limits(
+        ctx: Context<UpdateAssetLimits>,
+        args: UpdateAssetLimitsArgs
+    ) -> Result<()> {
+        instructions::update_asset_limits(ctx, args)
+    }
+
+    pub fn add_asset<'a>(
+        ctx: Context<'_, '_, 'a, 'a, AddAsset<'a>>,
         args: AddAssetArgs
     ) -> Result<()> {
         instructions::add_asset(ctx, args)
     }
 
+    pub fn add_asset_oracle<'a>(
+        ctx: Context<'_, '_, 'a, 'a, AddAssetOracle<'a>>,
+        args: AddAssetOracleArgs
+    ) -> Result<()> {
+        instructions::add_asset_oracle(ctx, args)
+    }
+
+    pub fn remove_asset_oracle(
+        ctx: Context<RemoveAssetOracle>,
+        args: RemoveAssetOracleArgs
+    ) -> Result<()> {
+        instructions::remove_asset_oracle(ctx, args)
+    }
+
+    pub fn update_asset<'a>(
+        ctx: Context<'_, '_, 'a, 'a, UpdateAsset<'a>>,
+        args: UpdateAssetArgs
+    ) -> Result<()> {
+        instructions::update_asset(ctx, args)
+    }
+
+    pub fn migrate_asset(
+        ctx: Context<MigrateAsset>,
+        args: MigrateAssetArgs
+    ) -> Result<()> {
+        instructions::migrate_asset(ctx, args)
+    }
+
     pub fn freeze_functionality(
         ctx: Context<RlpAdminMain>,
         args: FreezeProtocolActionArgs
@@ -50,6 +113,62 @@ pub mod rlp {
         instructions::update_deposit_cap(ctx, args)
     }
 
+    pub fn update_deposit_value_cap(
+        ctx: Context<UpdateDepositValueCap>,
+        args: UpdateDepositValueCapArgs
+    ) -> Result<()> {
+        instructions::update_deposit_value_cap(ctx, args)
+    }
+
+    pub fn update_cooldown_duration(
+        ctx: Context<UpdateCooldownDuration>,
+        args: UpdateCooldownDurationArgs
+    ) -> Result<()> {
+        instructions::update_cooldown_duration(ctx, args)
+    }
+
+    pub fn update_oracle_max_age(
+        ctx: Context<UpdateOracleMaxAge>,
+        args: UpdateOracleMaxAgeArgs
+    ) -> Result<()> {
+        instructions::update_oracle_max_age(ctx, args)
+    }
+
+    pub fn update_swap_fee(
+        ctx: Context<UpdateSwapFee>,
+        args: UpdateSwapFeeArgs
+    ) -> Result<()> {
+        instructions::update_swap_fee(ctx, args)
+    }
+
+    pub fn update_pool_swap_fee(
+        ctx: Context<UpdatePoolSwapFee>,
+        args: UpdatePoolSwapFeeArgs
+    ) -> Result<()> {
+        instructions::update_pool_swap_fee(ctx, args)
+    }
+
+    pub fn update_target_weights(
+        ctx: Context<UpdateTargetWeights>,
+        args: UpdateTargetWeightsArgs
+    ) -> Result<()> {
+        instructions::update_target_weights(ctx, args)
+    }
+
+    pub fn update_protocol_fee(
+        ctx: Context<UpdateProtocolFee>,
+        args: UpdateProtocolFeeArgs
+    ) -> Result<()> {
+        instructions::update_protocol_fee(ctx, args)
+    }
+
+    pub fn claim_protocol_fees(
+        ctx: Context<ClaimProtocolFees>,
+        args: ClaimProtocolFeesArgs
+    ) -> Result<()> {
+        instructions::claim_protocol_fees(ctx, args)
+    }
+
     pub fn slash(
         ctx: Context<Slash>,
         args: SlashArgs
@@ -64,6 +183,34 @@ pub mod rlp {
         instructions::deposit(ctx, args)
     }
 
+    pub fn deposit_proportional<'a>(
+        ctx: Context<'_, '_, 'a, 'a, DepositProportional<'a>>,
+        args: DepositProportionalArgs
+    ) -> Result<()> {
+        instructions::deposit_proportional(ctx, args)
+    }
+
+    pub fn initialize_reward_pool(
+        ctx: Context<InitializeRewardPool>,
+        args: InitializeRewardPoolArgs
+    ) -> Result<()> {
+        instructions::initialize_reward_pool(ctx, args)
+    }
+
+    pub fn deposit_rewards(
+        ctx: Context<DepositRewards>,
+        args: DepositRewardsArgs
+    ) -> Result<()> {
+        instructions::deposit_rewards(ctx, args)
+    }
+
+    pub fn update_vesting_duration(
+        ctx: Context<UpdateVestingDuration>,
+        args: UpdateVestingDurationArgs
+    ) -> Result<()> {
+        instructions::update_vesting_duration(ctx, args)
+    }
+
     pub fn request_withdrawal(
         ctx: Context<RequestWithdrawal>,
         args: RequestWithdrawalArgs
@@ -71,6 +218,13 @@ pub mod rlp {
         instructions::request_withdrawal(ctx, args)
     }
 
+    pub fn cancel_withdrawal_request(
+        ctx: Context<CancelWithdrawalRequest>,
+        args: CancelWithdrawalRequestArgs
+    ) -> Result<()> {
+        instructions::cancel_withdrawal_request(ctx, args)
+    }
+
     pub fn withdraw<'a>(
         ctx: Context<'_, '_, 'a, 'a, Withdraw<'a>>,
         args: WithdrawArgs
@@ -78,13 +232,69 @@ pub mod rlp {
         instructions::withdraw(ctx, args)
     }
 
-    pub fn swap(
-        ctx: Context<Swap>,
+    pub fn withdraw_partial<'a>(
+        ctx: Context<'_, '_, 'a, 'a, Withdraw<'a>>,
+        args: WithdrawPartialArgs
+    ) -> Result<()> {
+        instructions::withdraw_partial(ctx, args)
+    }
+
+    pub fn merge_cooldowns<'a>(
+        ctx: Context<'_, '_, 'a, 'a, MergeCooldowns<'a>>,
+        args: MergeCooldownsArgs
+    ) -> Result<()> {
+        instructions::merge_cooldowns(ctx, args)
+    }
+
+    pub fn withdraw_single_asset<'a>(
+        ctx: Context<'_, '_, 'a, 'a, WithdrawSingleAsset<'a>>,
+        args: WithdrawSingleAssetArgs
+    ) -> Result<()> {
+        instructions::withdraw_single_asset(ctx, args)
+    }
+
+    pub fn swap<'a>(
+        ctx: Context<'_, '_, 'a, 'a, Swap<'a>>,
         args: SwapArgs
     ) -> Result<()> {
         instructions::swap(ctx, args)
     }
 
+    pub fn swap_exact_out<'a>(
+        ctx: Context<'_, '_, 'a, 'a, Swap<'a>>,
+        args: SwapExactOutArgs
+    ) -> Result<()> {
+        instructions::swap_exact_out(ctx, args)
+    }
+
+    pub fn swap_route<'a>(
+        ctx: Context<'_, '_, 'a, 'a, SwapRoute<'a>>,
+        args: SwapRouteArgs
+    ) -> Result<()> {
+        instructions::swap_route(ctx, args)
+    }
+
+    pub fn quote_swap<'a>(
+        ctx: Context<'_, '_, 'a, 'a, QuoteSwap<'a>>,
+        args: QuoteSwapArgs
+    ) -> Result<QuoteSwapResult> {
+        instructions::quote_swap(ctx, args)
+    }
+
+    pub fn quote_deposit<'a>(
+        ctx: Context<'_, '_, 'a, 'a, QuoteDeposit<'a>>,
+        args: QuoteDepositArgs
+    ) -> Result<QuoteDepositResult> {
+        instructions::quote_deposit(ctx, args)
+    }
+
+    pub fn quote_withdraw<'a>(
+        ctx: Context<'_, '_, 'a, 'a, QuoteWithdraw<'a>>,
+        args: QuoteWithdrawArgs
+    ) -> Result<QuoteWithdrawResult> {
+        instructions::quote_withdraw(ctx, args)
+    }
+
     pub fn create_permission_account(
         ctx: Context<RlpUserPermissionsInit>,
         new_admin: Pubkey
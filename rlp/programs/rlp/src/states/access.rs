@@ -279,9 +279,12 @@ impl AccessControl {
         access_control.add_role_to_action(Action::FreezeWithdraw, Role::MANAGER)?;
         access_control.add_role_to_action(Action::SuspendDeposits, Role::MANAGER)?;
         access_control.add_role_to_action(Action::UpdateAction, Role::MANAGER)?;
+        access_control.add_role_to_action(Action::DepositRewards, Role::MANAGER)?;
+        access_control.add_role_to_action(Action::ClaimProtocolFees, Role::MANAGER)?;
 
         access_control.add_role_to_action(Action::Slash, Role::CRANK)?;
         access_control.add_role_to_action(Action::Swap, Role::CRANK)?;
+        access_control.add_role_to_action(Action::DepositRewards, Role::CRANK)?;
 
         access_control.add_role_to_action(Action::Deposit, Role::TESTEE)?;
         access_control.add_role_to_action(Action::Withdraw, Role::TESTEE)?;
@@ -1,12 +1,46 @@
 use anchor_lang::prelude::*;
+use crate::errors::RlpError;
 use crate::helpers::{
     get_price_from_pyth,
-    OraclePrice
+    get_price_from_switchboard,
+    get_rate_from_single_pool,
+    get_rate_from_spl_stake_pool,
+    OraclePrice,
+    StakePoolRate
 };
 
+pub const MAX_ADDITIONAL_ORACLES: usize = 2;
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Debug, InitSpace)]
+pub enum RateSource {
+    SplStakePool(Pubkey),
+    SinglePool {
+        pool_mint: Pubkey,
+        pool_stake: Pubkey,
+    },
+}
+
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Debug, InitSpace)]
+pub struct DepegGuard {
+    pub oracle: Pubkey,
+    pub max_deviation_bps: u16,
+}
+
 #[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Debug, InitSpace)]
 pub enum Oracle {
     Pyth(Pubkey),
+    Switchboard(Pubkey),
+    /// Pyth SOL-denominated base feed multiplied by an on-chain stake pool exchange rate.
+    ExchangeRate {
+        base: Pubkey,
+        rate_source: RateSource,
+    },
+    /// Constant price, optionally rejected when a Pyth feed drifts too far away from it.
+    Fixed {
+        price: i64,
+        exponent: i32,
+        depeg_guard: Option<DepegGuard>,
+    },
 }
 
 #[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, PartialEq, Debug)]
@@ -15,10 +49,100 @@ pub enum AccessLevel {
     Private
 }
 
+fn find_account<'a, 'info>(
+    accounts: &'a [AccountInfo<'info>],
+    key: &Pubkey
+) -> Result<&'a AccountInfo<'info>> {
+    accounts
+        .iter()
+        .find(|account| account.key().eq(key))
+        .ok_or(RlpError::InvalidOracle.into())
+}
+
+impl RateSource {
+    pub fn keys(&self) -> Vec<Pubkey> {
+        match self {
+            RateSource::SplStakePool(stake_pool) => vec![*stake_pool],
+            RateSource::SinglePool { pool_mint, pool_stake } => vec![*pool_mint, *pool_stake],
+        }
+    }
+
+    #[inline(never)]
+    pub fn get_rate(
+        &self,
+        accounts: &[AccountInfo],
+        clock: &Clock
+    ) -> Result<StakePoolRate> {
+        match self {
+            RateSource::SplStakePool(stake_pool) => {
+                get_rate_from_spl_stake_pool(find_account(accounts, stake_pool)?, clock)
+            },
+            RateSource::SinglePool { pool_mint, pool_stake } => {
+                get_rate_from_single_pool(
+                    find_account(accounts, pool_mint)?,
+                    find_account(accounts, pool_stake)?
+                )
+            },
+        }
+    }
+}
+
 impl Oracle {
-    pub fn key(&self) -> &Pubkey {
+    /// Address of the price feed account, if the oracle reads one.
+    pub fn key(&self) -> Option<&Pubkey> {
         match self {
-            Oracle::Pyth(key) => key,
+            Oracle::Pyth(key) => Some(key),
+            Oracle::Switchboard(key) => Some(key),
+            Oracle::ExchangeRate { base, .. } => Some(base),
+            Oracle::Fixed { depeg_guard, .. } => depeg_guard.as_ref().map(|guard| &guard.oracle),
+        }
+    }
+
+    /// Addresses of all accounts needed for pricing, price feed first.
+    pub fn keys(&self) -> Vec<Pubkey> {
+        let mut keys: Vec<Pubkey> = self.key().into_iter().copied().collect();
+
+        if let Oracle::ExchangeRate { rate_source, .. } = self {
+            keys.extend(rate_source.keys());
+        }
+
+        keys
+    }
+
+    #[inline(never)]
+    pub fn get_price(
+        &self,
+        accounts: &[AccountInfo],
+        clock: &Clock,
+        max_age: u64
+    ) -> Result<OraclePrice> {
+        match *self {
+            Oracle::Pyth(feed) => {
+                get_price_from_pyth(find_account(accounts, &feed)?, clock, max_age)
+            },
+            Oracle::Switchboard(feed) => {
+                get_price_from_switchboard(find_account(accounts, &feed)?, clock, max_age)
+            },
+            Oracle::ExchangeRate { base, rate_source } => {
+                let base_price = get_price_from_pyth(find_account(accounts, &base)?, clock, max_age)?;
+                let rate = rate_source.get_rate(accounts, clock)?;
+
+                Ok(base_price.apply_rate(&rate)?)
+            },
+            Oracle::Fixed { price, exponent, depeg_guard } => {
+                let fixed_price = OraclePrice {
+                    price,
+                    exponent,
+                    confidence: 0,
+                };
+
+                if let Some(DepegGuard { oracle, max_deviation_bps }) = depeg_guard {
+                    let market_price = get_price_from_pyth(find_account(accounts, &oracle)?, clock, max_age)?;
+                    fixed_price.check_deviation(&market_price, max_deviation_bps)?;
+                }
+
+                Ok(fixed_price)
+            },
         }
     }
 }
@@ -31,18 +155,86 @@ pub struct Asset {
     pub mint: Pubkey,
     pub oracle: Oracle,
     pub access_level: AccessLevel,
+    pub max_confidence_bps: u16,
+    pub oracle_max_age: u64,
+    pub additional_oracles: [Option<Oracle>; MAX_ADDITIONAL_ORACLES],
+    /// Maximum spread between the oracle sources, only used with additional oracles.
+    pub max_oracle_deviation_bps: u16,
+}
+
+/// `Oracle` as stored by `LegacyAsset`, before the other oracle variants were added.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Debug, InitSpace)]
+pub enum LegacyOracle {
+    Pyth(Pubkey),
+}
+
+impl From<LegacyOracle> for Oracle {
+    fn from(legacy: LegacyOracle) -> Self {
+        match legacy {
+            LegacyOracle::Pyth(key) => Oracle::Pyth(key),
+        }
+    }
+}
+
+/// `Asset` layout without per-asset oracle settings, only read by `migrate_asset`.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct LegacyAsset {
+    pub bump: u8,
+    pub index: u8,
+    pub mint: Pubkey,
+    pub oracle: LegacyOracle,
+    pub access_level: AccessLevel,
+}
+
+impl LegacyAsset {
+    /// Converts to the current layout, with the same defaults as `add_asset` for the new fields.
+    pub fn into_asset(self, max_confidence_bps: u16, oracle_max_age: u64) -> Asset {
+        Asset {
+            bump: self.bump,
+            index: self.index,
+            mint: self.mint,
+            oracle: self.oracle.into(),
+            access_level: self.access_level,
+            max_confidence_bps,
+            oracle_max_age,
+            additional_oracles: [None; MAX_ADDITIONAL_ORACLES],
+            max_oracle_deviation_bps: 0,
+        }
+    }
 }
 
 impl Asset {
+    /// Primary oracle followed by any additional ones.
+    pub fn oracles(&self) -> impl Iterator<Item = &Oracle> {
+        std::iter::once(&self.oracle).chain(self.additional_oracles.iter().flatten())
+    }
+
+    /// Addresses of all accounts needed to price the asset.
+    pub fn oracle_keys(&self) -> Vec<Pubkey> {
+        self.oracles().flat_map(|oracle| oracle.keys()).collect()
+    }
+
+    /// `accounts` must contain the accounts listed by `Asset::oracle_keys`.
+    /// With multiple oracles the asset is priced at their median.
     #[inline(never)]
     pub fn get_price(
         &self,
-        account: &AccountInfo,
+        accounts: &[AccountInfo],
         clock: &Clock
     ) -> Result<OraclePrice> {
-        match self.oracle {
-            Oracle::Pyth(_) => get_price_from_pyth(account, clock),
+        let mut prices: Vec<OraclePrice> = Vec::with_capacity(1 + MAX_ADDITIONAL_ORACLES);
+
+        for oracle in self.oracles() {
+            let price = oracle.get_price(accounts, clock, self.oracle_max_age)?;
+            price.check_confidence(self.max_confidence_bps)?;
+            prices.push(price);
         }
+
+        if prices.len() == 1 {
+            return Ok(prices[0]);
+        }
+
+        Ok(OraclePrice::median(&prices, self.max_oracle_deviation_bps)?)
     }
 
     pub fn is_public(&self) -> bool {
@@ -6,12 +6,12 @@ use crate::errors::RlpError;
 #[repr(C)]
 #[derive(BorshSchema, AnchorSerialize, AnchorDeserialize, Clone, Copy, Debug, PartialEq, Eq, InitSpace, Default)]
 pub struct KillSwitch {
-    pub frozen: u16,
+    pub frozen: u32,
 }
 
 impl KillSwitch {
     pub fn deserialize(buf: &mut &[u8]) -> Result<Self> {
-        let frozen = u16::deserialize(buf)?;
+        let frozen = u32::deserialize(buf)?;
         Ok(KillSwitch { frozen })
     }
 
@@ -20,8 +20,13 @@ impl KillSwitch {
         Ok(())
     }
 
+    /// Actions past the width of `frozen` can't be frozen and map to an empty mask.
+    fn mask(action: &Action) -> u32 {
+        1u32.checked_shl(*action as u32).unwrap_or(0)
+    }
+
     pub fn is_frozen(&self, action: &Action) -> bool {
-        let mask = 1u16 << (*action as u16);
+        let mask = Self::mask(action);
         (self.frozen & mask) != 0
     }
 
@@ -33,12 +38,12 @@ impl KillSwitch {
     }
 
     pub fn freeze(&mut self, action: &Action) {
-        let mask = 1u16 << (*action as u16);
+        let mask = Self::mask(action);
         self.frozen |= mask;
     }
 
     pub fn unfreeze(&mut self, action: &Action) {
-        let mask = 1u16 << (*action as u16);
+        let mask = Self::mask(action);
         self.frozen &= !mask;
     }
 }
\ No newline at end of file
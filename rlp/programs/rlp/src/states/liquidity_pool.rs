@@ -5,8 +5,52 @@ use anchor_spl::associated_token::get_associated_token_address;
 use anchor_spl::token::{mint_to, transfer, Mint, MintTo, Token, TokenAccount, Transfer};
 use spl_math::precise_number::PreciseNumber;
 
-pub const MAX_POOL_ASSETS: usize = 4;
+pub const MAX_POOL_ASSETS: usize = 16;
 
+/// Pool asset capacity of the fixed-size layout that predates `migrate_liquidity_pool`.
+pub const LEGACY_MAX_POOL_ASSETS: usize = 4;
+
+// `calculate_total_pool_value` tracks visited pool positions in a `u32`.
+const _: () = assert!(MAX_POOL_ASSETS <= u32::BITS as usize);
+
+pub const MAX_PAIR_FEES: usize = 6;
+
+/// Swap fee override for a pair of pool assets, regardless of swap direction.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Debug, InitSpace)]
+pub struct PairFee {
+    pub asset_a: u8,
+    pub asset_b: u8,
+    pub fee_bps: u16,
+}
+
+impl PairFee {
+    pub fn matches(&self, asset_a: u8, asset_b: u8) -> bool {
+        (self.asset_a == asset_a && self.asset_b == asset_b)
+            || (self.asset_a == asset_b && self.asset_b == asset_a)
+    }
+}
+
+/// Risk limits on inflows of a single pool asset, through deposits and swaps.
+#[derive(AnchorSerialize, AnchorDeserialize, Clone, Copy, PartialEq, Debug, Default, InitSpace)]
+pub struct AssetLimits {
+    pub paused: bool,
+    /// Maximum reserve balance, accrued protocol fees included.
+    pub max_reserve: Option<u64>,
+    /// Maximum share of the pool value held in the asset, in bps.
+    pub max_share_bps: Option<u16>,
+}
+
+/// Oracle values (at `PRECISION` decimals) around a swap, used to price it against target weights.
+#[derive(Debug, Clone, Copy)]
+pub struct SwapComposition {
+    pub total_value: u128,
+    pub value_in: u128,
+    pub value_out: u128,
+    pub trade_value: u128,
+}
+
+/// Sized for its current assets, see `LiquidityPool::space`. Adding or removing an asset
+/// reallocs the account.
 #[derive(InitSpace)]
 #[account]
 pub struct LiquidityPool {
@@ -15,16 +59,396 @@ pub struct LiquidityPool {
     pub lp_token: Pubkey,
     pub cooldowns: u64,
     pub cooldown_duration: u64,
+    /// Maximum LP token supply.
     pub deposit_cap: Option<u64>,
     pub asset_count: u8,
-    pub assets: [u8; MAX_POOL_ASSETS],
+    #[max_len(MAX_POOL_ASSETS)]
+    pub assets: Vec<u8>,
+    /// Protocol fees accrued in the pool reserves, in the same order as `assets`.
+    #[max_len(MAX_POOL_ASSETS)]
+    pub protocol_fees: Vec<u64>,
+    /// Pool-wide swap fee, falls back to `Settings::swap_fee_bps` when unset.
+    pub swap_fee_bps: Option<u16>,
+    pub pair_fees: [Option<PairFee>; MAX_PAIR_FEES],
+    /// Target share of the pool value per asset, in the same order as `assets`. All zero disables weights.
+    #[max_len(MAX_POOL_ASSETS)]
+    pub target_weights_bps: Vec<u16>,
+    /// Fee surcharge (or discount) per bps the swap moves the pool away from (or toward) its targets, in bps.
+    pub imbalance_fee_bps: u16,
+    /// Swaps can't push an asset further than this from its target weight. Zero disables the limit.
+    pub max_weight_deviation_bps: u16,
+    /// Inflow limits per asset, in the same order as `assets`.
+    #[max_len(MAX_POOL_ASSETS)]
+    pub asset_limits: Vec<AssetLimits>,
+    /// Maximum pool value after a deposit, at `PRECISION` decimals like `calculate_total_pool_value`.
+    pub deposit_value_cap: Option<u128>,
+}
+
+/// Fixed-size `LiquidityPool` layout from before pools held fees, weights and limits, only read
+/// by `migrate_liquidity_pool`.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct LegacyLiquidityPool {
+    pub bump: u8,
+    pub index: u8,
+    pub lp_token: Pubkey,
+    pub cooldowns: u64,
+    pub cooldown_duration: u64,
+    pub deposit_cap: Option<u64>,
+    pub asset_count: u8,
+    pub assets: [u8; LEGACY_MAX_POOL_ASSETS],
+}
+
+impl From<LegacyLiquidityPool> for LiquidityPool {
+    fn from(legacy: LegacyLiquidityPool) -> Self {
+        let asset_count = legacy.asset_count as usize;
+
+        LiquidityPool {
+            bump: legacy.bump,
+            index: legacy.index,
+            lp_token: legacy.lp_token,
+            cooldowns: legacy.cooldowns,
+            cooldown_duration: legacy.cooldown_duration,
+            deposit_cap: legacy.deposit_cap,
+            asset_count: legacy.asset_count,
+            assets: legacy.assets[..asset_count].to_vec(),
+            protocol_fees: vec![0; asset_count],
+            swap_fee_bps: None,
+            pair_fees: [None; MAX_PAIR_FEES],
+            target_weights_bps: vec![0; asset_count],
+            imbalance_fee_bps: 0,
+            max_weight_deviation_bps: 0,
+            asset_limits: vec![AssetLimits::default(); asset_count],
+            deposit_value_cap: None,
+        }
+    }
 }
 
 impl LiquidityPool {
+    /// Bytes taken by each asset across `assets`, `protocol_fees`, `target_weights_bps` and `asset_limits`.
+    const ASSET_SPACE: usize = 1 + 8 + 2 + AssetLimits::INIT_SPACE;
+
+    /// Account size, discriminator included, for a pool holding `asset_count` assets.
+    pub fn space(asset_count: usize) -> usize {
+        8 + LiquidityPool::INIT_SPACE - MAX_POOL_ASSETS.saturating_sub(asset_count) * Self::ASSET_SPACE
+    }
+
     pub fn has_asset(&self, asset_index: u8) -> bool {
         self.assets[..self.asset_count as usize].contains(&asset_index)
     }
 
+    /// Swap fee for a pair, resolved as pair override, then pool fee, then the global fee.
+    pub fn resolve_swap_fee(&self, asset_in: u8, asset_out: u8, settings: &Settings) -> u16 {
+        self.pair_fees
+            .iter()
+            .flatten()
+            .find(|pair_fee| pair_fee.matches(asset_in, asset_out))
+            .map(|pair_fee| pair_fee.fee_bps)
+            .or(self.swap_fee_bps)
+            .unwrap_or(settings.swap_fee_bps)
+    }
+
+    /// Sets or clears the fee override of a pair.
+    pub fn set_pair_fee(&mut self, asset_a: u8, asset_b: u8, fee_bps: Option<u16>) -> Result<()> {
+        let existing = self
+            .pair_fees
+            .iter()
+            .position(|pair_fee| pair_fee.is_some_and(|p| p.matches(asset_a, asset_b)));
+
+        let slot = match (existing, fee_bps) {
+            (Some(slot), _) => slot,
+            (None, Some(_)) => self
+                .pair_fees
+                .iter()
+                .position(|pair_fee| pair_fee.is_none())
+                .ok_or(crate::errors::RlpError::PairFeesFull)?,
+            (None, None) => return Ok(()),
+        };
+
+        self.pair_fees[slot] = fee_bps.map(|fee_bps| PairFee {
+            asset_a,
+            asset_b,
+            fee_bps,
+        });
+
+        Ok(())
+    }
+
+    /// Appends an asset with no accrued protocol fees, no target weight and no limits.
+    pub fn add_asset(&mut self, asset_index: u8) -> Result<()> {
+        require!(
+            !self.has_asset(asset_index),
+            crate::errors::RlpError::InvalidInput
+        );
+
+        require!(
+            (self.asset_count as usize) < MAX_POOL_ASSETS,
+            crate::errors::RlpError::PoolAssetsFull
+        );
+
+        self.assets.push(asset_index);
+        self.protocol_fees.push(0);
+        self.target_weights_bps.push(0);
+        self.asset_limits.push(AssetLimits::default());
+        self.asset_count += 1;
+
+        Ok(())
+    }
+
+    /// Removes an asset, keeping the per-asset arrays aligned with `assets`. Its pair fee
+    /// overrides are dropped and it can't carry a target weight.
+    pub fn remove_asset(&mut self, asset_index: u8) -> Result<()> {
+        let position = self
+            .asset_position(asset_index)
+            .ok_or(crate::errors::RlpError::AssetNotWhitelisted)?;
+
+        require!(
+            self.target_weights_bps[position] == 0,
+            crate::errors::RlpError::InvalidInput
+        );
+
+        self.assets.remove(position);
+        self.protocol_fees.remove(position);
+        self.target_weights_bps.remove(position);
+        self.asset_limits.remove(position);
+        self.asset_count -= 1;
+
+        for pair_fee in self.pair_fees.iter_mut() {
+            if pair_fee.is_some_and(|p| p.asset_a == asset_index || p.asset_b == asset_index) {
+                *pair_fee = None;
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn has_target_weights(&self) -> bool {
+        self.target_weights_bps.iter().any(|&weight| weight > 0)
+    }
+
+    fn target_weight(&self, asset_index: u8) -> u128 {
+        self.asset_position(asset_index)
+            .map_or(0, |position| self.target_weights_bps[position] as u128)
+    }
+
+    /// Adjusts `fee_bps` by how much the swap moves the in and out assets toward or away from
+    /// their target weights, rejecting swaps that leave an asset too far from its target.
+    pub fn weighted_swap_fee(
+        &self,
+        fee_bps: u16,
+        asset_in: u8,
+        asset_out: u8,
+        composition: SwapComposition,
+    ) -> Result<u16> {
+        let SwapComposition {
+            total_value,
+            value_in,
+            value_out,
+            trade_value,
+        } = composition;
+
+        if total_value == 0 {
+            return Ok(fee_bps);
+        }
+
+        let deviation_in = self.weight_deviation(
+            asset_in,
+            value_in,
+            value_in
+                .checked_add(trade_value)
+                .ok_or(crate::errors::RlpError::MathOverflow)?,
+            total_value,
+            total_value,
+        )?;
+        let deviation_out = self.weight_deviation(
+            asset_out,
+            value_out,
+            value_out.saturating_sub(trade_value),
+            total_value,
+            total_value,
+        )?;
+
+        self.apply_imbalance_fee(fee_bps, &[deviation_in, deviation_out])
+    }
+
+    /// `weighted_swap_fee` for a withdrawal paid out in `asset_out` alone, where the pool
+    /// total shrinks by `trade_value` as well. `value_in` isn't used.
+    pub fn weighted_withdraw_fee(
+        &self,
+        fee_bps: u16,
+        asset_out: u8,
+        composition: SwapComposition,
+    ) -> Result<u16> {
+        let SwapComposition {
+            total_value,
+            value_out,
+            trade_value,
+            ..
+        } = composition;
+
+        let total_after = total_value.saturating_sub(trade_value);
+
+        if total_after == 0 {
+            return Ok(fee_bps);
+        }
+
+        let deviation_out = self.weight_deviation(
+            asset_out,
+            value_out,
+            value_out.saturating_sub(trade_value),
+            total_value,
+            total_after,
+        )?;
+
+        self.apply_imbalance_fee(fee_bps, &[deviation_out])
+    }
+
+    /// Distance of an asset from its target weight before and after a trade, in bps.
+    fn weight_deviation(
+        &self,
+        asset_index: u8,
+        value_before: u128,
+        value_after: u128,
+        total_before: u128,
+        total_after: u128,
+    ) -> Result<(u128, u128)> {
+        let weight = |value: u128, total_value: u128| -> Result<u128> {
+            Ok(value
+                .checked_mul(BPS_PRECISION)
+                .ok_or(crate::errors::RlpError::MathOverflow)?
+                / total_value)
+        };
+
+        let target = self.target_weight(asset_index);
+
+        Ok((
+            weight(value_before, total_before)?.abs_diff(target),
+            weight(value_after, total_after)?.abs_diff(target),
+        ))
+    }
+
+    /// Rejects trades leaving an asset past `max_weight_deviation_bps` unless they bring it
+    /// closer, then moves `fee_bps` by the net change in deviation times `imbalance_fee_bps`.
+    fn apply_imbalance_fee(&self, fee_bps: u16, deviations: &[(u128, u128)]) -> Result<u16> {
+        if self.max_weight_deviation_bps > 0 {
+            let max_deviation = self.max_weight_deviation_bps as u128;
+
+            for &(before, after) in deviations {
+                require!(
+                    after <= max_deviation || after <= before,
+                    crate::errors::RlpError::PoolImbalance
+                );
+            }
+        }
+
+        let deviation_before: u128 = deviations.iter().map(|&(before, _)| before).sum();
+        let deviation_after: u128 = deviations.iter().map(|&(_, after)| after).sum();
+
+        let adjustment = deviation_before
+            .abs_diff(deviation_after)
+            .checked_mul(self.imbalance_fee_bps as u128)
+            .ok_or(crate::errors::RlpError::MathOverflow)?
+            / BPS_PRECISION;
+
+        let fee_bps = if deviation_after > deviation_before {
+            (fee_bps as u128)
+                .saturating_add(adjustment)
+                .min(MAX_SWAP_FEE_BPS as u128)
+        } else {
+            (fee_bps as u128).saturating_sub(adjustment)
+        };
+
+        Ok(fee_bps as u16)
+    }
+
+    fn asset_position(&self, asset_index: u8) -> Option<usize> {
+        self.assets[..self.asset_count as usize]
+            .iter()
+            .position(|&asset| asset == asset_index)
+    }
+
+    pub fn asset_limits_for(&self, asset_index: u8) -> AssetLimits {
+        self.asset_position(asset_index)
+            .map_or(AssetLimits::default(), |position| self.asset_limits[position])
+    }
+
+    pub fn set_asset_limits(&mut self, asset_index: u8, limits: AssetLimits) -> Result<()> {
+        let position = self
+            .asset_position(asset_index)
+            .ok_or(crate::errors::RlpError::AssetNotWhitelisted)?;
+
+        self.asset_limits[position] = limits;
+
+        Ok(())
+    }
+
+    /// Rejects inflows into a paused asset, or leaving its reserve above the cap.
+    pub fn check_asset_inflow(&self, asset_index: u8, reserve_after: u64) -> Result<()> {
+        let limits = self.asset_limits_for(asset_index);
+
+        require!(!limits.paused, crate::errors::RlpError::AssetPaused);
+
+        if let Some(max_reserve) = limits.max_reserve {
+            require!(
+                reserve_after <= max_reserve,
+                crate::errors::RlpError::AssetCapExceeded
+            );
+        }
+
+        Ok(())
+    }
+
+    /// Rejects inflows leaving the asset above its maximum share of the pool value. Share caps
+    /// don't apply while the pool is empty, so that it can be seeded.
+    pub fn check_asset_share(
+        &self,
+        asset_index: u8,
+        value_after: u128,
+        total_value_before: u128,
+        total_value_after: u128,
+    ) -> Result<()> {
+        if let Some(max_share_bps) = self.asset_limits_for(asset_index).max_share_bps {
+            if total_value_before > 0 {
+                require!(
+                    value_after
+                        .checked_mul(BPS_PRECISION)
+                        .ok_or(crate::errors::RlpError::MathOverflow)?
+                        <= total_value_after
+                            .checked_mul(max_share_bps as u128)
+                            .ok_or(crate::errors::RlpError::MathOverflow)?,
+                    crate::errors::RlpError::AssetCapExceeded
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn protocol_fees_for(&self, asset_index: u8) -> u64 {
+        self.asset_position(asset_index)
+            .map_or(0, |position| self.protocol_fees[position])
+    }
+
+    pub fn accrue_protocol_fee(&mut self, asset_index: u8, amount: u64) -> Result<()> {
+        let position = self
+            .asset_position(asset_index)
+            .ok_or(crate::errors::RlpError::AssetNotWhitelisted)?;
+
+        self.protocol_fees[position] = self.protocol_fees[position]
+            .checked_add(amount)
+            .ok_or(crate::errors::RlpError::MathOverflow)?;
+
+        Ok(())
+    }
+
+    /// Resets the accrued protocol fees of an asset, returning the claimable amount.
+    pub fn take_protocol_fees(&mut self, asset_index: u8) -> Result<u64> {
+        let position = self
+            .asset_position(asset_index)
+            .ok_or(crate::errors::RlpError::AssetNotWhitelisted)?;
+
+        Ok(std::mem::take(&mut self.protocol_fees[position]))
+    }
+
     pub fn deposit<'info>(
         &self,
         signer: &Signer<'info>,
@@ -55,25 +479,28 @@ impl LiquidityPool {
         remaining_accounts: &[AccountInfo],
         liquidity_pool: &Account<LiquidityPool>,
         settings: &Account<Settings>,
+        reward_pool: Option<&RewardPool>,
         clock: &Clock,
     ) -> Result<PreciseNumber> {
-        let expected_len = self.asset_count as usize * 4;
         let mut total_pool_value =
             PreciseNumber::new(0).ok_or(crate::errors::RlpError::MathOverflow)?;
 
-        require!(
-            remaining_accounts.len() == expected_len,
-            crate::errors::RlpError::InvalidInput
-        );
-
-        let mut visited_mints: Vec<Pubkey> = Vec::with_capacity(self.asset_count as usize);
+        // Pool positions already valued, one bit per position.
+        let mut visited: u32 = 0;
 
+        // Each asset takes [token_account, asset, ...oracle accounts, mint], where the
+        // oracle accounts are the ones listed by `Asset::oracle_keys` (none for fixed prices).
+        // Duplicates are tracked by pool position and asset addresses are checked against
+        // their stored bump, which keeps a 16-asset pool within the compute budget.
         let mut i = 0;
         while i < remaining_accounts.len() {
+            require!(
+                remaining_accounts.len() >= i + 2,
+                crate::errors::RlpError::InvalidInput
+            );
+
             let token_account_info = &remaining_accounts[i];
             let asset_info = &remaining_accounts[i + 1];
-            let oracle_info = &remaining_accounts[i + 2];
-            let mint_info = &remaining_accounts[i + 3];
 
             require!(
                 token_account_info.owner == &anchor_spl::token::ID,
@@ -81,7 +508,7 @@ impl LiquidityPool {
             );
 
             let token_account = TokenAccount::try_deserialize(
-                &mut token_account_info.try_borrow_mut_data()?.as_ref(),
+                &mut token_account_info.try_borrow_data()?.as_ref(),
             )
             .map_err(|_| crate::errors::RlpError::InvalidInput)?;
 
@@ -95,35 +522,35 @@ impl LiquidityPool {
                 crate::errors::RlpError::InvalidInput
             );
 
-            let asset = Asset::try_deserialize(&mut asset_info.try_borrow_mut_data()?.as_ref())
+            let asset = Asset::try_deserialize(&mut asset_info.try_borrow_data()?.as_ref())
                 .map_err(|_| crate::errors::RlpError::InvalidInput)?;
 
-            require!(
-                self.has_asset(asset.index),
-                crate::errors::RlpError::AssetNotWhitelisted
-            );
-
-            let asset_mint = asset.mint;
+            let position = self
+                .asset_position(asset.index)
+                .ok_or(crate::errors::RlpError::AssetNotWhitelisted)?;
 
             require!(
-                !visited_mints.contains(&asset_mint),
+                visited & (1 << position) == 0,
                 crate::errors::RlpError::InvalidInput
             );
 
-            visited_mints.push(asset_mint);
+            visited |= 1 << position;
 
             require!(
                 asset.mint == token_account.mint,
                 crate::errors::RlpError::InvalidInput
             );
 
-            let (expected_asset_pda, _) = Pubkey::find_program_address(
+            // The stored bump saves a `find_program_address` per asset.
+            let expected_asset_pda = Pubkey::create_program_address(
                 &[
                     crate::constants::ASSET_SEED.as_bytes(),
                     &asset.mint.to_bytes(),
+                    &[asset.bump],
                 ],
                 &crate::ID,
-            );
+            )
+            .map_err(|_| crate::errors::RlpError::InvalidInput)?;
 
             require!(
                 asset_info.key() == expected_asset_pda,
@@ -138,14 +565,17 @@ impl LiquidityPool {
                 crate::errors::RlpError::InvalidInput
             );
 
+            let oracle_accounts_count = asset.oracle_keys().len();
+
             require!(
-                oracle_info.key() == *asset.oracle.key(),
+                remaining_accounts.len() >= i + 3 + oracle_accounts_count,
                 crate::errors::RlpError::InvalidInput
             );
 
-            let asset_price = asset
-                .get_price(oracle_info, clock)
-                .map_err(|_| crate::errors::RlpError::InvalidInput)?;
+            let oracle_accounts = &remaining_accounts[i + 2..i + 2 + oracle_accounts_count];
+            let mint_info = &remaining_accounts[i + 2 + oracle_accounts_count];
+
+            let asset_price = asset.get_price(oracle_accounts, clock)?;
 
             require!(
                 mint_info.owner == &anchor_spl::token::ID,
@@ -157,11 +587,18 @@ impl LiquidityPool {
                 crate::errors::RlpError::InvalidInput
             );
 
-            let mint_data = &mut mint_info.try_borrow_mut_data()?;
+            let mint_data = mint_info.try_borrow_data()?;
             let mint_account = Mint::try_deserialize(&mut mint_data.as_ref())
                 .map_err(|_| crate::errors::RlpError::InvalidInput)?;
 
-            let token_balance = token_account.amount;
+            // Rewards that are still vesting and accrued protocol fees don't count
+            // towards the pool value.
+            let token_balance = self.withdrawable_reserve(
+                asset.index,
+                token_account.amount,
+                reward_pool,
+                clock.unix_timestamp as u64,
+            )?;
             let token_decimals = mint_account.decimals;
             if token_balance > 0 {
                 let token_value_precise =
@@ -172,12 +609,39 @@ impl LiquidityPool {
                     .ok_or(crate::errors::RlpError::MathOverflow)?;
             }
 
-            i += 4;
+            i += 3 + oracle_accounts_count;
         }
 
+        require!(
+            visited.count_ones() == self.asset_count as u32,
+            crate::errors::RlpError::InvalidInput
+        );
+
         Ok(total_pool_value)
     }
 
+    /// Rejects deposits leaving the pool value above `deposit_value_cap`.
+    pub fn check_deposit_value_cap(
+        &self,
+        total_pool_value: &PreciseNumber,
+        deposit_value: &PreciseNumber,
+    ) -> Result<()> {
+        if let Some(cap) = self.deposit_value_cap {
+            let total_pool_value_after = total_pool_value
+                .checked_add(deposit_value)
+                .ok_or(crate::errors::RlpError::MathOverflow)?
+                .to_imprecise()
+                .ok_or(crate::errors::RlpError::MathOverflow)?;
+
+            require!(
+                total_pool_value_after <= cap,
+                crate::errors::RlpError::DepositCapOverflow
+            );
+        }
+
+        Ok(())
+    }
+
     pub fn calculate_lp_tokens_on_deposit(
         &self,
         lp_token: &Account<Mint>,
@@ -220,6 +684,52 @@ impl LiquidityPool {
         Ok(lp_tokens_to_mint)
     }
 
+    /// Reserve balance backing LP tokens: rewards that are still vesting and accrued
+    /// protocol fees stay in the pool.
+    pub fn withdrawable_reserve(
+        &self,
+        asset_index: u8,
+        reserve_amount: u64,
+        reward_pool: Option<&RewardPool>,
+        now: u64,
+    ) -> Result<u64> {
+        let unvested_rewards = match reward_pool {
+            Some(reward_pool) => reward_pool.unvested(asset_index, now)?,
+            None => 0,
+        };
+
+        Ok(reserve_amount
+            .saturating_sub(unvested_rewards)
+            .saturating_sub(self.protocol_fees_for(asset_index)))
+    }
+
+    /// Pro-rata share of `reserve_amount` owed for burning `lp_token_amount`.
+    pub fn calculate_withdraw_amount(
+        &self,
+        reserve_amount: u64,
+        lp_token_amount: u64,
+        lp_token_supply: u64,
+    ) -> Result<u64> {
+        let share_amount = PreciseNumber::new(reserve_amount as u128)
+            .ok_or(crate::errors::RlpError::MathOverflow)?
+            .checked_mul(
+                &PreciseNumber::new(lp_token_amount as u128)
+                    .ok_or(crate::errors::RlpError::MathOverflow)?,
+            )
+            .ok_or(crate::errors::RlpError::MathOverflow)?
+            .checked_div(
+                &PreciseNumber::new(lp_token_supply as u128)
+                    .ok_or(crate::errors::RlpError::MathOverflow)?,
+            )
+            .ok_or(crate::errors::RlpError::MathOverflow)?
+            .to_imprecise()
+            .ok_or(crate::errors::RlpError::MathOverflow)?;
+
+        share_amount
+            .try_into()
+            .map_err(|_| error!(crate::errors::RlpError::MathOverflow))
+    }
+
     pub fn mint_lp_token<'info>(
         &self,
         amount: u64,
@@ -23,4 +23,7 @@ pub mod killswitch;
 pub use killswitch::*;
 
 pub mod update;
-pub use update::*;
\ No newline at end of file
+pub use update::*;
+
+pub mod reward_pool;
+pub use reward_pool::*;
\ No newline at end of file
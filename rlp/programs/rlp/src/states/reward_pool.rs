@@ -0,0 +1,172 @@
+use crate::constants::*;
+use crate::errors::RlpError;
+use anchor_lang::prelude::*;
+
+/// Assets with rewards vesting at the same time. Kept apart from `MAX_POOL_ASSETS` so
+/// that reward pools keep their layout as pools grow.
+pub const MAX_REWARD_SCHEDULES: usize = 4;
+
+/// Rewards of a single asset vesting linearly between `start_ts` and `end_ts`.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace, Clone, Copy, Default)]
+pub struct RewardSchedule {
+    pub asset: u8,
+    pub amount: u64,
+    pub start_ts: u64,
+    pub end_ts: u64,
+}
+
+impl RewardSchedule {
+    pub fn unvested(&self, now: u64) -> Result<u64> {
+        if self.amount == 0 || now >= self.end_ts {
+            return Ok(0);
+        }
+
+        let remaining = self.end_ts - now.max(self.start_ts);
+        let duration = self.end_ts - self.start_ts;
+
+        let unvested = (self.amount as u128)
+            .checked_mul(remaining as u128)
+            .ok_or(RlpError::MathOverflow)?
+            .checked_div(duration as u128)
+            .ok_or(RlpError::MathOverflow)?;
+
+        Ok(unvested as u64)
+    }
+}
+
+#[derive(InitSpace)]
+#[account]
+pub struct RewardPool {
+    pub bump: u8,
+    pub liquidity_pool: Pubkey,
+    /// Window (in seconds) over which each reward deposit vests.
+    pub vesting_duration: u64,
+    pub schedules: [RewardSchedule; MAX_REWARD_SCHEDULES],
+}
+
+impl RewardPool {
+    /// Amount of `asset` rewards sitting in the pool reserve that has not vested yet.
+    pub fn unvested(&self, asset: u8, now: u64) -> Result<u64> {
+        let mut unvested: u64 = 0;
+
+        for schedule in self.schedules.iter().filter(|s| s.asset == asset) {
+            unvested = unvested
+                .checked_add(schedule.unvested(now)?)
+                .ok_or(RlpError::MathOverflow)?;
+        }
+
+        Ok(unvested)
+    }
+
+    /// Starts vesting `amount` of `asset`, rolling in whatever is still unvested
+    /// from the asset's previous schedule.
+    pub fn stream(&mut self, asset: u8, amount: u64, now: u64) -> Result<()> {
+        let slot = match self
+            .schedules
+            .iter()
+            .position(|s| s.amount > 0 && s.asset == asset)
+        {
+            Some(slot) => slot,
+            None => self
+                .schedules
+                .iter()
+                .map(|s| s.unvested(now))
+                .collect::<Result<Vec<u64>>>()?
+                .iter()
+                .position(|&unvested| unvested == 0)
+                .ok_or(RlpError::RewardScheduleFull)?,
+        };
+
+        let schedule = &mut self.schedules[slot];
+        let carried = if schedule.asset == asset {
+            schedule.unvested(now)?
+        } else {
+            0
+        };
+
+        *schedule = RewardSchedule {
+            asset,
+            amount: carried.checked_add(amount).ok_or(RlpError::MathOverflow)?,
+            start_ts: now,
+            end_ts: now
+                .checked_add(self.vesting_duration)
+                .ok_or(RlpError::MathOverflow)?,
+        };
+
+        Ok(())
+    }
+
+    /// Reads the reward pool of a liquidity pool, if one has been initialized.
+    pub fn load(reward_pool: &AccountInfo, liquidity_pool: &Pubkey) -> Result<Option<RewardPool>> {
+        let (expected_reward_pool, _) = Pubkey::find_program_address(
+            &[REWARD_POOL_SEED.as_bytes(), liquidity_pool.as_ref()],
+            &crate::ID,
+        );
+
+        require!(
+            reward_pool.key() == expected_reward_pool,
+            RlpError::InvalidInput
+        );
+
+        if reward_pool.data_is_empty() {
+            return Ok(None);
+        }
+
+        require!(
+            reward_pool.owner == &crate::ID,
+            RlpError::InvalidInput
+        );
+
+        let reward_pool = RewardPool::try_deserialize(&mut reward_pool.try_borrow_data()?.as_ref())?;
+        Ok(Some(reward_pool))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn schedule(amount: u64, start_ts: u64, end_ts: u64) -> RewardSchedule {
+        RewardSchedule {
+            asset: 0,
+            amount,
+            start_ts,
+            end_ts,
+        }
+    }
+
+    #[test]
+    fn unvested_releases_linearly() {
+        let schedule = schedule(1_000, 100, 200);
+
+        assert_eq!(schedule.unvested(100).unwrap(), 1_000);
+        assert_eq!(schedule.unvested(125).unwrap(), 750);
+        assert_eq!(schedule.unvested(199).unwrap(), 10);
+        assert_eq!(schedule.unvested(200).unwrap(), 0);
+        assert_eq!(schedule.unvested(300).unwrap(), 0);
+    }
+
+    #[test]
+    fn unvested_is_everything_before_the_start() {
+        assert_eq!(schedule(1_000, 100, 200).unvested(50).unwrap(), 1_000);
+    }
+
+    #[test]
+    fn unvested_rounds_down() {
+        // 10 × 2 / 3 leaves 6.67 unvested.
+        assert_eq!(schedule(10, 0, 3).unvested(1).unwrap(), 6);
+    }
+
+    #[test]
+    fn unvested_handles_empty_and_instant_schedules() {
+        assert_eq!(RewardSchedule::default().unvested(0).unwrap(), 0);
+        assert_eq!(schedule(1_000, 100, 100).unvested(100).unwrap(), 0);
+    }
+
+    #[test]
+    fn unvested_does_not_overflow_on_large_amounts() {
+        let schedule = schedule(u64::MAX, 0, u64::MAX);
+
+        assert_eq!(schedule.unvested(u64::MAX / 2 + 1).unwrap(), u64::MAX / 2);
+    }
+}
\ No newline at end of file
@@ -1,4 +1,4 @@
-use crate::states::AccessControl;
+use crate::states::{AccessControl, AccessMap, Action, KillSwitch, Role};
 use anchor_lang::prelude::*;
 
 #[account]
@@ -9,5 +9,58 @@ pub struct Settings {
     pub assets: u8,
     pub access_control: AccessControl,
     pub swap_fee_bps: u16,
+    /// Share of the swap fee (in bps of the fee) accrued to the protocol instead of LPs.
+    pub protocol_fee_bps: u16,
+    /// Owner of the token accounts protocol fees are claimed to.
+    pub treasury: Pubkey,
 }
 
+/// `Settings` layout before protocol fees, when `KillSwitch::frozen` was a `u16`. Only read by
+/// `migrate_settings`.
+#[derive(AnchorSerialize, AnchorDeserialize, InitSpace)]
+pub struct LegacySettings {
+    pub bump: u8,
+    pub liquidity_pools: u8,
+    pub assets: u8,
+    pub access_map: AccessMap,
+    pub frozen: u16,
+    pub swap_fee_bps: u16,
+}
+
+impl LegacySettings {
+    /// Converts to the current layout with protocol fees off, like `initialize_rlp`. Actions added
+    /// since get the roles `AccessControl::new_defaults` grants them, unless already mapped.
+    pub fn into_settings(self, treasury: Pubkey) -> Result<Settings> {
+        let mut access_control = AccessControl {
+            access_map: self.access_map,
+            killswitch: KillSwitch {
+                frozen: self.frozen as u32,
+            },
+        };
+
+        for (action, role) in [
+            (Action::DepositRewards, Role::MANAGER),
+            (Action::DepositRewards, Role::CRANK),
+            (Action::ClaimProtocolFees, Role::MANAGER),
+        ] {
+            let mapped = access_control
+                .access_map
+                .get_action_allowees(action)
+                .is_some_and(|roles| roles.contains(&role));
+
+            if !mapped {
+                access_control.add_role_to_action(action, role)?;
+            }
+        }
+
+        Ok(Settings {
+            bump: self.bump,
+            liquidity_pools: self.liquidity_pools,
+            assets: self.assets,
+            access_control,
+            swap_fee_bps: self.swap_fee_bps,
+            protocol_fee_bps: 0,
+            treasury,
+        })
+    }
+}
\ No newline at end of file
@@ -14,13 +14,15 @@ import {
   type Cooldown,
   type UserPermissions,
   type AccessLevel,
+  type FixedPriceArgs,
+  type Oracle,
   RLP_PROGRAM_ADDRESS,
+  ASSET_DISCRIMINATOR,
   LIQUIDITY_POOL_DISCRIMINATOR,
   USER_PERMISSIONS_DISCRIMINATOR,
   fetchSettings,
   fetchLiquidityPool,
   getLiquidityPoolDecoder,
-  getAssetEncoder,
   getAssetDecoder,
   getCooldownEncoder,
   getCooldownDecoder,
@@ -117,7 +119,6 @@ export class Insurance {
       return this.assets;
     }
 
-    const encoder = getAssetEncoder();
     const decoder = getAssetDecoder();
 
     const programAccounts = await (this.connection as any)
@@ -125,7 +126,13 @@ export class Insurance {
         encoding: "base64",
         withContext: false,
         filters: [
-          { dataSize: BigInt((encoder as any).fixedSize) },
+          {
+            memcmp: {
+              encoding: "base64",
+              offset: BigInt(0),
+              bytes: Buffer.from(ASSET_DISCRIMINATOR).toString("base64"),
+            },
+          },
         ],
       })
       .send();
@@ -248,14 +255,20 @@ export class Insurance {
   async addAsset(
     signer: TransactionSigner,
     assetMint: Address,
-    oracle: Address,
+    oracle: Address | undefined,
     accessLevel: AccessLevel,
+    maxConfidenceBps: number,
+    oracleMaxAge: number | bigint | null = null,
+    fixedPrice: FixedPriceArgs | null = null,
   ) {
     return getAddAssetInstructionAsync({
       signer,
       assetMint,
       oracle,
       accessLevel,
+      maxConfidenceBps,
+      oracleMaxAge,
+      fixedPrice,
     });
   }
 
@@ -334,9 +347,49 @@ export class Insurance {
     });
   }
 
+  /**
+   * Address of the price feed account an oracle reads, if any.
+   * Mirrors `Oracle::key`.
+   */
+  static oracleKey(oracle: Oracle): Address | undefined {
+    switch (oracle.__kind) {
+      case "Pyth":
+      case "Switchboard":
+        return oracle.fields[0];
+      case "ExchangeRate":
+        return oracle.base;
+      case "Fixed":
+        return oracle.depegGuard.__option === "Some"
+          ? oracle.depegGuard.value.oracle
+          : undefined;
+    }
+  }
+
+  /**
+   * Addresses of all accounts needed to price an asset, across its primary
+   * and additional oracles. Mirrors `Asset::oracle_keys`.
+   */
+  static oracleKeys(asset: Asset): Address[] {
+    const oracles = [asset.oracle];
+    for (const additional of asset.additionalOracles) {
+      if (additional.__option === "Some") oracles.push(additional.value);
+    }
+
+    return oracles.flatMap((oracle) => {
+      const key = Insurance.oracleKey(oracle);
+      const keys = key ? [key] : [];
+      if (oracle.__kind === "ExchangeRate") {
+        const source = oracle.rateSource;
+        if (source.__kind === "SplStakePool") keys.push(source.fields[0]);
+        else keys.push(source.poolMint, source.poolStake);
+      }
+      return keys;
+    });
+  }
+
   /**
    * Build the remaining accounts needed by calculate_total_pool_value().
-   * Per asset (in pool asset order): [pool_ata, asset_pda, oracle, mint]
+   * Per asset (in pool asset order): [pool_ata, asset_pda, ...oracle_keys, mint]
    */
   private async buildPoolValueRemainingAccounts(
     liquidityPoolAddress: Address,
@@ -351,12 +404,13 @@ export class Insurance {
         owner: liquidityPoolAddress,
         tokenProgram: TOKEN_PROGRAM_ADDRESS,
       });
-      const oracleAddress = (asset.data.oracle as any).fields[0] as Address;
-
       remaining.push(
         { address: poolAta, role: AccountRole.READONLY },
         { address: asset.address, role: AccountRole.READONLY },
-        { address: oracleAddress, role: AccountRole.READONLY },
+        ...Insurance.oracleKeys(asset.data).map((address) => ({
+          address,
+          role: AccountRole.READONLY,
+        })),
         { address: asset.data.mint, role: AccountRole.READONLY },
       );
     }
@@ -432,7 +486,7 @@ export class Insurance {
     const assetEntry = assets.find((a) => a.data.mint === mint);
     if (!assetEntry) throw new Error(`Asset not found for mint ${mint}`);
 
-    const oracleAddress = (assetEntry.data.oracle as any).fields[0];
+    const oracleAddress = Insurance.oracleKey(assetEntry.data.oracle);
 
     const lpEntry = this.liquidityPools.find(
       (lp) => lp.data.index === liquidityPoolId,
@@ -446,10 +500,13 @@ export class Insurance {
       tokenProgram: TOKEN_PROGRAM_ADDRESS,
     });
 
+    const [rewardPool] = await PdaClient.deriveRewardPool(lpEntry.address);
+
     const ix = await getDepositInstructionAsync({
       signer,
       permissions: RLP_PROGRAM_ADDRESS,
       liquidityPool: lpEntry.address,
+      rewardPool,
       lpToken: lpEntry.data.lpToken,
       assetMint: mint,
       userAssetAccount,
@@ -517,10 +574,13 @@ export class Insurance {
       cooldownId,
     );
 
+    const [rewardPool] = await PdaClient.deriveRewardPool(lpEntry.address);
+
     const ix = await getWithdrawInstructionAsync({
       signer,
       permissions: RLP_PROGRAM_ADDRESS,
       liquidityPool: lpEntry.address,
+      rewardPool,
       lpTokenMint: lpEntry.data.lpToken,
       cooldown: cooldownAddress,
       liquidityPoolId,
@@ -554,10 +614,8 @@ export class Insurance {
     if (!tokenToEntry)
       throw new Error(`Asset not found for mint ${tokenToMint}`);
 
-    const tokenFromOracle = (tokenFromEntry.data.oracle as any)
-      .fields[0] as Address;
-    const tokenToOracle = (tokenToEntry.data.oracle as any)
-      .fields[0] as Address;
+    const tokenFromOracle = Insurance.oracleKey(tokenFromEntry.data.oracle);
+    const tokenToOracle = Insurance.oracleKey(tokenToEntry.data.oracle);
 
     const lpEntry = this.liquidityPools.find(
       (lp) => lp.data.index === liquidityPoolId,
@@ -589,9 +647,12 @@ export class Insurance {
       tokenProgram: TOKEN_PROGRAM_ADDRESS,
     });
 
-    return getSwapInstructionAsync({
+    const [rewardPool] = await PdaClient.deriveRewardPool(lpEntry.address);
+
+    const ix = await getSwapInstructionAsync({
       signer,
       liquidityPool: lpEntry.address,
+      rewardPool,
       tokenFrom: tokenFromMint,
       tokenFromOracle,
       tokenTo: tokenToMint,
@@ -603,6 +664,15 @@ export class Insurance {
       amountIn,
       minOut: (minOut ?? null) as any,
     });
+
+    // Additional oracles, target weights and share caps are priced from the
+    // pool valuation layout.
+    const remaining = await this.buildPoolValueRemainingAccounts(
+      lpEntry.address,
+      liquidityPoolId,
+    );
+
+    return this.appendRemainingAccounts(ix, remaining);
   }
 
   async createPermissionAccount(
@@ -719,7 +789,9 @@ export class Insurance {
     const assets = await this.getAssets();
     const assetEntry = assets.find((a) => a.data.mint === mintOrOracle);
     if (assetEntry) {
-      oracleAddress = (assetEntry.data.oracle as any).fields[0] as Address;
+      const key = Insurance.oracleKey(assetEntry.data.oracle);
+      if (!key) throw new Error(`Asset ${mintOrOracle} has a fixed price`);
+      oracleAddress = key;
     }
 
     const account = await fetchEncodedAccount(this.connection, oracleAddress);
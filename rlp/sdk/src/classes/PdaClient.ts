@@ -11,6 +11,7 @@ import {
   LIQUIDITY_POOL_SEED,
   ASSET_SEED,
   COOLDOWN_SEED,
+  REWARD_POOL_SEED,
 } from "../constants";
 import { RLP_PROGRAM_ADDRESS } from "../generated";
 
@@ -59,4 +60,11 @@ export class PdaClient {
       ],
     });
   }
+
+  static async deriveRewardPool(liquidityPool: Address) {
+    return getProgramDerivedAddress({
+      programAddress: RLP_PROGRAM_ADDRESS,
+      seeds: [REWARD_POOL_SEED, getAddressEncoder().encode(liquidityPool)],
+    });
+  }
 }
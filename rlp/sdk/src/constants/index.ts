@@ -3,3 +3,4 @@ export const PERMISSIONS_SEED = "permissions";
 export const LIQUIDITY_POOL_SEED = "liquidity_pool";
 export const ASSET_SEED = "asset";
 export const COOLDOWN_SEED = "cooldown";
+export const REWARD_POOL_SEED = "reward_pool";
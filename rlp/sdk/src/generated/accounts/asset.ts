@@ -17,23 +17,33 @@ import {
   fixEncoderSize,
   getAddressDecoder,
   getAddressEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
   getBytesDecoder,
   getBytesEncoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
+  getU64Decoder,
+  getU64Encoder,
   getU8Decoder,
   getU8Encoder,
   transformEncoder,
   type Account,
   type Address,
+  type Codec,
+  type Decoder,
   type EncodedAccount,
+  type Encoder,
   type FetchAccountConfig,
   type FetchAccountsConfig,
-  type FixedSizeCodec,
-  type FixedSizeDecoder,
-  type FixedSizeEncoder,
   type MaybeAccount,
   type MaybeEncodedAccount,
+  type Option,
+  type OptionOrNullable,
   type ReadonlyUint8Array,
 } from "@solana/kit";
 import {
@@ -62,6 +72,11 @@ export type Asset = {
   mint: Address;
   oracle: Oracle;
   accessLevel: AccessLevel;
+  maxConfidenceBps: number;
+  oracleMaxAge: bigint;
+  additionalOracles: Array<Option<Oracle>>;
+  /** Maximum spread between the oracle sources, only used with additional oracles. */
+  maxOracleDeviationBps: number;
 };
 
 export type AssetArgs = {
@@ -70,10 +85,15 @@ export type AssetArgs = {
   mint: Address;
   oracle: OracleArgs;
   accessLevel: AccessLevelArgs;
+  maxConfidenceBps: number;
+  oracleMaxAge: number | bigint;
+  additionalOracles: Array<OptionOrNullable<OracleArgs>>;
+  /** Maximum spread between the oracle sources, only used with additional oracles. */
+  maxOracleDeviationBps: number;
 };
 
 /** Gets the encoder for {@link AssetArgs} account data. */
-export function getAssetEncoder(): FixedSizeEncoder<AssetArgs> {
+export function getAssetEncoder(): Encoder<AssetArgs> {
   return transformEncoder(
     getStructEncoder([
       ["discriminator", fixEncoderSize(getBytesEncoder(), 8)],
@@ -82,13 +102,20 @@ export function getAssetEncoder(): FixedSizeEncoder<AssetArgs> {
       ["mint", getAddressEncoder()],
       ["oracle", getOracleEncoder()],
       ["accessLevel", getAccessLevelEncoder()],
+      ["maxConfidenceBps", getU16Encoder()],
+      ["oracleMaxAge", getU64Encoder()],
+      [
+        "additionalOracles",
+        getArrayEncoder(getOptionEncoder(getOracleEncoder()), { size: 2 }),
+      ],
+      ["maxOracleDeviationBps", getU16Encoder()],
     ]),
     (value) => ({ ...value, discriminator: ASSET_DISCRIMINATOR }),
   );
 }
 
 /** Gets the decoder for {@link Asset} account data. */
-export function getAssetDecoder(): FixedSizeDecoder<Asset> {
+export function getAssetDecoder(): Decoder<Asset> {
   return getStructDecoder([
     ["discriminator", fixDecoderSize(getBytesDecoder(), 8)],
     ["bump", getU8Decoder()],
@@ -96,11 +123,18 @@ export function getAssetDecoder(): FixedSizeDecoder<Asset> {
     ["mint", getAddressDecoder()],
     ["oracle", getOracleDecoder()],
     ["accessLevel", getAccessLevelDecoder()],
+    ["maxConfidenceBps", getU16Decoder()],
+    ["oracleMaxAge", getU64Decoder()],
+    [
+      "additionalOracles",
+      getArrayDecoder(getOptionDecoder(getOracleDecoder()), { size: 2 }),
+    ],
+    ["maxOracleDeviationBps", getU16Decoder()],
   ]);
 }
 
 /** Gets the codec for {@link Asset} account data. */
-export function getAssetCodec(): FixedSizeCodec<AssetArgs, Asset> {
+export function getAssetCodec(): Codec<AssetArgs, Asset> {
   return combineCodec(getAssetEncoder(), getAssetDecoder());
 }
 
@@ -156,7 +190,3 @@ export async function fetchAllMaybeAsset(
   const maybeAccounts = await fetchEncodedAccounts(rpc, addresses, config);
   return maybeAccounts.map((maybeAccount) => decodeAsset(maybeAccount));
 }
-
-export function getAssetSize(): number {
-  return 76;
-}
@@ -9,5 +9,6 @@
 export * from "./asset";
 export * from "./cooldown";
 export * from "./liquidityPool";
+export * from "./rewardPool";
 export * from "./settings";
 export * from "./userPermissions";
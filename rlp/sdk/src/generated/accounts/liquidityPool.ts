@@ -7,6 +7,8 @@
  */
 
 import {
+  addDecoderSizePrefix,
+  addEncoderSizePrefix,
   assertAccountExists,
   assertAccountsExist,
   combineCodec,
@@ -17,12 +19,20 @@ import {
   fixEncoderSize,
   getAddressDecoder,
   getAddressEncoder,
+  getArrayDecoder,
+  getArrayEncoder,
   getBytesDecoder,
   getBytesEncoder,
   getOptionDecoder,
   getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
+  getU128Decoder,
+  getU128Encoder,
+  getU16Decoder,
+  getU16Encoder,
+  getU32Decoder,
+  getU32Encoder,
   getU64Decoder,
   getU64Encoder,
   getU8Decoder,
@@ -42,6 +52,16 @@ import {
   type OptionOrNullable,
   type ReadonlyUint8Array,
 } from "@solana/kit";
+import {
+  getAssetLimitsDecoder,
+  getAssetLimitsEncoder,
+  getPairFeeDecoder,
+  getPairFeeEncoder,
+  type AssetLimits,
+  type AssetLimitsArgs,
+  type PairFee,
+  type PairFeeArgs,
+} from "../types";
 
 export const LIQUIDITY_POOL_DISCRIMINATOR = new Uint8Array([
   66, 38, 17, 64, 188, 80, 68, 129,
@@ -53,6 +73,10 @@ export function getLiquidityPoolDiscriminatorBytes() {
   );
 }
 
+/**
+ * Sized for its current assets, see `LiquidityPool::space`. Adding or removing an asset
+ * reallocs the account.
+ */
 export type LiquidityPool = {
   discriminator: ReadonlyUint8Array;
   bump: number;
@@ -60,9 +84,25 @@ export type LiquidityPool = {
   lpToken: Address;
   cooldowns: bigint;
   cooldownDuration: bigint;
+  /** Maximum LP token supply. */
   depositCap: Option<bigint>;
   assetCount: number;
   assets: ReadonlyUint8Array;
+  /** Protocol fees accrued in the pool reserves, in the same order as `assets`. */
+  protocolFees: Array<bigint>;
+  /** Pool-wide swap fee, falls back to `Settings::swap_fee_bps` when unset. */
+  swapFeeBps: Option<number>;
+  pairFees: Array<Option<PairFee>>;
+  /** Target share of the pool value per asset, in the same order as `assets`. All zero disables weights. */
+  targetWeightsBps: Array<number>;
+  /** Fee surcharge (or discount) per bps the swap moves the pool away from (or toward) its targets, in bps. */
+  imbalanceFeeBps: number;
+  /** Swaps can't push an asset further than this from its target weight. Zero disables the limit. */
+  maxWeightDeviationBps: number;
+  /** Inflow limits per asset, in the same order as `assets`. */
+  assetLimits: Array<AssetLimits>;
+  /** Maximum pool value after a deposit, at `PRECISION` decimals like `calculate_total_pool_value`. */
+  depositValueCap: Option<bigint>;
 };
 
 export type LiquidityPoolArgs = {
@@ -71,9 +111,25 @@ export type LiquidityPoolArgs = {
   lpToken: Address;
   cooldowns: number | bigint;
   cooldownDuration: number | bigint;
+  /** Maximum LP token supply. */
   depositCap: OptionOrNullable<number | bigint>;
   assetCount: number;
   assets: ReadonlyUint8Array;
+  /** Protocol fees accrued in the pool reserves, in the same order as `assets`. */
+  protocolFees: Array<number | bigint>;
+  /** Pool-wide swap fee, falls back to `Settings::swap_fee_bps` when unset. */
+  swapFeeBps: OptionOrNullable<number>;
+  pairFees: Array<OptionOrNullable<PairFeeArgs>>;
+  /** Target share of the pool value per asset, in the same order as `assets`. All zero disables weights. */
+  targetWeightsBps: Array<number>;
+  /** Fee surcharge (or discount) per bps the swap moves the pool away from (or toward) its targets, in bps. */
+  imbalanceFeeBps: number;
+  /** Swaps can't push an asset further than this from its target weight. Zero disables the limit. */
+  maxWeightDeviationBps: number;
+  /** Inflow limits per asset, in the same order as `assets`. */
+  assetLimits: Array<AssetLimitsArgs>;
+  /** Maximum pool value after a deposit, at `PRECISION` decimals like `calculate_total_pool_value`. */
+  depositValueCap: OptionOrNullable<number | bigint>;
 };
 
 /** Gets the encoder for {@link LiquidityPoolArgs} account data. */
@@ -88,7 +144,18 @@ export function getLiquidityPoolEncoder(): Encoder<LiquidityPoolArgs> {
       ["cooldownDuration", getU64Encoder()],
       ["depositCap", getOptionEncoder(getU64Encoder())],
       ["assetCount", getU8Encoder()],
-      ["assets", fixEncoderSize(getBytesEncoder(), 4)],
+      ["assets", addEncoderSizePrefix(getBytesEncoder(), getU32Encoder())],
+      ["protocolFees", getArrayEncoder(getU64Encoder())],
+      ["swapFeeBps", getOptionEncoder(getU16Encoder())],
+      [
+        "pairFees",
+        getArrayEncoder(getOptionEncoder(getPairFeeEncoder()), { size: 6 }),
+      ],
+      ["targetWeightsBps", getArrayEncoder(getU16Encoder())],
+      ["imbalanceFeeBps", getU16Encoder()],
+      ["maxWeightDeviationBps", getU16Encoder()],
+      ["assetLimits", getArrayEncoder(getAssetLimitsEncoder())],
+      ["depositValueCap", getOptionEncoder(getU128Encoder())],
     ]),
     (value) => ({ ...value, discriminator: LIQUIDITY_POOL_DISCRIMINATOR }),
   );
@@ -105,7 +172,18 @@ export function getLiquidityPoolDecoder(): Decoder<LiquidityPool> {
     ["cooldownDuration", getU64Decoder()],
     ["depositCap", getOptionDecoder(getU64Decoder())],
     ["assetCount", getU8Decoder()],
-    ["assets", fixDecoderSize(getBytesDecoder(), 4)],
+    ["assets", addDecoderSizePrefix(getBytesDecoder(), getU32Decoder())],
+    ["protocolFees", getArrayDecoder(getU64Decoder())],
+    ["swapFeeBps", getOptionDecoder(getU16Decoder())],
+    [
+      "pairFees",
+      getArrayDecoder(getOptionDecoder(getPairFeeDecoder()), { size: 6 }),
+    ],
+    ["targetWeightsBps", getArrayDecoder(getU16Decoder())],
+    ["imbalanceFeeBps", getU16Decoder()],
+    ["maxWeightDeviationBps", getU16Decoder()],
+    ["assetLimits", getArrayDecoder(getAssetLimitsDecoder())],
+    ["depositValueCap", getOptionDecoder(getU128Decoder())],
   ]);
 }
 
@@ -15,6 +15,8 @@ import {
   fetchEncodedAccounts,
   fixDecoderSize,
   fixEncoderSize,
+  getAddressDecoder,
+  getAddressEncoder,
   getBytesDecoder,
   getBytesEncoder,
   getStructDecoder,
@@ -58,6 +60,10 @@ export type Settings = {
   assets: number;
   accessControl: AccessControl;
   swapFeeBps: number;
+  /** Share of the swap fee (in bps of the fee) accrued to the protocol instead of LPs. */
+  protocolFeeBps: number;
+  /** Owner of the token accounts protocol fees are claimed to. */
+  treasury: Address;
 };
 
 export type SettingsArgs = {
@@ -66,6 +72,10 @@ export type SettingsArgs = {
   assets: number;
   accessControl: AccessControlArgs;
   swapFeeBps: number;
+  /** Share of the swap fee (in bps of the fee) accrued to the protocol instead of LPs. */
+  protocolFeeBps: number;
+  /** Owner of the token accounts protocol fees are claimed to. */
+  treasury: Address;
 };
 
 /** Gets the encoder for {@link SettingsArgs} account data. */
@@ -78,6 +88,8 @@ export function getSettingsEncoder(): FixedSizeEncoder<SettingsArgs> {
       ["assets", getU8Encoder()],
       ["accessControl", getAccessControlEncoder()],
       ["swapFeeBps", getU16Encoder()],
+      ["protocolFeeBps", getU16Encoder()],
+      ["treasury", getAddressEncoder()],
     ]),
     (value) => ({ ...value, discriminator: SETTINGS_DISCRIMINATOR }),
   );
@@ -92,6 +104,8 @@ export function getSettingsDecoder(): FixedSizeDecoder<Settings> {
     ["assets", getU8Decoder()],
     ["accessControl", getAccessControlDecoder()],
     ["swapFeeBps", getU16Decoder()],
+    ["protocolFeeBps", getU16Decoder()],
+    ["treasury", getAddressDecoder()],
   ]);
 }
 
@@ -154,5 +168,5 @@ export async function fetchAllMaybeSettings(
 }
 
 export function getSettingsSize(): number {
-  return 376;
+  return 412;
 }
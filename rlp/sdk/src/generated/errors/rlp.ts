@@ -112,6 +112,26 @@ export const RLP_ERROR__ALREADY_UNFROZEN = 0x179e; // 6046
 export const RLP_ERROR__SLASH_AMOUNT_EXCEEDS_LIMIT = 0x179f; // 6047
 /** OracleDataTooStale: OracleDataTooStale */
 export const RLP_ERROR__ORACLE_DATA_TOO_STALE = 0x17a0; // 6048
+/** OracleConfidenceTooWide: OracleConfidenceTooWide */
+export const RLP_ERROR__ORACLE_CONFIDENCE_TOO_WIDE = 0x17a1; // 6049
+/** OracleDepegged: OracleDepegged */
+export const RLP_ERROR__ORACLE_DEPEGGED = 0x17a2; // 6050
+/** OracleDeviationTooLarge: OracleDeviationTooLarge */
+export const RLP_ERROR__ORACLE_DEVIATION_TOO_LARGE = 0x17a3; // 6051
+/** TooManyOracles: TooManyOracles */
+export const RLP_ERROR__TOO_MANY_ORACLES = 0x17a4; // 6052
+/** RewardScheduleFull: RewardScheduleFull */
+export const RLP_ERROR__REWARD_SCHEDULE_FULL = 0x17a5; // 6053
+/** PairFeesFull: PairFeesFull */
+export const RLP_ERROR__PAIR_FEES_FULL = 0x17a6; // 6054
+/** PoolAssetsFull: PoolAssetsFull */
+export const RLP_ERROR__POOL_ASSETS_FULL = 0x17a7; // 6055
+/** ReserveNotEmpty: ReserveNotEmpty */
+export const RLP_ERROR__RESERVE_NOT_EMPTY = 0x17a8; // 6056
+/** AssetPaused: AssetPaused */
+export const RLP_ERROR__ASSET_PAUSED = 0x17a9; // 6057
+/** AssetCapExceeded: AssetCapExceeded */
+export const RLP_ERROR__ASSET_CAP_EXCEEDED = 0x17aa; // 6058
 
 export type RlpError =
   | typeof RLP_ERROR__ACTION_FROZEN
@@ -120,7 +140,9 @@ export type RlpError =
   | typeof RLP_ERROR__ALL_DEPOSITS_SLASHED
   | typeof RLP_ERROR__ALREADY_FROZEN
   | typeof RLP_ERROR__ALREADY_UNFROZEN
+  | typeof RLP_ERROR__ASSET_CAP_EXCEEDED
   | typeof RLP_ERROR__ASSET_NOT_WHITELISTED
+  | typeof RLP_ERROR__ASSET_PAUSED
   | typeof RLP_ERROR__BOOST_NOT_APPLIED
   | typeof RLP_ERROR__COLD_WALLET_NOT_SLASHED
   | typeof RLP_ERROR__COOLDOWN_IN_FORCE
@@ -150,16 +172,24 @@ export type RlpError =
   | typeof RLP_ERROR__NOT_ENOUGH_FUNDS
   | typeof RLP_ERROR__NOT_ENOUGH_FUNDS_TO_SLASH
   | typeof RLP_ERROR__NOT_ENOUGH_RECEIPT_TOKENS
+  | typeof RLP_ERROR__ORACLE_CONFIDENCE_TOO_WIDE
   | typeof RLP_ERROR__ORACLE_DATA_TOO_STALE
+  | typeof RLP_ERROR__ORACLE_DEPEGGED
+  | typeof RLP_ERROR__ORACLE_DEVIATION_TOO_LARGE
+  | typeof RLP_ERROR__PAIR_FEES_FULL
   | typeof RLP_ERROR__PERMISSIONS_TOO_LOW
+  | typeof RLP_ERROR__POOL_ASSETS_FULL
   | typeof RLP_ERROR__POOL_IMBALANCE
   | typeof RLP_ERROR__PRICE_ERROR
+  | typeof RLP_ERROR__RESERVE_NOT_EMPTY
+  | typeof RLP_ERROR__REWARD_SCHEDULE_FULL
   | typeof RLP_ERROR__ROLE_NOT_UNDER_ACTION
   | typeof RLP_ERROR__SAME_ADMIN
   | typeof RLP_ERROR__SHARE_CONFIG_OVERFLOW
   | typeof RLP_ERROR__SLASH_AMOUNT_EXCEEDS_LIMIT
   | typeof RLP_ERROR__SLASH_AMOUNT_MISMATCH
   | typeof RLP_ERROR__SLIPPAGE_EXCEEDED
+  | typeof RLP_ERROR__TOO_MANY_ORACLES
   | typeof RLP_ERROR__TRANSFER_SIGNATURE_REQUIRED
   | typeof RLP_ERROR__WITHDRAWAL_NEEDS_INTENT
   | typeof RLP_ERROR__WITHDRAWAL_THRESHOLD_OVERFLOW;
@@ -173,7 +203,9 @@ if (process.env.NODE_ENV !== "production") {
     [RLP_ERROR__ALL_DEPOSITS_SLASHED]: `AllDepositsSlashed`,
     [RLP_ERROR__ALREADY_FROZEN]: `AlreadyFrozen`,
     [RLP_ERROR__ALREADY_UNFROZEN]: `AlreadyUnfrozen`,
+    [RLP_ERROR__ASSET_CAP_EXCEEDED]: `AssetCapExceeded`,
     [RLP_ERROR__ASSET_NOT_WHITELISTED]: `AssetNotWhitelisted`,
+    [RLP_ERROR__ASSET_PAUSED]: `AssetPaused`,
     [RLP_ERROR__BOOST_NOT_APPLIED]: `BoostNotApplied`,
     [RLP_ERROR__COLD_WALLET_NOT_SLASHED]: `ColdWalletNotSlashed`,
     [RLP_ERROR__COOLDOWN_IN_FORCE]: `CooldownInForce`,
@@ -203,16 +235,24 @@ if (process.env.NODE_ENV !== "production") {
     [RLP_ERROR__NOT_ENOUGH_FUNDS]: `NotEnoughFunds`,
     [RLP_ERROR__NOT_ENOUGH_FUNDS_TO_SLASH]: `NotEnoughFundsToSlash`,
     [RLP_ERROR__NOT_ENOUGH_RECEIPT_TOKENS]: `NotEnoughReceiptTokens`,
+    [RLP_ERROR__ORACLE_CONFIDENCE_TOO_WIDE]: `OracleConfidenceTooWide`,
     [RLP_ERROR__ORACLE_DATA_TOO_STALE]: `OracleDataTooStale`,
+    [RLP_ERROR__ORACLE_DEPEGGED]: `OracleDepegged`,
+    [RLP_ERROR__ORACLE_DEVIATION_TOO_LARGE]: `OracleDeviationTooLarge`,
+    [RLP_ERROR__PAIR_FEES_FULL]: `PairFeesFull`,
     [RLP_ERROR__PERMISSIONS_TOO_LOW]: `PermissionsTooLow`,
+    [RLP_ERROR__POOL_ASSETS_FULL]: `PoolAssetsFull`,
     [RLP_ERROR__POOL_IMBALANCE]: `PoolImbalance`,
     [RLP_ERROR__PRICE_ERROR]: `PriceError`,
+    [RLP_ERROR__RESERVE_NOT_EMPTY]: `ReserveNotEmpty`,
+    [RLP_ERROR__REWARD_SCHEDULE_FULL]: `RewardScheduleFull`,
     [RLP_ERROR__ROLE_NOT_UNDER_ACTION]: `RoleNotUnderAction`,
     [RLP_ERROR__SAME_ADMIN]: `SameAdmin`,
     [RLP_ERROR__SHARE_CONFIG_OVERFLOW]: `ShareConfigOverflow`,
     [RLP_ERROR__SLASH_AMOUNT_EXCEEDS_LIMIT]: `SlashAmountExceedsLimit`,
     [RLP_ERROR__SLASH_AMOUNT_MISMATCH]: `SlashAmountMismatch`,
     [RLP_ERROR__SLIPPAGE_EXCEEDED]: `SlippageExceeded`,
+    [RLP_ERROR__TOO_MANY_ORACLES]: `TooManyOracles`,
     [RLP_ERROR__TRANSFER_SIGNATURE_REQUIRED]: `TransferSignatureRequired`,
     [RLP_ERROR__WITHDRAWAL_NEEDS_INTENT]: `WithdrawalNeedsIntent`,
     [RLP_ERROR__WITHDRAWAL_THRESHOLD_OVERFLOW]: `WithdrawalThresholdOverflow`,
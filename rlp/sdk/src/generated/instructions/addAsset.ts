@@ -13,19 +13,27 @@ import {
   getAddressEncoder,
   getBytesDecoder,
   getBytesEncoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getProgramDerivedAddress,
   getStructDecoder,
   getStructEncoder,
+  getU16Decoder,
+  getU16Encoder,
+  getU64Decoder,
+  getU64Encoder,
   transformEncoder,
   type AccountMeta,
   type AccountSignerMeta,
   type Address,
-  type FixedSizeCodec,
-  type FixedSizeDecoder,
-  type FixedSizeEncoder,
+  type Codec,
+  type Decoder,
+  type Encoder,
   type Instruction,
   type InstructionWithAccounts,
   type InstructionWithData,
+  type Option,
+  type OptionOrNullable,
   type ReadonlyAccount,
   type ReadonlyUint8Array,
   type TransactionSigner,
@@ -41,8 +49,12 @@ import {
 import {
   getAccessLevelDecoder,
   getAccessLevelEncoder,
+  getFixedPriceDecoder,
+  getFixedPriceEncoder,
   type AccessLevel,
   type AccessLevelArgs,
+  type FixedPrice,
+  type FixedPriceArgs,
 } from "../types";
 
 export const ADD_ASSET_DISCRIMINATOR = new Uint8Array([
@@ -97,28 +109,42 @@ export type AddAssetInstruction<
 export type AddAssetInstructionData = {
   discriminator: ReadonlyUint8Array;
   accessLevel: AccessLevel;
+  maxConfidenceBps: number;
+  oracleMaxAge: Option<bigint>;
+  fixedPrice: Option<FixedPrice>;
 };
 
-export type AddAssetInstructionDataArgs = { accessLevel: AccessLevelArgs };
+export type AddAssetInstructionDataArgs = {
+  accessLevel: AccessLevelArgs;
+  maxConfidenceBps: number;
+  oracleMaxAge: OptionOrNullable<number | bigint>;
+  fixedPrice: OptionOrNullable<FixedPriceArgs>;
+};
 
-export function getAddAssetInstructionDataEncoder(): FixedSizeEncoder<AddAssetInstructionDataArgs> {
+export function getAddAssetInstructionDataEncoder(): Encoder<AddAssetInstructionDataArgs> {
   return transformEncoder(
     getStructEncoder([
       ["discriminator", fixEncoderSize(getBytesEncoder(), 8)],
       ["accessLevel", getAccessLevelEncoder()],
+      ["maxConfidenceBps", getU16Encoder()],
+      ["oracleMaxAge", getOptionEncoder(getU64Encoder())],
+      ["fixedPrice", getOptionEncoder(getFixedPriceEncoder())],
     ]),
     (value) => ({ ...value, discriminator: ADD_ASSET_DISCRIMINATOR }),
   );
 }
 
-export function getAddAssetInstructionDataDecoder(): FixedSizeDecoder<AddAssetInstructionData> {
+export function getAddAssetInstructionDataDecoder(): Decoder<AddAssetInstructionData> {
   return getStructDecoder([
     ["discriminator", fixDecoderSize(getBytesDecoder(), 8)],
     ["accessLevel", getAccessLevelDecoder()],
+    ["maxConfidenceBps", getU16Decoder()],
+    ["oracleMaxAge", getOptionDecoder(getU64Decoder())],
+    ["fixedPrice", getOptionDecoder(getFixedPriceDecoder())],
   ]);
 }
 
-export function getAddAssetInstructionDataCodec(): FixedSizeCodec<
+export function getAddAssetInstructionDataCodec(): Codec<
   AddAssetInstructionDataArgs,
   AddAssetInstructionData
 > {
@@ -142,9 +168,12 @@ export type AddAssetAsyncInput<
   settings?: Address<TAccountSettings>;
   asset?: Address<TAccountAsset>;
   assetMint: Address<TAccountAssetMint>;
-  oracle: Address<TAccountOracle>;
+  oracle?: Address<TAccountOracle>;
   systemProgram?: Address<TAccountSystemProgram>;
   accessLevel: AddAssetInstructionDataArgs["accessLevel"];
+  maxConfidenceBps: AddAssetInstructionDataArgs["maxConfidenceBps"];
+  oracleMaxAge: AddAssetInstructionDataArgs["oracleMaxAge"];
+  fixedPrice: AddAssetInstructionDataArgs["fixedPrice"];
 };
 
 export async function getAddAssetInstructionAsync<
@@ -279,9 +308,12 @@ export type AddAssetInput<
   settings: Address<TAccountSettings>;
   asset: Address<TAccountAsset>;
   assetMint: Address<TAccountAssetMint>;
-  oracle: Address<TAccountOracle>;
+  oracle?: Address<TAccountOracle>;
   systemProgram?: Address<TAccountSystemProgram>;
   accessLevel: AddAssetInstructionDataArgs["accessLevel"];
+  maxConfidenceBps: AddAssetInstructionDataArgs["maxConfidenceBps"];
+  oracleMaxAge: AddAssetInstructionDataArgs["oracleMaxAge"];
+  fixedPrice: AddAssetInstructionDataArgs["fixedPrice"];
 };
 
 export function getAddAssetInstruction<
@@ -379,7 +411,7 @@ export type ParsedAddAssetInstruction<
     settings: TAccountMetas[2];
     asset: TAccountMetas[3];
     assetMint: TAccountMetas[4];
-    oracle: TAccountMetas[5];
+    oracle?: TAccountMetas[5] | undefined;
     systemProgram: TAccountMetas[6];
   };
   data: AddAssetInstructionData;
@@ -403,6 +435,12 @@ export function parseAddAssetInstruction<
     accountIndex += 1;
     return accountMeta;
   };
+  const getNextOptionalAccount = () => {
+    const accountMeta = getNextAccount();
+    return accountMeta.address === RLP_PROGRAM_ADDRESS
+      ? undefined
+      : accountMeta;
+  };
   return {
     programAddress: instruction.programAddress,
     accounts: {
@@ -411,7 +449,7 @@ export function parseAddAssetInstruction<
       settings: getNextAccount(),
       asset: getNextAccount(),
       assetMint: getNextAccount(),
-      oracle: getNextAccount(),
+      oracle: getNextOptionalAccount(),
       systemProgram: getNextAccount(),
     },
     data: getAddAssetInstructionDataDecoder().decode(instruction.data),
@@ -57,6 +57,7 @@ export type DepositInstruction<
   TAccountSettings extends string | AccountMeta<string> = string,
   TAccountPermissions extends string | AccountMeta<string> = string,
   TAccountLiquidityPool extends string | AccountMeta<string> = string,
+  TAccountRewardPool extends string | AccountMeta<string> = string,
   TAccountLpToken extends string | AccountMeta<string> = string,
   TAccountUserLpAccount extends string | AccountMeta<string> = string,
   TAccountAsset extends string | AccountMeta<string> = string,
@@ -88,6 +89,9 @@ export type DepositInstruction<
       TAccountLiquidityPool extends string
         ? ReadonlyAccount<TAccountLiquidityPool>
         : TAccountLiquidityPool,
+      TAccountRewardPool extends string
+        ? ReadonlyAccount<TAccountRewardPool>
+        : TAccountRewardPool,
       TAccountLpToken extends string
         ? WritableAccount<TAccountLpToken>
         : TAccountLpToken,
@@ -171,6 +175,7 @@ export type DepositAsyncInput<
   TAccountSettings extends string = string,
   TAccountPermissions extends string = string,
   TAccountLiquidityPool extends string = string,
+  TAccountRewardPool extends string = string,
   TAccountLpToken extends string = string,
   TAccountUserLpAccount extends string = string,
   TAccountAsset extends string = string,
@@ -186,13 +191,14 @@ export type DepositAsyncInput<
   settings?: Address<TAccountSettings>;
   permissions?: Address<TAccountPermissions>;
   liquidityPool: Address<TAccountLiquidityPool>;
+  rewardPool: Address<TAccountRewardPool>;
   lpToken: Address<TAccountLpToken>;
   userLpAccount?: Address<TAccountUserLpAccount>;
   asset?: Address<TAccountAsset>;
   assetMint: Address<TAccountAssetMint>;
   userAssetAccount: Address<TAccountUserAssetAccount>;
   poolAssetAccount?: Address<TAccountPoolAssetAccount>;
-  oracle: Address<TAccountOracle>;
+  oracle?: Address<TAccountOracle>;
   tokenProgram?: Address<TAccountTokenProgram>;
   associatedTokenProgram?: Address<TAccountAssociatedTokenProgram>;
   systemProgram?: Address<TAccountSystemProgram>;
@@ -206,6 +212,7 @@ export async function getDepositInstructionAsync<
   TAccountSettings extends string,
   TAccountPermissions extends string,
   TAccountLiquidityPool extends string,
+  TAccountRewardPool extends string,
   TAccountLpToken extends string,
   TAccountUserLpAccount extends string,
   TAccountAsset extends string,
@@ -223,6 +230,7 @@ export async function getDepositInstructionAsync<
     TAccountSettings,
     TAccountPermissions,
     TAccountLiquidityPool,
+    TAccountRewardPool,
     TAccountLpToken,
     TAccountUserLpAccount,
     TAccountAsset,
@@ -242,6 +250,7 @@ export async function getDepositInstructionAsync<
     TAccountSettings,
     TAccountPermissions,
     TAccountLiquidityPool,
+    TAccountRewardPool,
     TAccountLpToken,
     TAccountUserLpAccount,
     TAccountAsset,
@@ -263,6 +272,7 @@ export async function getDepositInstructionAsync<
     settings: { value: input.settings ?? null, isWritable: false },
     permissions: { value: input.permissions ?? null, isWritable: false },
     liquidityPool: { value: input.liquidityPool ?? null, isWritable: false },
+    rewardPool: { value: input.rewardPool ?? null, isWritable: false },
     lpToken: { value: input.lpToken ?? null, isWritable: true },
     userLpAccount: { value: input.userLpAccount ?? null, isWritable: true },
     asset: { value: input.asset ?? null, isWritable: false },
@@ -378,6 +388,7 @@ export async function getDepositInstructionAsync<
       getAccountMeta(accounts.settings),
       getAccountMeta(accounts.permissions),
       getAccountMeta(accounts.liquidityPool),
+      getAccountMeta(accounts.rewardPool),
       getAccountMeta(accounts.lpToken),
       getAccountMeta(accounts.userLpAccount),
       getAccountMeta(accounts.asset),
@@ -399,6 +410,7 @@ export async function getDepositInstructionAsync<
     TAccountSettings,
     TAccountPermissions,
     TAccountLiquidityPool,
+    TAccountRewardPool,
     TAccountLpToken,
     TAccountUserLpAccount,
     TAccountAsset,
@@ -417,6 +429,7 @@ export type DepositInput<
   TAccountSettings extends string = string,
   TAccountPermissions extends string = string,
   TAccountLiquidityPool extends string = string,
+  TAccountRewardPool extends string = string,
   TAccountLpToken extends string = string,
   TAccountUserLpAccount extends string = string,
   TAccountAsset extends string = string,
@@ -432,13 +445,14 @@ export type DepositInput<
   settings: Address<TAccountSettings>;
   permissions?: Address<TAccountPermissions>;
   liquidityPool: Address<TAccountLiquidityPool>;
+  rewardPool: Address<TAccountRewardPool>;
   lpToken: Address<TAccountLpToken>;
   userLpAccount: Address<TAccountUserLpAccount>;
   asset: Address<TAccountAsset>;
   assetMint: Address<TAccountAssetMint>;
   userAssetAccount: Address<TAccountUserAssetAccount>;
   poolAssetAccount: Address<TAccountPoolAssetAccount>;
-  oracle: Address<TAccountOracle>;
+  oracle?: Address<TAccountOracle>;
   tokenProgram?: Address<TAccountTokenProgram>;
   associatedTokenProgram?: Address<TAccountAssociatedTokenProgram>;
   systemProgram?: Address<TAccountSystemProgram>;
@@ -452,6 +466,7 @@ export function getDepositInstruction<
   TAccountSettings extends string,
   TAccountPermissions extends string,
   TAccountLiquidityPool extends string,
+  TAccountRewardPool extends string,
   TAccountLpToken extends string,
   TAccountUserLpAccount extends string,
   TAccountAsset extends string,
@@ -469,6 +484,7 @@ export function getDepositInstruction<
     TAccountSettings,
     TAccountPermissions,
     TAccountLiquidityPool,
+    TAccountRewardPool,
     TAccountLpToken,
     TAccountUserLpAccount,
     TAccountAsset,
@@ -487,6 +503,7 @@ export function getDepositInstruction<
   TAccountSettings,
   TAccountPermissions,
   TAccountLiquidityPool,
+  TAccountRewardPool,
   TAccountLpToken,
   TAccountUserLpAccount,
   TAccountAsset,
@@ -507,6 +524,7 @@ export function getDepositInstruction<
     settings: { value: input.settings ?? null, isWritable: false },
     permissions: { value: input.permissions ?? null, isWritable: false },
     liquidityPool: { value: input.liquidityPool ?? null, isWritable: false },
+    rewardPool: { value: input.rewardPool ?? null, isWritable: false },
     lpToken: { value: input.lpToken ?? null, isWritable: true },
     userLpAccount: { value: input.userLpAccount ?? null, isWritable: true },
     asset: { value: input.asset ?? null, isWritable: false },
@@ -556,6 +574,7 @@ export function getDepositInstruction<
       getAccountMeta(accounts.settings),
       getAccountMeta(accounts.permissions),
       getAccountMeta(accounts.liquidityPool),
+      getAccountMeta(accounts.rewardPool),
       getAccountMeta(accounts.lpToken),
       getAccountMeta(accounts.userLpAccount),
       getAccountMeta(accounts.asset),
@@ -577,6 +596,7 @@ export function getDepositInstruction<
     TAccountSettings,
     TAccountPermissions,
     TAccountLiquidityPool,
+    TAccountRewardPool,
     TAccountLpToken,
     TAccountUserLpAccount,
     TAccountAsset,
@@ -600,16 +620,17 @@ export type ParsedDepositInstruction<
     settings: TAccountMetas[1];
     permissions?: TAccountMetas[2] | undefined;
     liquidityPool: TAccountMetas[3];
-    lpToken: TAccountMetas[4];
-    userLpAccount: TAccountMetas[5];
-    asset: TAccountMetas[6];
-    assetMint: TAccountMetas[7];
-    userAssetAccount: TAccountMetas[8];
-    poolAssetAccount: TAccountMetas[9];
-    oracle: TAccountMetas[10];
-    tokenProgram: TAccountMetas[11];
-    associatedTokenProgram: TAccountMetas[12];
-    systemProgram: TAccountMetas[13];
+    rewardPool: TAccountMetas[4];
+    lpToken: TAccountMetas[5];
+    userLpAccount: TAccountMetas[6];
+    asset: TAccountMetas[7];
+    assetMint: TAccountMetas[8];
+    userAssetAccount: TAccountMetas[9];
+    poolAssetAccount: TAccountMetas[10];
+    oracle?: TAccountMetas[11] | undefined;
+    tokenProgram: TAccountMetas[12];
+    associatedTokenProgram: TAccountMetas[13];
+    systemProgram: TAccountMetas[14];
   };
   data: DepositInstructionData;
 };
@@ -622,7 +643,7 @@ export function parseDepositInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>,
 ): ParsedDepositInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 14) {
+  if (instruction.accounts.length < 15) {
     // TODO: Coded error.
     throw new Error("Not enough accounts");
   }
@@ -645,13 +666,14 @@ export function parseDepositInstruction<
       settings: getNextAccount(),
       permissions: getNextOptionalAccount(),
       liquidityPool: getNextAccount(),
+      rewardPool: getNextAccount(),
       lpToken: getNextAccount(),
       userLpAccount: getNextAccount(),
       asset: getNextAccount(),
       assetMint: getNextAccount(),
       userAssetAccount: getNextAccount(),
       poolAssetAccount: getNextAccount(),
-      oracle: getNextAccount(),
+      oracle: getNextOptionalAccount(),
       tokenProgram: getNextAccount(),
       associatedTokenProgram: getNextAccount(),
       systemProgram: getNextAccount(),
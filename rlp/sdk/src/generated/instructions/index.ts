@@ -7,15 +7,45 @@
  */
 
 export * from "./addAsset";
+export * from "./addAssetOracle";
+export * from "./addPoolAsset";
+export * from "./cancelWithdrawalRequest";
+export * from "./claimProtocolFees";
 export * from "./createPermissionAccount";
 export * from "./deposit";
+export * from "./depositProportional";
+export * from "./depositRewards";
 export * from "./freezeFunctionality";
 export * from "./initializeLp";
+export * from "./initializeRewardPool";
 export * from "./initializeRlp";
+export * from "./mergeCooldowns";
+export * from "./migrateAsset";
+export * from "./migrateLiquidityPool";
+export * from "./migrateSettings";
+export * from "./quoteDeposit";
+export * from "./quoteSwap";
+export * from "./quoteWithdraw";
+export * from "./removeAssetOracle";
+export * from "./removePoolAsset";
 export * from "./requestWithdrawal";
 export * from "./slash";
 export * from "./swap";
+export * from "./swapExactOut";
+export * from "./swapRoute";
 export * from "./updateActionRole";
+export * from "./updateAsset";
+export * from "./updateAssetLimits";
+export * from "./updateCooldownDuration";
 export * from "./updateDepositCap";
+export * from "./updateDepositValueCap";
+export * from "./updateOracleMaxAge";
+export * from "./updatePoolSwapFee";
+export * from "./updateProtocolFee";
 export * from "./updateRoleHolder";
+export * from "./updateSwapFee";
+export * from "./updateTargetWeights";
+export * from "./updateVestingDuration";
 export * from "./withdraw";
+export * from "./withdrawPartial";
+export * from "./withdrawSingleAsset";
@@ -59,6 +59,7 @@ export type SwapInstruction<
   TAccountAdmin extends string | AccountMeta<string> = string,
   TAccountSettings extends string | AccountMeta<string> = string,
   TAccountLiquidityPool extends string | AccountMeta<string> = string,
+  TAccountRewardPool extends string | AccountMeta<string> = string,
   TAccountTokenFrom extends string | AccountMeta<string> = string,
   TAccountTokenFromAsset extends string | AccountMeta<string> = string,
   TAccountTokenFromOracle extends string | AccountMeta<string> = string,
@@ -89,8 +90,11 @@ export type SwapInstruction<
         ? ReadonlyAccount<TAccountSettings>
         : TAccountSettings,
       TAccountLiquidityPool extends string
-        ? ReadonlyAccount<TAccountLiquidityPool>
+        ? WritableAccount<TAccountLiquidityPool>
         : TAccountLiquidityPool,
+      TAccountRewardPool extends string
+        ? ReadonlyAccount<TAccountRewardPool>
+        : TAccountRewardPool,
       TAccountTokenFrom extends string
         ? ReadonlyAccount<TAccountTokenFrom>
         : TAccountTokenFrom,
@@ -176,6 +180,7 @@ export type SwapAsyncInput<
   TAccountAdmin extends string = string,
   TAccountSettings extends string = string,
   TAccountLiquidityPool extends string = string,
+  TAccountRewardPool extends string = string,
   TAccountTokenFrom extends string = string,
   TAccountTokenFromAsset extends string = string,
   TAccountTokenFromOracle extends string = string,
@@ -193,12 +198,13 @@ export type SwapAsyncInput<
   admin?: Address<TAccountAdmin>;
   settings?: Address<TAccountSettings>;
   liquidityPool: Address<TAccountLiquidityPool>;
+  rewardPool: Address<TAccountRewardPool>;
   tokenFrom: Address<TAccountTokenFrom>;
   tokenFromAsset?: Address<TAccountTokenFromAsset>;
-  tokenFromOracle: Address<TAccountTokenFromOracle>;
+  tokenFromOracle?: Address<TAccountTokenFromOracle>;
   tokenTo: Address<TAccountTokenTo>;
   tokenToAsset?: Address<TAccountTokenToAsset>;
-  tokenToOracle: Address<TAccountTokenToOracle>;
+  tokenToOracle?: Address<TAccountTokenToOracle>;
   tokenFromPool?: Address<TAccountTokenFromPool>;
   tokenToPool?: Address<TAccountTokenToPool>;
   tokenFromSignerAccount: Address<TAccountTokenFromSignerAccount>;
@@ -214,6 +220,7 @@ export async function getSwapInstructionAsync<
   TAccountAdmin extends string,
   TAccountSettings extends string,
   TAccountLiquidityPool extends string,
+  TAccountRewardPool extends string,
   TAccountTokenFrom extends string,
   TAccountTokenFromAsset extends string,
   TAccountTokenFromOracle extends string,
@@ -233,6 +240,7 @@ export async function getSwapInstructionAsync<
     TAccountAdmin,
     TAccountSettings,
     TAccountLiquidityPool,
+    TAccountRewardPool,
     TAccountTokenFrom,
     TAccountTokenFromAsset,
     TAccountTokenFromOracle,
@@ -254,6 +262,7 @@ export async function getSwapInstructionAsync<
     TAccountAdmin,
     TAccountSettings,
     TAccountLiquidityPool,
+    TAccountRewardPool,
     TAccountTokenFrom,
     TAccountTokenFromAsset,
     TAccountTokenFromOracle,
@@ -276,7 +285,8 @@ export async function getSwapInstructionAsync<
     signer: { value: input.signer ?? null, isWritable: true },
     admin: { value: input.admin ?? null, isWritable: false },
     settings: { value: input.settings ?? null, isWritable: false },
-    liquidityPool: { value: input.liquidityPool ?? null, isWritable: false },
+    liquidityPool: { value: input.liquidityPool ?? null, isWritable: true },
+    rewardPool: { value: input.rewardPool ?? null, isWritable: false },
     tokenFrom: { value: input.tokenFrom ?? null, isWritable: false },
     tokenFromAsset: { value: input.tokenFromAsset ?? null, isWritable: false },
     tokenFromOracle: {
@@ -402,6 +412,7 @@ export async function getSwapInstructionAsync<
       getAccountMeta(accounts.admin),
       getAccountMeta(accounts.settings),
       getAccountMeta(accounts.liquidityPool),
+      getAccountMeta(accounts.rewardPool),
       getAccountMeta(accounts.tokenFrom),
       getAccountMeta(accounts.tokenFromAsset),
       getAccountMeta(accounts.tokenFromOracle),
@@ -425,6 +436,7 @@ export async function getSwapInstructionAsync<
     TAccountAdmin,
     TAccountSettings,
     TAccountLiquidityPool,
+    TAccountRewardPool,
     TAccountTokenFrom,
     TAccountTokenFromAsset,
     TAccountTokenFromOracle,
@@ -445,6 +457,7 @@ export type SwapInput<
   TAccountAdmin extends string = string,
   TAccountSettings extends string = string,
   TAccountLiquidityPool extends string = string,
+  TAccountRewardPool extends string = string,
   TAccountTokenFrom extends string = string,
   TAccountTokenFromAsset extends string = string,
   TAccountTokenFromOracle extends string = string,
@@ -462,12 +475,13 @@ export type SwapInput<
   admin?: Address<TAccountAdmin>;
   settings: Address<TAccountSettings>;
   liquidityPool: Address<TAccountLiquidityPool>;
+  rewardPool: Address<TAccountRewardPool>;
   tokenFrom: Address<TAccountTokenFrom>;
   tokenFromAsset: Address<TAccountTokenFromAsset>;
-  tokenFromOracle: Address<TAccountTokenFromOracle>;
+  tokenFromOracle?: Address<TAccountTokenFromOracle>;
   tokenTo: Address<TAccountTokenTo>;
   tokenToAsset: Address<TAccountTokenToAsset>;
-  tokenToOracle: Address<TAccountTokenToOracle>;
+  tokenToOracle?: Address<TAccountTokenToOracle>;
   tokenFromPool: Address<TAccountTokenFromPool>;
   tokenToPool: Address<TAccountTokenToPool>;
   tokenFromSignerAccount: Address<TAccountTokenFromSignerAccount>;
@@ -483,6 +497,7 @@ export function getSwapInstruction<
   TAccountAdmin extends string,
   TAccountSettings extends string,
   TAccountLiquidityPool extends string,
+  TAccountRewardPool extends string,
   TAccountTokenFrom extends string,
   TAccountTokenFromAsset extends string,
   TAccountTokenFromOracle extends string,
@@ -502,6 +517,7 @@ export function getSwapInstruction<
     TAccountAdmin,
     TAccountSettings,
     TAccountLiquidityPool,
+    TAccountRewardPool,
     TAccountTokenFrom,
     TAccountTokenFromAsset,
     TAccountTokenFromOracle,
@@ -522,6 +538,7 @@ export function getSwapInstruction<
   TAccountAdmin,
   TAccountSettings,
   TAccountLiquidityPool,
+  TAccountRewardPool,
   TAccountTokenFrom,
   TAccountTokenFromAsset,
   TAccountTokenFromOracle,
@@ -543,7 +560,8 @@ export function getSwapInstruction<
     signer: { value: input.signer ?? null, isWritable: true },
     admin: { value: input.admin ?? null, isWritable: false },
     settings: { value: input.settings ?? null, isWritable: false },
-    liquidityPool: { value: input.liquidityPool ?? null, isWritable: false },
+    liquidityPool: { value: input.liquidityPool ?? null, isWritable: true },
+    rewardPool: { value: input.rewardPool ?? null, isWritable: false },
     tokenFrom: { value: input.tokenFrom ?? null, isWritable: false },
     tokenFromAsset: { value: input.tokenFromAsset ?? null, isWritable: false },
     tokenFromOracle: {
@@ -594,6 +612,7 @@ export function getSwapInstruction<
       getAccountMeta(accounts.admin),
       getAccountMeta(accounts.settings),
       getAccountMeta(accounts.liquidityPool),
+      getAccountMeta(accounts.rewardPool),
       getAccountMeta(accounts.tokenFrom),
       getAccountMeta(accounts.tokenFromAsset),
       getAccountMeta(accounts.tokenFromOracle),
@@ -617,6 +636,7 @@ export function getSwapInstruction<
     TAccountAdmin,
     TAccountSettings,
     TAccountLiquidityPool,
+    TAccountRewardPool,
     TAccountTokenFrom,
     TAccountTokenFromAsset,
     TAccountTokenFromOracle,
@@ -642,18 +662,19 @@ export type ParsedSwapInstruction<
     admin?: TAccountMetas[1] | undefined;
     settings: TAccountMetas[2];
     liquidityPool: TAccountMetas[3];
-    tokenFrom: TAccountMetas[4];
-    tokenFromAsset: TAccountMetas[5];
-    tokenFromOracle: TAccountMetas[6];
-    tokenTo: TAccountMetas[7];
-    tokenToAsset: TAccountMetas[8];
-    tokenToOracle: TAccountMetas[9];
-    tokenFromPool: TAccountMetas[10];
-    tokenToPool: TAccountMetas[11];
-    tokenFromSignerAccount: TAccountMetas[12];
-    tokenToSignerAccount: TAccountMetas[13];
-    tokenProgram: TAccountMetas[14];
-    associatedTokenProgram: TAccountMetas[15];
+    rewardPool: TAccountMetas[4];
+    tokenFrom: TAccountMetas[5];
+    tokenFromAsset: TAccountMetas[6];
+    tokenFromOracle?: TAccountMetas[7] | undefined;
+    tokenTo: TAccountMetas[8];
+    tokenToAsset: TAccountMetas[9];
+    tokenToOracle?: TAccountMetas[10] | undefined;
+    tokenFromPool: TAccountMetas[11];
+    tokenToPool: TAccountMetas[12];
+    tokenFromSignerAccount: TAccountMetas[13];
+    tokenToSignerAccount: TAccountMetas[14];
+    tokenProgram: TAccountMetas[15];
+    associatedTokenProgram: TAccountMetas[16];
   };
   data: SwapInstructionData;
 };
@@ -666,7 +687,7 @@ export function parseSwapInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>,
 ): ParsedSwapInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 16) {
+  if (instruction.accounts.length < 17) {
     // TODO: Coded error.
     throw new Error("Not enough accounts");
   }
@@ -689,12 +710,13 @@ export function parseSwapInstruction<
       admin: getNextOptionalAccount(),
       settings: getNextAccount(),
       liquidityPool: getNextAccount(),
+      rewardPool: getNextAccount(),
       tokenFrom: getNextAccount(),
       tokenFromAsset: getNextAccount(),
-      tokenFromOracle: getNextAccount(),
+      tokenFromOracle: getNextOptionalAccount(),
       tokenTo: getNextAccount(),
       tokenToAsset: getNextAccount(),
-      tokenToOracle: getNextAccount(),
+      tokenToOracle: getNextOptionalAccount(),
       tokenFromPool: getNextAccount(),
       tokenToPool: getNextAccount(),
       tokenFromSignerAccount: getNextAccount(),
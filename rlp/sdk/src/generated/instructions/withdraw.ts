@@ -57,6 +57,7 @@ export type WithdrawInstruction<
   TAccountSettings extends string | AccountMeta<string> = string,
   TAccountPermissions extends string | AccountMeta<string> = string,
   TAccountLiquidityPool extends string | AccountMeta<string> = string,
+  TAccountRewardPool extends string | AccountMeta<string> = string,
   TAccountLpTokenMint extends string | AccountMeta<string> = string,
   TAccountCooldownLpTokenAccount extends string | AccountMeta<string> = string,
   TAccountCooldown extends string | AccountMeta<string> = string,
@@ -82,6 +83,9 @@ export type WithdrawInstruction<
       TAccountLiquidityPool extends string
         ? WritableAccount<TAccountLiquidityPool>
         : TAccountLiquidityPool,
+      TAccountRewardPool extends string
+        ? ReadonlyAccount<TAccountRewardPool>
+        : TAccountRewardPool,
       TAccountLpTokenMint extends string
         ? WritableAccount<TAccountLpTokenMint>
         : TAccountLpTokenMint,
@@ -146,6 +150,7 @@ export type WithdrawAsyncInput<
   TAccountSettings extends string = string,
   TAccountPermissions extends string = string,
   TAccountLiquidityPool extends string = string,
+  TAccountRewardPool extends string = string,
   TAccountLpTokenMint extends string = string,
   TAccountCooldownLpTokenAccount extends string = string,
   TAccountCooldown extends string = string,
@@ -156,6 +161,7 @@ export type WithdrawAsyncInput<
   settings?: Address<TAccountSettings>;
   permissions?: Address<TAccountPermissions>;
   liquidityPool: Address<TAccountLiquidityPool>;
+  rewardPool: Address<TAccountRewardPool>;
   lpTokenMint: Address<TAccountLpTokenMint>;
   cooldownLpTokenAccount?: Address<TAccountCooldownLpTokenAccount>;
   cooldown: Address<TAccountCooldown>;
@@ -170,6 +176,7 @@ export async function getWithdrawInstructionAsync<
   TAccountSettings extends string,
   TAccountPermissions extends string,
   TAccountLiquidityPool extends string,
+  TAccountRewardPool extends string,
   TAccountLpTokenMint extends string,
   TAccountCooldownLpTokenAccount extends string,
   TAccountCooldown extends string,
@@ -182,6 +189,7 @@ export async function getWithdrawInstructionAsync<
     TAccountSettings,
     TAccountPermissions,
     TAccountLiquidityPool,
+    TAccountRewardPool,
     TAccountLpTokenMint,
     TAccountCooldownLpTokenAccount,
     TAccountCooldown,
@@ -196,6 +204,7 @@ export async function getWithdrawInstructionAsync<
     TAccountSettings,
     TAccountPermissions,
     TAccountLiquidityPool,
+    TAccountRewardPool,
     TAccountLpTokenMint,
     TAccountCooldownLpTokenAccount,
     TAccountCooldown,
@@ -212,6 +221,7 @@ export async function getWithdrawInstructionAsync<
     settings: { value: input.settings ?? null, isWritable: true },
     permissions: { value: input.permissions ?? null, isWritable: false },
     liquidityPool: { value: input.liquidityPool ?? null, isWritable: true },
+    rewardPool: { value: input.rewardPool ?? null, isWritable: false },
     lpTokenMint: { value: input.lpTokenMint ?? null, isWritable: true },
     cooldownLpTokenAccount: {
       value: input.cooldownLpTokenAccount ?? null,
@@ -286,6 +296,7 @@ export async function getWithdrawInstructionAsync<
       getAccountMeta(accounts.settings),
       getAccountMeta(accounts.permissions),
       getAccountMeta(accounts.liquidityPool),
+      getAccountMeta(accounts.rewardPool),
       getAccountMeta(accounts.lpTokenMint),
       getAccountMeta(accounts.cooldownLpTokenAccount),
       getAccountMeta(accounts.cooldown),
@@ -302,6 +313,7 @@ export async function getWithdrawInstructionAsync<
     TAccountSettings,
     TAccountPermissions,
     TAccountLiquidityPool,
+    TAccountRewardPool,
     TAccountLpTokenMint,
     TAccountCooldownLpTokenAccount,
     TAccountCooldown,
@@ -315,6 +327,7 @@ export type WithdrawInput<
   TAccountSettings extends string = string,
   TAccountPermissions extends string = string,
   TAccountLiquidityPool extends string = string,
+  TAccountRewardPool extends string = string,
   TAccountLpTokenMint extends string = string,
   TAccountCooldownLpTokenAccount extends string = string,
   TAccountCooldown extends string = string,
@@ -325,6 +338,7 @@ export type WithdrawInput<
   settings: Address<TAccountSettings>;
   permissions?: Address<TAccountPermissions>;
   liquidityPool: Address<TAccountLiquidityPool>;
+  rewardPool: Address<TAccountRewardPool>;
   lpTokenMint: Address<TAccountLpTokenMint>;
   cooldownLpTokenAccount: Address<TAccountCooldownLpTokenAccount>;
   cooldown: Address<TAccountCooldown>;
@@ -339,6 +353,7 @@ export function getWithdrawInstruction<
   TAccountSettings extends string,
   TAccountPermissions extends string,
   TAccountLiquidityPool extends string,
+  TAccountRewardPool extends string,
   TAccountLpTokenMint extends string,
   TAccountCooldownLpTokenAccount extends string,
   TAccountCooldown extends string,
@@ -351,6 +366,7 @@ export function getWithdrawInstruction<
     TAccountSettings,
     TAccountPermissions,
     TAccountLiquidityPool,
+    TAccountRewardPool,
     TAccountLpTokenMint,
     TAccountCooldownLpTokenAccount,
     TAccountCooldown,
@@ -364,6 +380,7 @@ export function getWithdrawInstruction<
   TAccountSettings,
   TAccountPermissions,
   TAccountLiquidityPool,
+  TAccountRewardPool,
   TAccountLpTokenMint,
   TAccountCooldownLpTokenAccount,
   TAccountCooldown,
@@ -379,6 +396,7 @@ export function getWithdrawInstruction<
     settings: { value: input.settings ?? null, isWritable: true },
     permissions: { value: input.permissions ?? null, isWritable: false },
     liquidityPool: { value: input.liquidityPool ?? null, isWritable: true },
+    rewardPool: { value: input.rewardPool ?? null, isWritable: false },
     lpTokenMint: { value: input.lpTokenMint ?? null, isWritable: true },
     cooldownLpTokenAccount: {
       value: input.cooldownLpTokenAccount ?? null,
@@ -413,6 +431,7 @@ export function getWithdrawInstruction<
       getAccountMeta(accounts.settings),
       getAccountMeta(accounts.permissions),
       getAccountMeta(accounts.liquidityPool),
+      getAccountMeta(accounts.rewardPool),
       getAccountMeta(accounts.lpTokenMint),
       getAccountMeta(accounts.cooldownLpTokenAccount),
       getAccountMeta(accounts.cooldown),
@@ -429,6 +448,7 @@ export function getWithdrawInstruction<
     TAccountSettings,
     TAccountPermissions,
     TAccountLiquidityPool,
+    TAccountRewardPool,
     TAccountLpTokenMint,
     TAccountCooldownLpTokenAccount,
     TAccountCooldown,
@@ -447,11 +467,12 @@ export type ParsedWithdrawInstruction<
     settings: TAccountMetas[1];
     permissions?: TAccountMetas[2] | undefined;
     liquidityPool: TAccountMetas[3];
-    lpTokenMint: TAccountMetas[4];
-    cooldownLpTokenAccount: TAccountMetas[5];
-    cooldown: TAccountMetas[6];
-    tokenProgram: TAccountMetas[7];
-    systemProgram?: TAccountMetas[8] | undefined;
+    rewardPool: TAccountMetas[4];
+    lpTokenMint: TAccountMetas[5];
+    cooldownLpTokenAccount: TAccountMetas[6];
+    cooldown: TAccountMetas[7];
+    tokenProgram: TAccountMetas[8];
+    systemProgram?: TAccountMetas[9] | undefined;
   };
   data: WithdrawInstructionData;
 };
@@ -464,7 +485,7 @@ export function parseWithdrawInstruction<
     InstructionWithAccounts<TAccountMetas> &
     InstructionWithData<ReadonlyUint8Array>,
 ): ParsedWithdrawInstruction<TProgram, TAccountMetas> {
-  if (instruction.accounts.length < 9) {
+  if (instruction.accounts.length < 10) {
     // TODO: Coded error.
     throw new Error("Not enough accounts");
   }
@@ -487,6 +508,7 @@ export function parseWithdrawInstruction<
       settings: getNextAccount(),
       permissions: getNextOptionalAccount(),
       liquidityPool: getNextAccount(),
+      rewardPool: getNextAccount(),
       lpTokenMint: getNextAccount(),
       cooldownLpTokenAccount: getNextAccount(),
       cooldown: getNextAccount(),
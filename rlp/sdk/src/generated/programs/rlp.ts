@@ -18,31 +18,91 @@ import {
 } from "@solana/kit";
 import {
   parseAddAssetInstruction,
+  parseAddAssetOracleInstruction,
+  parseAddPoolAssetInstruction,
+  parseCancelWithdrawalRequestInstruction,
+  parseClaimProtocolFeesInstruction,
   parseCreatePermissionAccountInstruction,
   parseDepositInstruction,
+  parseDepositProportionalInstruction,
+  parseDepositRewardsInstruction,
   parseFreezeFunctionalityInstruction,
   parseInitializeLpInstruction,
+  parseInitializeRewardPoolInstruction,
   parseInitializeRlpInstruction,
+  parseMergeCooldownsInstruction,
+  parseMigrateAssetInstruction,
+  parseMigrateLiquidityPoolInstruction,
+  parseMigrateSettingsInstruction,
+  parseQuoteDepositInstruction,
+  parseQuoteSwapInstruction,
+  parseQuoteWithdrawInstruction,
+  parseRemoveAssetOracleInstruction,
+  parseRemovePoolAssetInstruction,
   parseRequestWithdrawalInstruction,
   parseSlashInstruction,
+  parseSwapExactOutInstruction,
   parseSwapInstruction,
+  parseSwapRouteInstruction,
   parseUpdateActionRoleInstruction,
+  parseUpdateAssetInstruction,
+  parseUpdateAssetLimitsInstruction,
+  parseUpdateCooldownDurationInstruction,
   parseUpdateDepositCapInstruction,
+  parseUpdateDepositValueCapInstruction,
+  parseUpdateOracleMaxAgeInstruction,
+  parseUpdatePoolSwapFeeInstruction,
+  parseUpdateProtocolFeeInstruction,
   parseUpdateRoleHolderInstruction,
+  parseUpdateSwapFeeInstruction,
+  parseUpdateTargetWeightsInstruction,
+  parseUpdateVestingDurationInstruction,
   parseWithdrawInstruction,
+  parseWithdrawPartialInstruction,
+  parseWithdrawSingleAssetInstruction,
   type ParsedAddAssetInstruction,
+  type ParsedAddAssetOracleInstruction,
+  type ParsedAddPoolAssetInstruction,
+  type ParsedCancelWithdrawalRequestInstruction,
+  type ParsedClaimProtocolFeesInstruction,
   type ParsedCreatePermissionAccountInstruction,
   type ParsedDepositInstruction,
+  type ParsedDepositProportionalInstruction,
+  type ParsedDepositRewardsInstruction,
   type ParsedFreezeFunctionalityInstruction,
   type ParsedInitializeLpInstruction,
+  type ParsedInitializeRewardPoolInstruction,
   type ParsedInitializeRlpInstruction,
+  type ParsedMergeCooldownsInstruction,
+  type ParsedMigrateAssetInstruction,
+  type ParsedMigrateLiquidityPoolInstruction,
+  type ParsedMigrateSettingsInstruction,
+  type ParsedQuoteDepositInstruction,
+  type ParsedQuoteSwapInstruction,
+  type ParsedQuoteWithdrawInstruction,
+  type ParsedRemoveAssetOracleInstruction,
+  type ParsedRemovePoolAssetInstruction,
   type ParsedRequestWithdrawalInstruction,
   type ParsedSlashInstruction,
+  type ParsedSwapExactOutInstruction,
   type ParsedSwapInstruction,
+  type ParsedSwapRouteInstruction,
   type ParsedUpdateActionRoleInstruction,
+  type ParsedUpdateAssetInstruction,
+  type ParsedUpdateAssetLimitsInstruction,
+  type ParsedUpdateCooldownDurationInstruction,
   type ParsedUpdateDepositCapInstruction,
+  type ParsedUpdateDepositValueCapInstruction,
+  type ParsedUpdateOracleMaxAgeInstruction,
+  type ParsedUpdatePoolSwapFeeInstruction,
+  type ParsedUpdateProtocolFeeInstruction,
   type ParsedUpdateRoleHolderInstruction,
+  type ParsedUpdateSwapFeeInstruction,
+  type ParsedUpdateTargetWeightsInstruction,
+  type ParsedUpdateVestingDurationInstruction,
   type ParsedWithdrawInstruction,
+  type ParsedWithdrawPartialInstruction,
+  type ParsedWithdrawSingleAssetInstruction,
 } from "../instructions";
 
 export const RLP_PROGRAM_ADDRESS =
@@ -52,6 +112,7 @@ export enum RlpAccount {
   Asset,
   Cooldown,
   LiquidityPool,
+  RewardPool,
   Settings,
   UserPermissions,
 }
@@ -93,6 +154,17 @@ export function identifyRlpAccount(
   ) {
     return RlpAccount.LiquidityPool;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([134, 121, 197, 211, 133, 154, 82, 32]),
+      ),
+      0,
+    )
+  ) {
+    return RlpAccount.RewardPool;
+  }
   if (
     containsBytes(
       data,
@@ -122,18 +194,48 @@ export function identifyRlpAccount(
 
 export enum RlpInstruction {
   AddAsset,
+  AddAssetOracle,
+  AddPoolAsset,
+  CancelWithdrawalRequest,
+  ClaimProtocolFees,
   CreatePermissionAccount,
   Deposit,
+  DepositProportional,
+  DepositRewards,
   FreezeFunctionality,
   InitializeLp,
+  InitializeRewardPool,
   InitializeRlp,
+  MergeCooldowns,
+  MigrateAsset,
+  MigrateLiquidityPool,
+  MigrateSettings,
+  QuoteDeposit,
+  QuoteSwap,
+  QuoteWithdraw,
+  RemoveAssetOracle,
+  RemovePoolAsset,
   RequestWithdrawal,
   Slash,
   Swap,
+  SwapExactOut,
+  SwapRoute,
   UpdateActionRole,
+  UpdateAsset,
+  UpdateAssetLimits,
+  UpdateCooldownDuration,
   UpdateDepositCap,
+  UpdateDepositValueCap,
+  UpdateOracleMaxAge,
+  UpdatePoolSwapFee,
+  UpdateProtocolFee,
   UpdateRoleHolder,
+  UpdateSwapFee,
+  UpdateTargetWeights,
+  UpdateVestingDuration,
   Withdraw,
+  WithdrawPartial,
+  WithdrawSingleAsset,
 }
 
 export function identifyRlpInstruction(
@@ -151,6 +253,50 @@ export function identifyRlpInstruction(
   ) {
     return RlpInstruction.AddAsset;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([139, 249, 198, 106, 97, 21, 113, 4]),
+      ),
+      0,
+    )
+  ) {
+    return RlpInstruction.AddAssetOracle;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([68, 86, 95, 90, 34, 243, 33, 221]),
+      ),
+      0,
+    )
+  ) {
+    return RlpInstruction.AddPoolAsset;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([82, 183, 63, 72, 51, 40, 167, 212]),
+      ),
+      0,
+    )
+  ) {
+    return RlpInstruction.CancelWithdrawalRequest;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([34, 142, 219, 112, 109, 54, 133, 23]),
+      ),
+      0,
+    )
+  ) {
+    return RlpInstruction.ClaimProtocolFees;
+  }
   if (
     containsBytes(
       data,
@@ -173,6 +319,28 @@ export function identifyRlpInstruction(
   ) {
     return RlpInstruction.Deposit;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([63, 57, 128, 46, 164, 238, 143, 133]),
+      ),
+      0,
+    )
+  ) {
+    return RlpInstruction.DepositProportional;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([52, 249, 112, 72, 206, 161, 196, 1]),
+      ),
+      0,
+    )
+  ) {
+    return RlpInstruction.DepositRewards;
+  }
   if (
     containsBytes(
       data,
@@ -195,6 +363,17 @@ export function identifyRlpInstruction(
   ) {
     return RlpInstruction.InitializeLp;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([139, 189, 60, 130, 44, 211, 218, 99]),
+      ),
+      0,
+    )
+  ) {
+    return RlpInstruction.InitializeRewardPool;
+  }
   if (
     containsBytes(
       data,
@@ -206,6 +385,105 @@ export function identifyRlpInstruction(
   ) {
     return RlpInstruction.InitializeRlp;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([187, 23, 184, 157, 97, 106, 50, 168]),
+      ),
+      0,
+    )
+  ) {
+    return RlpInstruction.MergeCooldowns;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([180, 156, 159, 159, 133, 15, 145, 207]),
+      ),
+      0,
+    )
+  ) {
+    return RlpInstruction.MigrateAsset;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([245, 184, 150, 2, 207, 93, 181, 32]),
+      ),
+      0,
+    )
+  ) {
+    return RlpInstruction.MigrateLiquidityPool;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([68, 101, 236, 165, 239, 88, 56, 172]),
+      ),
+      0,
+    )
+  ) {
+    return RlpInstruction.MigrateSettings;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([181, 208, 37, 150, 90, 54, 87, 204]),
+      ),
+      0,
+    )
+  ) {
+    return RlpInstruction.QuoteDeposit;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([20, 139, 100, 190, 67, 4, 13, 141]),
+      ),
+      0,
+    )
+  ) {
+    return RlpInstruction.QuoteSwap;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([200, 103, 235, 129, 119, 9, 220, 200]),
+      ),
+      0,
+    )
+  ) {
+    return RlpInstruction.QuoteWithdraw;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([66, 77, 61, 146, 121, 35, 244, 35]),
+      ),
+      0,
+    )
+  ) {
+    return RlpInstruction.RemoveAssetOracle;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([92, 98, 34, 74, 207, 224, 19, 121]),
+      ),
+      0,
+    )
+  ) {
+    return RlpInstruction.RemovePoolAsset;
+  }
   if (
     containsBytes(
       data,
@@ -239,6 +517,28 @@ export function identifyRlpInstruction(
   ) {
     return RlpInstruction.Swap;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([250, 73, 101, 33, 38, 207, 75, 184]),
+      ),
+      0,
+    )
+  ) {
+    return RlpInstruction.SwapExactOut;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([86, 183, 163, 144, 0, 50, 173, 28]),
+      ),
+      0,
+    )
+  ) {
+    return RlpInstruction.SwapRoute;
+  }
   if (
     containsBytes(
       data,
@@ -250,6 +550,39 @@ export function identifyRlpInstruction(
   ) {
     return RlpInstruction.UpdateActionRole;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([56, 126, 238, 138, 192, 118, 228, 172]),
+      ),
+      0,
+    )
+  ) {
+    return RlpInstruction.UpdateAsset;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([20, 177, 46, 30, 124, 126, 213, 239]),
+      ),
+      0,
+    )
+  ) {
+    return RlpInstruction.UpdateAssetLimits;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([217, 204, 49, 231, 216, 29, 46, 212]),
+      ),
+      0,
+    )
+  ) {
+    return RlpInstruction.UpdateCooldownDuration;
+  }
   if (
     containsBytes(
       data,
@@ -261,6 +594,50 @@ export function identifyRlpInstruction(
   ) {
     return RlpInstruction.UpdateDepositCap;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([31, 245, 117, 179, 178, 22, 146, 245]),
+      ),
+      0,
+    )
+  ) {
+    return RlpInstruction.UpdateDepositValueCap;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([169, 159, 3, 19, 230, 42, 233, 242]),
+      ),
+      0,
+    )
+  ) {
+    return RlpInstruction.UpdateOracleMaxAge;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([179, 61, 239, 145, 57, 155, 129, 218]),
+      ),
+      0,
+    )
+  ) {
+    return RlpInstruction.UpdatePoolSwapFee;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([170, 136, 6, 60, 43, 130, 81, 96]),
+      ),
+      0,
+    )
+  ) {
+    return RlpInstruction.UpdateProtocolFee;
+  }
   if (
     containsBytes(
       data,
@@ -272,6 +649,39 @@ export function identifyRlpInstruction(
   ) {
     return RlpInstruction.UpdateRoleHolder;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([240, 79, 214, 45, 1, 9, 150, 222]),
+      ),
+      0,
+    )
+  ) {
+    return RlpInstruction.UpdateSwapFee;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([157, 166, 234, 16, 39, 56, 115, 14]),
+      ),
+      0,
+    )
+  ) {
+    return RlpInstruction.UpdateTargetWeights;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([149, 57, 184, 235, 186, 137, 69, 90]),
+      ),
+      0,
+    )
+  ) {
+    return RlpInstruction.UpdateVestingDuration;
+  }
   if (
     containsBytes(
       data,
@@ -283,6 +693,28 @@ export function identifyRlpInstruction(
   ) {
     return RlpInstruction.Withdraw;
   }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([142, 181, 230, 69, 132, 105, 19, 229]),
+      ),
+      0,
+    )
+  ) {
+    return RlpInstruction.WithdrawPartial;
+  }
+  if (
+    containsBytes(
+      data,
+      fixEncoderSize(getBytesEncoder(), 8).encode(
+        new Uint8Array([96, 192, 131, 135, 149, 172, 111, 140]),
+      ),
+      0,
+    )
+  ) {
+    return RlpInstruction.WithdrawSingleAsset;
+  }
   throw new Error(
     "The provided instruction could not be identified as a rlp instruction.",
   );
@@ -294,21 +726,69 @@ export type ParsedRlpInstruction<
   | ({
       instructionType: RlpInstruction.AddAsset;
     } & ParsedAddAssetInstruction<TProgram>)
+  | ({
+      instructionType: RlpInstruction.AddAssetOracle;
+    } & ParsedAddAssetOracleInstruction<TProgram>)
+  | ({
+      instructionType: RlpInstruction.AddPoolAsset;
+    } & ParsedAddPoolAssetInstruction<TProgram>)
+  | ({
+      instructionType: RlpInstruction.CancelWithdrawalRequest;
+    } & ParsedCancelWithdrawalRequestInstruction<TProgram>)
+  | ({
+      instructionType: RlpInstruction.ClaimProtocolFees;
+    } & ParsedClaimProtocolFeesInstruction<TProgram>)
   | ({
       instructionType: RlpInstruction.CreatePermissionAccount;
     } & ParsedCreatePermissionAccountInstruction<TProgram>)
   | ({
       instructionType: RlpInstruction.Deposit;
     } & ParsedDepositInstruction<TProgram>)
+  | ({
+      instructionType: RlpInstruction.DepositProportional;
+    } & ParsedDepositProportionalInstruction<TProgram>)
+  | ({
+      instructionType: RlpInstruction.DepositRewards;
+    } & ParsedDepositRewardsInstruction<TProgram>)
   | ({
       instructionType: RlpInstruction.FreezeFunctionality;
     } & ParsedFreezeFunctionalityInstruction<TProgram>)
   | ({
       instructionType: RlpInstruction.InitializeLp;
     } & ParsedInitializeLpInstruction<TProgram>)
+  | ({
+      instructionType: RlpInstruction.InitializeRewardPool;
+    } & ParsedInitializeRewardPoolInstruction<TProgram>)
   | ({
       instructionType: RlpInstruction.InitializeRlp;
     } & ParsedInitializeRlpInstruction<TProgram>)
+  | ({
+      instructionType: RlpInstruction.MergeCooldowns;
+    } & ParsedMergeCooldownsInstruction<TProgram>)
+  | ({
+      instructionType: RlpInstruction.MigrateAsset;
+    } & ParsedMigrateAssetInstruction<TProgram>)
+  | ({
+      instructionType: RlpInstruction.MigrateLiquidityPool;
+    } & ParsedMigrateLiquidityPoolInstruction<TProgram>)
+  | ({
+      instructionType: RlpInstruction.MigrateSettings;
+    } & ParsedMigrateSettingsInstruction<TProgram>)
+  | ({
+      instructionType: RlpInstruction.QuoteDeposit;
+    } & ParsedQuoteDepositInstruction<TProgram>)
+  | ({
+      instructionType: RlpInstruction.QuoteSwap;
+    } & ParsedQuoteSwapInstruction<TProgram>)
+  | ({
+      instructionType: RlpInstruction.QuoteWithdraw;
+    } & ParsedQuoteWithdrawInstruction<TProgram>)
+  | ({
+      instructionType: RlpInstruction.RemoveAssetOracle;
+    } & ParsedRemoveAssetOracleInstruction<TProgram>)
+  | ({
+      instructionType: RlpInstruction.RemovePoolAsset;
+    } & ParsedRemovePoolAssetInstruction<TProgram>)
   | ({
       instructionType: RlpInstruction.RequestWithdrawal;
     } & ParsedRequestWithdrawalInstruction<TProgram>)
@@ -316,18 +796,60 @@ export type ParsedRlpInstruction<
       instructionType: RlpInstruction.Slash;
     } & ParsedSlashInstruction<TProgram>)
   | ({ instructionType: RlpInstruction.Swap } & ParsedSwapInstruction<TProgram>)
+  | ({
+      instructionType: RlpInstruction.SwapExactOut;
+    } & ParsedSwapExactOutInstruction<TProgram>)
+  | ({
+      instructionType: RlpInstruction.SwapRoute;
+    } & ParsedSwapRouteInstruction<TProgram>)
   | ({
       instructionType: RlpInstruction.UpdateActionRole;
     } & ParsedUpdateActionRoleInstruction<TProgram>)
+  | ({
+      instructionType: RlpInstruction.UpdateAsset;
+    } & ParsedUpdateAssetInstruction<TProgram>)
+  | ({
+      instructionType: RlpInstruction.UpdateAssetLimits;
+    } & ParsedUpdateAssetLimitsInstruction<TProgram>)
+  | ({
+      instructionType: RlpInstruction.UpdateCooldownDuration;
+    } & ParsedUpdateCooldownDurationInstruction<TProgram>)
   | ({
       instructionType: RlpInstruction.UpdateDepositCap;
     } & ParsedUpdateDepositCapInstruction<TProgram>)
+  | ({
+      instructionType: RlpInstruction.UpdateDepositValueCap;
+    } & ParsedUpdateDepositValueCapInstruction<TProgram>)
+  | ({
+      instructionType: RlpInstruction.UpdateOracleMaxAge;
+    } & ParsedUpdateOracleMaxAgeInstruction<TProgram>)
+  | ({
+      instructionType: RlpInstruction.UpdatePoolSwapFee;
+    } & ParsedUpdatePoolSwapFeeInstruction<TProgram>)
+  | ({
+      instructionType: RlpInstruction.UpdateProtocolFee;
+    } & ParsedUpdateProtocolFeeInstruction<TProgram>)
   | ({
       instructionType: RlpInstruction.UpdateRoleHolder;
     } & ParsedUpdateRoleHolderInstruction<TProgram>)
+  | ({
+      instructionType: RlpInstruction.UpdateSwapFee;
+    } & ParsedUpdateSwapFeeInstruction<TProgram>)
+  | ({
+      instructionType: RlpInstruction.UpdateTargetWeights;
+    } & ParsedUpdateTargetWeightsInstruction<TProgram>)
+  | ({
+      instructionType: RlpInstruction.UpdateVestingDuration;
+    } & ParsedUpdateVestingDurationInstruction<TProgram>)
   | ({
       instructionType: RlpInstruction.Withdraw;
-    } & ParsedWithdrawInstruction<TProgram>);
+    } & ParsedWithdrawInstruction<TProgram>)
+  | ({
+      instructionType: RlpInstruction.WithdrawPartial;
+    } & ParsedWithdrawPartialInstruction<TProgram>)
+  | ({
+      instructionType: RlpInstruction.WithdrawSingleAsset;
+    } & ParsedWithdrawSingleAssetInstruction<TProgram>);
 
 export function parseRlpInstruction<TProgram extends string>(
   instruction: Instruction<TProgram> & InstructionWithData<ReadonlyUint8Array>,
@@ -341,6 +863,34 @@ export function parseRlpInstruction<TProgram extends string>(
         ...parseAddAssetInstruction(instruction),
       };
     }
+    case RlpInstruction.AddAssetOracle: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: RlpInstruction.AddAssetOracle,
+        ...parseAddAssetOracleInstruction(instruction),
+      };
+    }
+    case RlpInstruction.AddPoolAsset: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: RlpInstruction.AddPoolAsset,
+        ...parseAddPoolAssetInstruction(instruction),
+      };
+    }
+    case RlpInstruction.CancelWithdrawalRequest: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: RlpInstruction.CancelWithdrawalRequest,
+        ...parseCancelWithdrawalRequestInstruction(instruction),
+      };
+    }
+    case RlpInstruction.ClaimProtocolFees: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: RlpInstruction.ClaimProtocolFees,
+        ...parseClaimProtocolFeesInstruction(instruction),
+      };
+    }
     case RlpInstruction.CreatePermissionAccount: {
       assertIsInstructionWithAccounts(instruction);
       return {
@@ -355,6 +905,20 @@ export function parseRlpInstruction<TProgram extends string>(
         ...parseDepositInstruction(instruction),
       };
     }
+    case RlpInstruction.DepositProportional: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: RlpInstruction.DepositProportional,
+        ...parseDepositProportionalInstruction(instruction),
+      };
+    }
+    case RlpInstruction.DepositRewards: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: RlpInstruction.DepositRewards,
+        ...parseDepositRewardsInstruction(instruction),
+      };
+    }
     case RlpInstruction.FreezeFunctionality: {
       assertIsInstructionWithAccounts(instruction);
       return {
@@ -369,6 +933,13 @@ export function parseRlpInstruction<TProgram extends string>(
         ...parseInitializeLpInstruction(instruction),
       };
     }
+    case RlpInstruction.InitializeRewardPool: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: RlpInstruction.InitializeRewardPool,
+        ...parseInitializeRewardPoolInstruction(instruction),
+      };
+    }
     case RlpInstruction.InitializeRlp: {
       assertIsInstructionWithAccounts(instruction);
       return {
@@ -376,6 +947,69 @@ export function parseRlpInstruction<TProgram extends string>(
         ...parseInitializeRlpInstruction(instruction),
       };
     }
+    case RlpInstruction.MergeCooldowns: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: RlpInstruction.MergeCooldowns,
+        ...parseMergeCooldownsInstruction(instruction),
+      };
+    }
+    case RlpInstruction.MigrateAsset: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: RlpInstruction.MigrateAsset,
+        ...parseMigrateAssetInstruction(instruction),
+      };
+    }
+    case RlpInstruction.MigrateLiquidityPool: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: RlpInstruction.MigrateLiquidityPool,
+        ...parseMigrateLiquidityPoolInstruction(instruction),
+      };
+    }
+    case RlpInstruction.MigrateSettings: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: RlpInstruction.MigrateSettings,
+        ...parseMigrateSettingsInstruction(instruction),
+      };
+    }
+    case RlpInstruction.QuoteDeposit: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: RlpInstruction.QuoteDeposit,
+        ...parseQuoteDepositInstruction(instruction),
+      };
+    }
+    case RlpInstruction.QuoteSwap: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: RlpInstruction.QuoteSwap,
+        ...parseQuoteSwapInstruction(instruction),
+      };
+    }
+    case RlpInstruction.QuoteWithdraw: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: RlpInstruction.QuoteWithdraw,
+        ...parseQuoteWithdrawInstruction(instruction),
+      };
+    }
+    case RlpInstruction.RemoveAssetOracle: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: RlpInstruction.RemoveAssetOracle,
+        ...parseRemoveAssetOracleInstruction(instruction),
+      };
+    }
+    case RlpInstruction.RemovePoolAsset: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: RlpInstruction.RemovePoolAsset,
+        ...parseRemovePoolAssetInstruction(instruction),
+      };
+    }
     case RlpInstruction.RequestWithdrawal: {
       assertIsInstructionWithAccounts(instruction);
       return {
@@ -397,6 +1031,20 @@ export function parseRlpInstruction<TProgram extends string>(
         ...parseSwapInstruction(instruction),
       };
     }
+    case RlpInstruction.SwapExactOut: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: RlpInstruction.SwapExactOut,
+        ...parseSwapExactOutInstruction(instruction),
+      };
+    }
+    case RlpInstruction.SwapRoute: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: RlpInstruction.SwapRoute,
+        ...parseSwapRouteInstruction(instruction),
+      };
+    }
     case RlpInstruction.UpdateActionRole: {
       assertIsInstructionWithAccounts(instruction);
       return {
@@ -404,6 +1052,27 @@ export function parseRlpInstruction<TProgram extends string>(
         ...parseUpdateActionRoleInstruction(instruction),
       };
     }
+    case RlpInstruction.UpdateAsset: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: RlpInstruction.UpdateAsset,
+        ...parseUpdateAssetInstruction(instruction),
+      };
+    }
+    case RlpInstruction.UpdateAssetLimits: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: RlpInstruction.UpdateAssetLimits,
+        ...parseUpdateAssetLimitsInstruction(instruction),
+      };
+    }
+    case RlpInstruction.UpdateCooldownDuration: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: RlpInstruction.UpdateCooldownDuration,
+        ...parseUpdateCooldownDurationInstruction(instruction),
+      };
+    }
     case RlpInstruction.UpdateDepositCap: {
       assertIsInstructionWithAccounts(instruction);
       return {
@@ -411,6 +1080,34 @@ export function parseRlpInstruction<TProgram extends string>(
         ...parseUpdateDepositCapInstruction(instruction),
       };
     }
+    case RlpInstruction.UpdateDepositValueCap: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: RlpInstruction.UpdateDepositValueCap,
+        ...parseUpdateDepositValueCapInstruction(instruction),
+      };
+    }
+    case RlpInstruction.UpdateOracleMaxAge: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: RlpInstruction.UpdateOracleMaxAge,
+        ...parseUpdateOracleMaxAgeInstruction(instruction),
+      };
+    }
+    case RlpInstruction.UpdatePoolSwapFee: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: RlpInstruction.UpdatePoolSwapFee,
+        ...parseUpdatePoolSwapFeeInstruction(instruction),
+      };
+    }
+    case RlpInstruction.UpdateProtocolFee: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: RlpInstruction.UpdateProtocolFee,
+        ...parseUpdateProtocolFeeInstruction(instruction),
+      };
+    }
     case RlpInstruction.UpdateRoleHolder: {
       assertIsInstructionWithAccounts(instruction);
       return {
@@ -418,6 +1115,27 @@ export function parseRlpInstruction<TProgram extends string>(
         ...parseUpdateRoleHolderInstruction(instruction),
       };
     }
+    case RlpInstruction.UpdateSwapFee: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: RlpInstruction.UpdateSwapFee,
+        ...parseUpdateSwapFeeInstruction(instruction),
+      };
+    }
+    case RlpInstruction.UpdateTargetWeights: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: RlpInstruction.UpdateTargetWeights,
+        ...parseUpdateTargetWeightsInstruction(instruction),
+      };
+    }
+    case RlpInstruction.UpdateVestingDuration: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: RlpInstruction.UpdateVestingDuration,
+        ...parseUpdateVestingDurationInstruction(instruction),
+      };
+    }
     case RlpInstruction.Withdraw: {
       assertIsInstructionWithAccounts(instruction);
       return {
@@ -425,6 +1143,20 @@ export function parseRlpInstruction<TProgram extends string>(
         ...parseWithdrawInstruction(instruction),
       };
     }
+    case RlpInstruction.WithdrawPartial: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: RlpInstruction.WithdrawPartial,
+        ...parseWithdrawPartialInstruction(instruction),
+      };
+    }
+    case RlpInstruction.WithdrawSingleAsset: {
+      assertIsInstructionWithAccounts(instruction);
+      return {
+        instructionType: RlpInstruction.WithdrawSingleAsset,
+        ...parseWithdrawSingleAssetInstruction(instruction),
+      };
+    }
     default:
       throw new Error(
         `Unrecognized instruction type: ${instructionType as string}`,
@@ -32,6 +32,7 @@ export enum Action {
   SuspendDeposits,
   UpdateRole,
   UpdateAction,
+  ClaimProtocolFees,
 }
 
 export type ActionArgs = Action;
@@ -12,21 +12,55 @@ export * from "./accessMap";
 export * from "./action";
 export * from "./actionMapping";
 export * from "./addAssetEvent";
+export * from "./addAssetOracleEvent";
+export * from "./addPoolAssetEvent";
+export * from "./assetLimits";
+export * from "./cancelWithdrawalRequestEvent";
+export * from "./claimProtocolFeesEvent";
 export * from "./createPermissionAccountEvent";
+export * from "./depegGuard";
 export * from "./depositEvent";
+export * from "./depositProportionalEvent";
 export * from "./depositRewardEvent";
+export * from "./fixedPrice";
 export * from "./freezeProtocolActionEvent";
 export * from "./initializeLiquidityPoolEvent";
+export * from "./initializeRewardPoolEvent";
 export * from "./initializeRlpEvent";
 export * from "./killSwitch";
 export * from "./levelRoles";
+export * from "./mergeCooldownsEvent";
+export * from "./migrateAssetEvent";
+export * from "./migrateLiquidityPoolEvent";
+export * from "./migrateSettingsEvent";
 export * from "./oracle";
+export * from "./pairFee";
+export * from "./quoteDepositResult";
+export * from "./quoteSwapResult";
+export * from "./quoteWithdrawResult";
+export * from "./rateSource";
+export * from "./removeAssetOracleEvent";
+export * from "./removePoolAssetEvent";
 export * from "./requestWithdrawEvent";
+export * from "./rewardSchedule";
 export * from "./role";
 export * from "./slashEvent";
 export * from "./swapEvent";
+export * from "./swapRouteEvent";
 export * from "./update";
 export * from "./updateActionRoleEvent";
+export * from "./updateAssetEvent";
+export * from "./updateAssetLimitsEvent";
+export * from "./updateCooldownDurationEvent";
 export * from "./updateDepositCapEvent";
+export * from "./updateDepositValueCapEvent";
+export * from "./updateOracleMaxAgeEvent";
+export * from "./updatePoolSwapFeeEvent";
+export * from "./updateProtocolFeeEvent";
 export * from "./updateRoleHolderEvent";
+export * from "./updateSwapFeeEvent";
+export * from "./updateTargetWeightsEvent";
+export * from "./updateVestingDurationEvent";
+export * from "./withdrawArgs";
 export * from "./withdrawEvent";
+export * from "./withdrawSingleAssetEvent";
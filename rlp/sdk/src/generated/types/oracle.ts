@@ -12,41 +12,115 @@ import {
   getAddressEncoder,
   getDiscriminatedUnionDecoder,
   getDiscriminatedUnionEncoder,
+  getI32Decoder,
+  getI32Encoder,
+  getI64Decoder,
+  getI64Encoder,
+  getOptionDecoder,
+  getOptionEncoder,
   getStructDecoder,
   getStructEncoder,
   getTupleDecoder,
   getTupleEncoder,
   type Address,
-  type FixedSizeCodec,
-  type FixedSizeDecoder,
-  type FixedSizeEncoder,
+  type Codec,
+  type Decoder,
+  type Encoder,
   type GetDiscriminatedUnionVariant,
   type GetDiscriminatedUnionVariantContent,
+  type Option,
+  type OptionOrNullable,
 } from "@solana/kit";
+import {
+  getDepegGuardDecoder,
+  getDepegGuardEncoder,
+  getRateSourceDecoder,
+  getRateSourceEncoder,
+  type DepegGuard,
+  type DepegGuardArgs,
+  type RateSource,
+  type RateSourceArgs,
+} from ".";
 
-export type Oracle = { __kind: "Pyth"; fields: readonly [Address] };
+export type Oracle =
+  | { __kind: "Pyth"; fields: readonly [Address] }
+  | { __kind: "Switchboard"; fields: readonly [Address] }
+  | { __kind: "ExchangeRate"; base: Address; rateSource: RateSource }
+  | {
+      __kind: "Fixed";
+      price: bigint;
+      exponent: number;
+      depegGuard: Option<DepegGuard>;
+    };
 
-export type OracleArgs = Oracle;
+export type OracleArgs =
+  | { __kind: "Pyth"; fields: readonly [Address] }
+  | { __kind: "Switchboard"; fields: readonly [Address] }
+  | { __kind: "ExchangeRate"; base: Address; rateSource: RateSourceArgs }
+  | {
+      __kind: "Fixed";
+      price: number | bigint;
+      exponent: number;
+      depegGuard: OptionOrNullable<DepegGuardArgs>;
+    };
 
-export function getOracleEncoder(): FixedSizeEncoder<OracleArgs> {
+export function getOracleEncoder(): Encoder<OracleArgs> {
   return getDiscriminatedUnionEncoder([
     [
       "Pyth",
       getStructEncoder([["fields", getTupleEncoder([getAddressEncoder()])]]),
     ],
-  ]) as FixedSizeEncoder<OracleArgs>;
+    [
+      "Switchboard",
+      getStructEncoder([["fields", getTupleEncoder([getAddressEncoder()])]]),
+    ],
+    [
+      "ExchangeRate",
+      getStructEncoder([
+        ["base", getAddressEncoder()],
+        ["rateSource", getRateSourceEncoder()],
+      ]),
+    ],
+    [
+      "Fixed",
+      getStructEncoder([
+        ["price", getI64Encoder()],
+        ["exponent", getI32Encoder()],
+        ["depegGuard", getOptionEncoder(getDepegGuardEncoder())],
+      ]),
+    ],
+  ]);
 }
 
-export function getOracleDecoder(): FixedSizeDecoder<Oracle> {
+export function getOracleDecoder(): Decoder<Oracle> {
   return getDiscriminatedUnionDecoder([
     [
       "Pyth",
       getStructDecoder([["fields", getTupleDecoder([getAddressDecoder()])]]),
     ],
-  ]) as FixedSizeDecoder<Oracle>;
+    [
+      "Switchboard",
+      getStructDecoder([["fields", getTupleDecoder([getAddressDecoder()])]]),
+    ],
+    [
+      "ExchangeRate",
+      getStructDecoder([
+        ["base", getAddressDecoder()],
+        ["rateSource", getRateSourceDecoder()],
+      ]),
+    ],
+    [
+      "Fixed",
+      getStructDecoder([
+        ["price", getI64Decoder()],
+        ["exponent", getI32Decoder()],
+        ["depegGuard", getOptionDecoder(getDepegGuardDecoder())],
+      ]),
+    ],
+  ]);
 }
 
-export function getOracleCodec(): FixedSizeCodec<OracleArgs, Oracle> {
+export function getOracleCodec(): Codec<OracleArgs, Oracle> {
   return combineCodec(getOracleEncoder(), getOracleDecoder());
 }
 
@@ -59,6 +133,26 @@ export function oracle(
     "Pyth"
   >["fields"],
 ): GetDiscriminatedUnionVariant<OracleArgs, "__kind", "Pyth">;
+export function oracle(
+  kind: "Switchboard",
+  data: GetDiscriminatedUnionVariantContent<
+    OracleArgs,
+    "__kind",
+    "Switchboard"
+  >["fields"],
+): GetDiscriminatedUnionVariant<OracleArgs, "__kind", "Switchboard">;
+export function oracle(
+  kind: "ExchangeRate",
+  data: GetDiscriminatedUnionVariantContent<
+    OracleArgs,
+    "__kind",
+    "ExchangeRate"
+  >,
+): GetDiscriminatedUnionVariant<OracleArgs, "__kind", "ExchangeRate">;
+export function oracle(
+  kind: "Fixed",
+  data: GetDiscriminatedUnionVariantContent<OracleArgs, "__kind", "Fixed">,
+): GetDiscriminatedUnionVariant<OracleArgs, "__kind", "Fixed">;
 export function oracle<K extends OracleArgs["__kind"], Data>(
   kind: K,
   data?: Data,
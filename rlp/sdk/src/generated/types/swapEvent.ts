@@ -25,6 +25,8 @@ export type SwapEvent = {
   liquidityPool: Address;
   amountIn: bigint;
   amountOut: bigint;
+  lpFee: bigint;
+  protocolFee: bigint;
 };
 
 export type SwapEventArgs = {
@@ -32,6 +34,8 @@ export type SwapEventArgs = {
   liquidityPool: Address;
   amountIn: number | bigint;
   amountOut: number | bigint;
+  lpFee: number | bigint;
+  protocolFee: number | bigint;
 };
 
 export function getSwapEventEncoder(): FixedSizeEncoder<SwapEventArgs> {
@@ -40,6 +44,8 @@ export function getSwapEventEncoder(): FixedSizeEncoder<SwapEventArgs> {
     ["liquidityPool", getAddressEncoder()],
     ["amountIn", getU64Encoder()],
     ["amountOut", getU64Encoder()],
+    ["lpFee", getU64Encoder()],
+    ["protocolFee", getU64Encoder()],
   ]);
 }
 
@@ -49,6 +55,8 @@ export function getSwapEventDecoder(): FixedSizeDecoder<SwapEvent> {
     ["liquidityPool", getAddressDecoder()],
     ["amountIn", getU64Decoder()],
     ["amountOut", getU64Decoder()],
+    ["lpFee", getU64Decoder()],
+    ["protocolFee", getU64Decoder()],
   ]);
 }
 
@@ -188,6 +188,7 @@ describe("RLP SDK Full Flow Test", function () {
   let adminPermissionsPda: Address;
   let userPermissionsPda: Address;
   let liquidityPoolPda: Address;
+  let rewardPoolPda: Address;
   let asset1Pda: Address;
   let asset2Pda: Address;
 
@@ -457,6 +458,7 @@ describe("RLP SDK Full Flow Test", function () {
     [adminPermissionsPda] = await PdaClient.deriveUserPermissions(admin.address);
     [userPermissionsPda] = await PdaClient.deriveUserPermissions(user.address);
     [liquidityPoolPda] = await PdaClient.deriveLiquidityPool(0);
+    [rewardPoolPda] = await PdaClient.deriveRewardPool(liquidityPoolPda);
     [asset1Pda] = await PdaClient.deriveAsset(assetMint1.address);
     [asset2Pda] = await PdaClient.deriveAsset(assetMint2.address);
 
@@ -515,6 +517,9 @@ describe("RLP SDK Full Flow Test", function () {
       assetMint: assetMint1.address,
       oracle: oracle1Address,
       accessLevel: AccessLevel.Public,
+      maxConfidenceBps: 200,
+      oracleMaxAge: null,
+      fixedPrice: null,
     });
     const result = sendSdkInstruction(ix);
     assertSuccess(result, "addAsset (asset 1, public)");
@@ -528,6 +533,9 @@ describe("RLP SDK Full Flow Test", function () {
       assetMint: assetMint2.address,
       oracle: oracle2Address,
       accessLevel: AccessLevel.Public,
+      maxConfidenceBps: 200,
+      oracleMaxAge: null,
+      fixedPrice: null,
     });
     const result = sendSdkInstruction(ix);
     assertSuccess(result, "addAsset (asset 2, public)");
@@ -661,8 +669,8 @@ describe("RLP SDK Full Flow Test", function () {
         },
       ],
       [
-        { address: asset1Pda, data: { bump: 0, index: 0, mint: assetMint1.address, oracle: { __kind: "Pyth" as const, fields: [oracle1Address] }, accessLevel: AccessLevel.Public } as any },
-        { address: asset2Pda, data: { bump: 0, index: 1, mint: assetMint2.address, oracle: { __kind: "Pyth" as const, fields: [oracle2Address] }, accessLevel: AccessLevel.Public } as any },
+        { address: asset1Pda, data: { bump: 0, index: 0, mint: assetMint1.address, oracle: { __kind: "Pyth" as const, fields: [oracle1Address] }, additionalOracles: [{ __option: "None" }, { __option: "None" }], accessLevel: AccessLevel.Public } as any },
+        { address: asset2Pda, data: { bump: 0, index: 1, mint: assetMint2.address, oracle: { __kind: "Pyth" as const, fields: [oracle2Address] }, additionalOracles: [{ __option: "None" }, { __option: "None" }], accessLevel: AccessLevel.Public } as any },
       ],
     );
   });
@@ -881,6 +889,7 @@ describe("RLP SDK Full Flow Test", function () {
     const ix = await getSwapInstructionAsync({
       signer: user.signer,
       liquidityPool: liquidityPoolPda,
+      rewardPool: rewardPoolPda,
       tokenFrom: assetMint1.address,
       tokenFromOracle: oracle1Address,
       tokenTo: assetMint2.address,